@@ -224,6 +224,7 @@ fn make_write_options<'a>(
     };
     Ok(opendal::options::WriteOptions {
         append: convert::read_bool_field(env, options, "append").unwrap_or_default(),
+        offset: None,
         content_type: convert::read_string_field(env, options, "contentType")?,
         content_disposition: convert::read_string_field(env, options, "contentDisposition")?,
         content_encoding: convert::read_string_field(env, options, "contentEncoding")?,
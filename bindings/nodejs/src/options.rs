@@ -473,6 +473,7 @@ impl From<WriteOptions> for opendal::options::WriteOptions {
     fn from(value: WriteOptions) -> Self {
         Self {
             append: value.append.unwrap_or_default(),
+            offset: None,
             chunk: value.chunk.map(|v| v.get_u64().1 as usize),
             content_type: value.content_type,
             content_disposition: value.content_disposition,
@@ -22,16 +22,26 @@ pub use opendal_python_shared::*;
 
 #[pymodule(gil_used = false)]
 fn _opendal_database(py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(enabled_services, m)?)?;
     m.add_class::<Operator>()?;
     m.add_class::<AsyncOperator>()?;
 
     m.add_class::<File>()?;
     m.add_class::<AsyncFile>()?;
+    m.add_class::<FileChunks>()?;
+    m.add_class::<AsyncFileChunks>()?;
+
+    m.add_class::<BlockingLister>()?;
+    m.add_class::<AsyncLister>()?;
 
     m.add_class::<Entry>()?;
     m.add_class::<EntryMode>()?;
     m.add_class::<Metadata>()?;
     m.add_class::<PresignedRequest>()?;
+    m.add_class::<DeleteResult>()?;
+    m.add_class::<OperatorInfo>()?;
+    m.add_class::<Buffer>()?;
+    m.add_class::<BufferReader>()?;
     m.add_class::<Capability>()?;
 
     m.add_class::<WriteOptions>()?;
@@ -43,8 +53,14 @@ fn _opendal_database(py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     let layers_module = PyModule::new(py, "layers")?;
     layers_module.add_class::<Layer>()?;
     layers_module.add_class::<RetryLayer>()?;
+    layers_module.add_class::<TimeoutLayer>()?;
     layers_module.add_class::<ConcurrentLimitLayer>()?;
     layers_module.add_class::<MimeGuessLayer>()?;
+    layers_module.add_class::<ThrottleLayer>()?;
+    layers_module.add_class::<CacheLayer>()?;
+    layers_module.add_class::<LoggingLayer>()?;
+    layers_module.add_class::<MetricsLayer>()?;
+    layers_module.add_class::<TracingLayer>()?;
     m.add_submodule(&layers_module)?;
     py.import("sys")?
         .getattr("modules")?
@@ -60,8 +76,11 @@ fn _opendal_database(py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     exception_module.add("IsADirectory", py.get_type::<IsADirectory>())?;
     exception_module.add("NotADirectory", py.get_type::<NotADirectory>())?;
     exception_module.add("AlreadyExists", py.get_type::<AlreadyExists>())?;
+    exception_module.add("RateLimited", py.get_type::<RateLimited>())?;
     exception_module.add("IsSameFile", py.get_type::<IsSameFile>())?;
     exception_module.add("ConditionNotMatch", py.get_type::<ConditionNotMatch>())?;
+    exception_module.add("RangeNotSatisfied", py.get_type::<RangeNotSatisfied>())?;
+    exception_module.add("ChecksumMismatch", py.get_type::<ChecksumMismatch>())?;
     m.add_submodule(&exception_module)?;
     py.import("sys")?
         .getattr("modules")?
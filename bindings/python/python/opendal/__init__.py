@@ -23,7 +23,8 @@ based on the storage scheme being used.
 """
 
 import sys
-from typing import Any, Dict, Set, TYPE_CHECKING
+from importlib import import_module
+from typing import Any, Dict, List, Set, TYPE_CHECKING
 
 if TYPE_CHECKING:
     from opendal_core import Operator as CoreOperator, AsyncOperator as CoreAsyncOperator
@@ -48,7 +49,7 @@ DATABASE_SERVICES: Set[str] = {
 
 CLOUD_SERVICES: Set[str] = {
     # Personal Cloud Storage (verified from backup)
-    "aliyun-drive", "dropbox", "onedrive", "gdrive", "yandex-disk",
+    "aliyun_drive", "dropbox", "onedrive", "gdrive", "yandex_disk",
     # Object Storage (verified from backup)
     "b2", "swift", "upyun",
     # Developer Platforms (verified from backup)
@@ -56,19 +57,43 @@ CLOUD_SERVICES: Set[str] = {
     # Distributed & Other Services (verified from backup)
     "ipfs", "koofr", "moka", "dashmap",
     # Misc (verified from backup)
-    "vercel-artifacts", "alluxio"
+    "vercel_artifacts", "alluxio"
 }
 
 ADVANCED_SERVICES: Set[str] = {
     # File System Extensions (verified from backup)
     "azfile", "monoiofs",
     # Cache Systems (verified from backup)
-    "mini-moka", "cacache"
+    "mini_moka", "cacache"
     # Note: ftp, hdfs, sftp excluded due to build/platform issues
 }
 
+# Package name and pip install target of every service package
+SERVICE_PACKAGES: Dict[str, str] = {
+    "opendal_core": "opendal",
+    "opendal_database": "opendal[database]",
+    "opendal_cloud": "opendal[cloud]",
+    "opendal_advanced": "opendal[advanced]",
+}
+
+def enabled_services() -> List[str]:
+    """Return the sorted schemes of all services in the installed packages."""
+    services: Set[str] = set()
+    for package_name in SERVICE_PACKAGES:
+        try:
+            package = import_module(package_name)
+        except ImportError:
+            continue
+        services.update(package.enabled_services())
+    return sorted(services)
+
+def _normalize_scheme(scheme: str) -> str:
+    """Accept `mini-moka` for `mini_moka` like the package extras do."""
+    return scheme.lower().replace("-", "_")
+
 def _get_service_package(scheme: str) -> str:
     """Determine which package provides the given scheme."""
+    scheme = _normalize_scheme(scheme)
     if scheme in CORE_SERVICES:
         return "opendal_core"
     elif scheme in DATABASE_SERVICES:
@@ -84,34 +109,22 @@ def _get_service_package(scheme: str) -> str:
 def _import_operator(scheme: str):
     """Import the appropriate Operator class for the given scheme."""
     package_name = _get_service_package(scheme)
-    
+
     try:
-        if package_name == "opendal_core":
-            from opendal_core import Operator, AsyncOperator
-        elif package_name == "opendal_database":
-            from opendal_database import Operator, AsyncOperator
-        elif package_name == "opendal_cloud":
-            from opendal_cloud import Operator, AsyncOperator
-        elif package_name == "opendal_advanced":
-            from opendal_advanced import Operator, AsyncOperator
-        else:
-            raise ImportError(f"Unknown package: {package_name}")
-            
-        return Operator, AsyncOperator
-        
+        package = import_module(package_name)
     except ImportError as e:
-        # Provide helpful installation instructions
         if package_name == "opendal_core":
             # This should never happen as core is always installed
-            raise ImportError(f"OpenDAL core package not found. Please reinstall: pip install opendal")
-        elif package_name == "opendal_database":
-            raise ImportError(f"Database services not installed. Install with: pip install opendal[database]")
-        elif package_name == "opendal_cloud":
-            raise ImportError(f"Cloud services not installed. Install with: pip install opendal[cloud]")  
-        elif package_name == "opendal_advanced":
-            raise ImportError(f"Advanced services not installed. Install with: pip install opendal[advanced]")
-        else:
-            raise e
+            raise ImportError(
+                "OpenDAL core package not found. Please reinstall: pip install opendal"
+            ) from e
+        # Provide helpful installation instructions
+        raise exceptions.Unsupported(
+            f"service {scheme!r} is provided by {package_name}, which is not "
+            f"installed. Install with: pip install {SERVICE_PACKAGES[package_name]}"
+        ) from e
+
+    return package.Operator, package.AsyncOperator
 
 class Operator:
     """Smart routing Operator that delegates to the appropriate service package."""
@@ -119,7 +132,19 @@ class Operator:
     def __new__(cls, scheme: str, **options: Any):
         """Create operator instance from the appropriate service package."""
         OperatorClass, _ = _import_operator(scheme)
-        return OperatorClass(scheme, **options)
+        return OperatorClass(_normalize_scheme(scheme), **options)
+
+    @staticmethod
+    def from_uri(uri: str, **options: Any):
+        """Create operator instance from a URI like ``s3://bucket/prefix``."""
+        OperatorClass, _ = _import_operator(uri.split(":", 1)[0])
+        return OperatorClass.from_uri(uri, **options)
+
+    @staticmethod
+    def from_env(scheme: str, prefix: str = "OPENDAL", **options: Any):
+        """Create operator instance from variables like ``OPENDAL_S3_BUCKET``."""
+        OperatorClass, _ = _import_operator(scheme)
+        return OperatorClass.from_env(_normalize_scheme(scheme), prefix, **options)
 
 class AsyncOperator:
     """Smart routing AsyncOperator that delegates to the appropriate service package."""
@@ -127,12 +152,25 @@ class AsyncOperator:
     def __new__(cls, scheme: str, **options: Any):
         """Create async operator instance from the appropriate service package."""
         _, AsyncOperatorClass = _import_operator(scheme)
-        return AsyncOperatorClass(scheme, **options)
+        return AsyncOperatorClass(_normalize_scheme(scheme), **options)
+
+    @staticmethod
+    def from_uri(uri: str, **options: Any):
+        """Create async operator instance from a URI like ``s3://bucket/prefix``."""
+        _, AsyncOperatorClass = _import_operator(uri.split(":", 1)[0])
+        return AsyncOperatorClass.from_uri(uri, **options)
+
+    @staticmethod
+    def from_env(scheme: str, prefix: str = "OPENDAL", **options: Any):
+        """Create async operator instance from variables like ``OPENDAL_S3_BUCKET``."""
+        _, AsyncOperatorClass = _import_operator(scheme)
+        return AsyncOperatorClass.from_env(_normalize_scheme(scheme), prefix, **options)
 
 # Import shared types and exceptions from core package
 try:
     from opendal_core import (
-        File, AsyncFile, Entry, EntryMode, Metadata, PresignedRequest, Capability,
+        File, AsyncFile, FileChunks, AsyncFileChunks, BlockingLister, AsyncLister, Entry, EntryMode, Metadata,
+        PresignedRequest, DeleteResult, OperatorInfo, Buffer, BufferReader, Capability,
         WriteOptions, ReadOptions, ListOptions, StatOptions
     )
     # Import submodules and make them available as opendal.exceptions and opendal.layers
@@ -152,7 +190,9 @@ except ImportError:
 
 # Export everything that the original opendal package exported
 __all__ = [
-    "Operator", "AsyncOperator", "File", "AsyncFile", "Entry", "EntryMode", 
-    "Metadata", "PresignedRequest", "Capability", "WriteOptions", "ReadOptions", 
-    "ListOptions", "StatOptions", "exceptions", "layers"
+    "Operator", "AsyncOperator", "File", "AsyncFile", "FileChunks", "AsyncFileChunks",
+    "BlockingLister", "AsyncLister",
+    "Entry", "EntryMode", 
+    "Metadata", "PresignedRequest", "DeleteResult", "OperatorInfo", "Buffer", "BufferReader", "Capability", "WriteOptions", "ReadOptions", 
+    "ListOptions", "StatOptions", "enabled_services", "exceptions", "layers"
 ]
\ No newline at end of file
@@ -16,28 +16,93 @@
 # under the License.
 
 import os
-from collections.abc import AsyncIterable, Iterable
-from datetime import datetime
+from collections.abc import AsyncIterable, AsyncIterator, Callable, Iterable, Iterator
+from datetime import datetime, timedelta
 from types import TracebackType
-from typing import Any, Union, final
+from typing import Any, Literal, TypedDict, Union, final
 
 try:
     from warnings import deprecated
 except ImportError:
     from typing_extensions import deprecated
+try:
+    from collections.abc import Buffer as BytesLike
+except ImportError:
+    from typing_extensions import Buffer as BytesLike
 from opendal import exceptions as exceptions
 from opendal import layers as layers
 from opendal.__base import _Base
 from opendal.layers import Layer
 
-PathBuf = Union[str, os.PathLike]
+# Paths are strings; any `os.PathLike[str]` like `pathlib.PurePosixPath` is
+# accepted too, while bytes paths raise `TypeError`.
+PathBuf = Union[str, os.PathLike[str]]
+Duration = Union[int, float, timedelta]
+ProgressCallback = Callable[[int, Union[int, None]], object]
+# `(access_key, secret_key, session_token, expiry)`, see `Operator`.
+CredentialProvider = Callable[
+    [], tuple[str, str, Union[str, None], Union[datetime, None]]
+]
+
+class HttpOptions(TypedDict, total=False):
+    """Settings of the HTTP client used by HTTP based services."""
+
+    connect_timeout: Duration
+    """The timeout to establish a connection, in seconds or as a `timedelta`."""
+    pool_max_idle_per_host: int
+    """The maximum number of idle connections kept per host."""
+    proxy_url: str
+    """Send every request through this proxy, like `http://proxy:3128`."""
+    ca_cert_path: str
+    """A PEM file of CA certificates to trust on top of the system ones."""
+
+def enabled_services() -> list[str]:
+    """List the services available in the installed packages.
+
+    Services are split across the `opendal` packages, so a scheme only works
+    if its package is installed. Creating an operator for a scheme that isn't
+    available raises `Unsupported`, naming the package to install.
+
+    Returns
+    -------
+        list[str]: The sorted schemes, like `["fs", "memory", "s3", ...]`.
+    """
 
 @final
 class Operator(_Base):
     """The entry class for all public blocking APIs.
 
+    Pickling rebuilds the operator from `scheme` and `options`, so it can be
+    passed to `multiprocessing` workers. The options, including any
+    credentials, are stored in the pickle in plain text, so only send it to
+    processes you trust. Layers are not carried over and must be added again,
+    while `copy.copy` and `copy.deepcopy` keep them. Forked processes can't
+    use the runtime of a parent that already ran operations, so prefer the
+    `spawn` or `forkserver` start methods.
+
+    Every service works here, including those only implemented with async
+    APIs: calls are bridged through a runtime owned by OpenDAL and block the
+    calling thread until done. In asyncio code use `AsyncOperator`, calling a
+    blocking operator from a thread running an event loop raises
+    `RuntimeError` instead of stalling the loop. So does calling it from
+    inside that runtime, e.g. from a layer callback like
+    `RetryLayer(on_retry=...)`.
+
     Args:
         scheme (str): The service name that OpenDAL supports.
+        http_options (HttpOptions): Settings of the HTTP client, like a proxy or
+            a private CA. Ignored by services that don't use HTTP. Raises
+            `ValueError` for an invalid proxy URL or a file without
+            certificates.
+        credential_provider (CredentialProvider): A callable returning
+            `(access_key, secret_key, session_token, expiry)`, called to sign
+            requests instead of static credentials. The result is cached until
+            two minutes before `expiry`, a timezone-aware `datetime` or `None`
+            for no expiry, and fetched again once the service rejects it with
+            401 or 403. Calls that can be sent again, like `stat` or `read`,
+            are retried once with the fresh credentials. Only `s3` supports
+            it, other services raise `Unsupported`. Pickling the operator
+            pickles the callable too.
         **options (any): The options for the service.
             See the documentation of each service for more details.
 
@@ -50,10 +115,110 @@ class Operator(_Base):
         ```
     """
 
-    def __init__(self, scheme: str, **options: Any) -> None: ...
+    def __init__(
+        self,
+        scheme: str,
+        *,
+        http_options: HttpOptions | None = None,
+        credential_provider: CredentialProvider | None = None,
+        **options: Any,
+    ) -> None: ...
+    @staticmethod
+    def from_uri(
+        uri: str,
+        *,
+        http_options: HttpOptions | None = None,
+        credential_provider: CredentialProvider | None = None,
+        **options: Any,
+    ) -> Operator:
+        """Create a new operator from a URI.
+
+        The scheme picks the service. For services with a bucket or container,
+        like `s3`, `gcs` or `azblob`, the host names it. The path becomes
+        `root`, and each query parameter maps to the config key of the same
+        name. Keyword arguments override the values parsed from the URI, so
+        credentials can be passed separately.
+
+        Args:
+            uri (str): The URI, like `"s3://bucket/prefix?region=us-east-1"`.
+            http_options (HttpOptions | None): See `Operator`.
+            credential_provider (CredentialProvider | None): See `Operator`.
+            **options (Any): Additional service options.
+
+        Returns
+        -------
+            The new operator.
+
+        Raises
+        ------
+            ValueError: If the URI is invalid, holds credentials, or has a
+                host for a service without bucket or container.
+
+        Example:
+            ```python
+            import os
+
+            import opendal
+
+            op = opendal.Operator.from_uri(
+                os.environ["STORAGE_URI"],
+                secret_access_key=os.environ["SECRET_ACCESS_KEY"],
+            )
+            ```
+        """
+    @staticmethod
+    def from_env(
+        scheme: str,
+        prefix: str = "OPENDAL",
+        *,
+        http_options: HttpOptions | None = None,
+        credential_provider: CredentialProvider | None = None,
+        **options: Any,
+    ) -> Operator:
+        """Create a new operator from environment variables.
+
+        The variables named `{PREFIX}_{SCHEME}_{KEY}`, like
+        `OPENDAL_S3_BUCKET`, are collected and `KEY` is lowercased into the
+        config key, so `OPENDAL_S3_REGION` sets `region`. Keyword arguments
+        override the values read from the environment.
+
+        A missing bucket or container, like `OPENDAL_S3_BUCKET`, or the
+        root of `fs` raises `ConfigInvalid` naming its variable. Other
+        config errors come from the service and name the config key.
+
+        Args:
+            scheme (str): The scheme of the service, like `"s3"`.
+            prefix (str): The prefix of the variables. Pass `""` to read
+                variables like `S3_BUCKET`.
+            http_options (HttpOptions | None): See `Operator`.
+            credential_provider (CredentialProvider | None): See `Operator`.
+            **options (Any): Additional service options.
+
+        Returns
+        -------
+            The new operator.
+
+        Raises
+        ------
+            ConfigInvalid: If a config key the service requires, like the
+                bucket of `s3`, is missing. The message names its variable.
+
+        Example:
+            ```python
+            import opendal
+
+            # With OPENDAL_S3_BUCKET and OPENDAL_S3_REGION set.
+            op = opendal.Operator.from_env("s3")
+            ```
+        """
     def layer(self, layer: Layer) -> Operator:
         """Add new layers upon the current operator.
 
+        This returns a new operator and leaves the current one unchanged, so
+        several variants can be derived from the same base, e.g.
+        `fast = base.layer(ConcurrentLimitLayer(256))` and
+        `safe = base.layer(RetryLayer(max_times=10))`.
+
         Args:
             layer (Layer): The layer to be added.
 
@@ -61,19 +226,78 @@ class Operator(_Base):
         -------
             The new operator with the layer added.
         """
+    def with_default_write_options(self, **options: Any) -> Operator:
+        """Apply the given write options to every write of a new operator.
+
+        Options passed to `write` or `open` take precedence over these
+        defaults, and `user_metadata` is merged key by key. Options the
+        service can't honor still raise `Unsupported` when writing. Like
+        layers, the defaults are not carried over by pickling.
+
+        Args:
+            **options (Any): Write options such as `content_type` or
+                `cache_control`.
+
+        Returns
+        -------
+            The new operator with the defaults applied.
+
+        Example:
+            ```python
+            op = base.with_default_write_options(cache_control="max-age=60")
+            op.write("a.json", b"{}", content_type="application/json")
+            ```
+        """
+    def with_chunk_sizes(
+        self, *, read_chunk: int | None = None, write_chunk: int | None = None
+    ) -> Operator:
+        """Read and write in chunks of the given sizes with a new operator.
+
+        By default reads fetch the whole range in one request, or in 8 MiB
+        chunks when `concurrent` is given, and writes are buffered up to the
+        service's minimum part size, if it has one. Larger chunks mean fewer
+        requests, which pays off on high-latency services. Pass `chunk` to
+        `read`, `write` or `open` to override the sizes for one call.
+
+        Args:
+            read_chunk (int | None): The size of each read request, in bytes.
+            write_chunk (int | None): The size of each part written, in bytes.
+
+        Returns
+        -------
+            The new operator with the chunk sizes applied.
+
+        Raises
+        ------
+            ValueError: If `read_chunk` isn't positive, or `write_chunk` is
+                outside the part sizes the service accepts.
+        """
     def open(self, path: PathBuf, mode: str, **options: Any) -> File:
         """Open a file at the given path for reading or writing.
 
         Args:
             path (str | Path): The path to the file.
-            mode (str): The mode to open the file. Must be either `"rb"` for reading or
-                `"wb"` for writing.
+            mode (str): The mode to open the file. Must be `"rb"` for reading,
+                `"wb"` for writing or `"ab"` for appending. Appending raises
+                `Unsupported` if the service can't append.
             **options (Any): Additional options passed to the underlying OpenDAL reader
                 or writer.
                 - If `mode == "rb"`: options match the
                   [OpenDAL `ReaderOptions`](https://opendal.apache.org/docs/rust/opendal/options/struct.ReaderOptions.html).
-                - If `mode == "wb"`: options match the
+                  Pass `chunk=SIZE` with `concurrent=N` to prefetch up to `N`
+                  chunks of `SIZE` bytes ahead of the read position. Reads are
+                  served from the prefetched chunks first, and seeking outside
+                  of them discards the prefetch.
+                  Pass `range=(start, end)` to read only the bytes from
+                  `start` up to, but not including, `end`. The file then
+                  reports EOF at `end`, and `seek` and `tell` positions are
+                  relative to `start`.
+                - If `mode == "wb"` or `mode == "ab"`: options match the
                   [OpenDAL `WriteOptions`](https://opendal.apache.org/docs/rust/opendal/options/struct.WriteOptions.html).
+                `extra_headers` raises `ValueError`, the file sends its requests
+                after `open` returns.
+                `strategy="single"` raises `ValueError` too, since the file
+                streams what's written instead of holding it.
 
         Returns
         -------
@@ -88,11 +312,37 @@ class Operator(_Base):
                 f.write(b"hello world")
             ```
         """
-    def read(self, path: PathBuf, **options: Any) -> bytes:
+    def read(
+        self,
+        path: PathBuf,
+        *,
+        progress: ProgressCallback | None = None,
+        buffer_protocol: bool = False,
+        verify: bool = False,
+        require_checksum: bool = False,
+        timeout: float | timedelta | None = None,
+        **options: Any,
+    ) -> bytes | Buffer:
         """Read the content of the object at the given path.
 
         Args:
             path (str | Path): The path to the object.
+            progress (Callable[[int, int | None], object]): Called with
+                `(bytes_so_far, total)` as the content arrives. `total` is the
+                content length to read. An exception raised by the callback
+                aborts the read and propagates.
+            buffer_protocol (bool): If True, return a `Buffer` that shares the
+                memory read from the service instead of copying it into
+                `bytes`. Useful to feed large reads into numpy or pyarrow.
+            verify (bool): If True, check the content against the MD5 the
+                service reports as `content_md5`, and raise `ChecksumMismatch`
+                if it differs. Etags aren't trusted as checksums, so objects
+                without a `content_md5` are returned unchecked. Can't be
+                combined with `offset` or `size`.
+            require_checksum (bool): Like `verify`, but raise
+                `ChecksumMismatch` if the object has no checksum either.
+            timeout (float | timedelta): Raise `Unexpected` if the call takes
+                longer than this many seconds, cancelling the request.
             **options (Any): Optional read parameters matching the
                 [OpenDAL `ReadOptions`](https://opendal.apache.org/docs/rust/opendal/options/struct.ReadOptions.html):
 
@@ -101,38 +351,181 @@ class Operator(_Base):
                 - size (int): Number of bytes to read. If not specified, reads until
                     the end of the object.
                   Together, `offset` and `size` define the byte range for reading.
+                  A range past the end of the object is truncated, so an
+                  `offset` beyond EOF returns empty bytes. The object is only
+                  stat-ed to truncate the range once the service refuses it.
                 - version (str): Specify the version of the object to read, if
                     supported by the backend.
-                - concurrent (int): Level of concurrency for reading. Defaults to
-                    backend-specific value.
-                - chunk (int): Read chunk size in bytes.
+                - concurrent (int): Number of ranged reads to run at once,
+                    reassembled in order. Defaults to 1.
+                - chunk (int): Size in bytes of each ranged read. Defaults to
+                    8 MiB when `concurrent` is above 1.
                 - gap (int): Minimum gap (in bytes) between chunks to consider
                     them separate.
                 - if_match (str): Read only if the ETag matches the given value.
                 - if_none_match (str): Read-only if the ETag does not match the
                     given value.
                 - if_modified_since (datetime): Only read if the object was modified
-                    since this timestamp. This timestamp must be in UTC.
+                    since this timestamp. The timestamp must be timezone-aware.
                 - if_unmodified_since (datetime): Only read if the object was not
-                    modified since this timestamp. This timestamp must be in UTC.
+                    modified since this timestamp. The timestamp must be
+                    timezone-aware.
+                - extra_headers (dict[str, str]): Extra HTTP headers to send with
+                    the requests, added after they're signed. Headers OpenDAL
+                    sets itself, like `Authorization`, raise `ValueError`.
+
+                Raises `ConditionNotMatch` if a condition fails.
+
+        Returns
+        -------
+            bytes | Buffer: The content of the object, as a `Buffer` if
+                `buffer_protocol` is set.
+        """
+    def read_stream(self, path: PathBuf, **options: Any) -> BufferReader:
+        """Read the whole object into memory as a file-like object.
+
+        The returned `BufferReader` shares the memory the content was read
+        into, so it can be handed to parsers expecting a file without copying
+        it into `io.BytesIO` first.
+
+        Args:
+            path (str|Path): The path to the object.
+            **options (Any): The same options as `read`.
+
+        Returns
+        -------
+            BufferReader: A readable and seekable file-like object.
+        """
+    def reader_with_size(
+        self, path: PathBuf, **options: Any
+    ) -> tuple[int | None, File]:
+        """Open a reader for the object along with the number of bytes it yields.
+
+        Suits responses that need a `Content-Length` before streaming the
+        content, like a Starlette `StreamingResponse`, without a separate
+        `stat`.
+
+        Args:
+            path (str|Path): The path to the object.
+            **options (Any): The read options of `open` in `"rb"` mode.
+                `extra_headers` raises `ValueError`.
+
+        Returns
+        -------
+            tuple[int | None, File]: The size and a reader that can be iterated
+                over in chunks. The size is looked up with a stat and accounts
+                for `range`, `offset` and `size`, which are clamped to the
+                object like `read` does. It's `None` if the service can't stat.
+        """
+    def read_if_modified(
+        self, path: PathBuf, since: datetime
+    ) -> tuple[Metadata, bytes | None]:
+        """Read the object unless it hasn't changed since `since`.
+
+        Services supporting it are sent a conditional read with
+        `if_modified_since`, and a `304 Not Modified` is returned as `None`
+        instead of raising. Other services compare the object's
+        `last_modified` to `since`, to the second.
+
+        Args:
+            path (str|Path): The path to the object.
+            since (datetime): A timezone-aware datetime, like the
+                `last_modified` of a cached copy.
+
+        Returns
+        -------
+            tuple[Metadata, bytes | None]: The metadata of the object and its
+                content, or `None` if it wasn't modified after `since`.
+        """
+    def read_with_metadata(
+        self, path: PathBuf, **options: Any
+    ) -> tuple[Metadata, bytes]:
+        """Read the whole object along with the metadata of what was read.
+
+        For services speaking HTTP, the metadata, like `etag`,
+        `content_length`, `content_type` and `last_modified`, is parsed from
+        the headers of the same request that returned the content, so it
+        can't describe another version of the object, unlike a `stat` before
+        the `read`. Other services, like `fs`, stat the object after reading
+        it.
+
+        Args:
+            path (str|Path): The path to the object.
+            **options (Any): The read options of `read`, but `range`, `offset`
+                and `size`, which raise `ValueError`.
+
+        Returns
+        -------
+            tuple[Metadata, bytes]: The metadata of the object and its content.
+        """
+    def read_ranges(
+        self, path: PathBuf, ranges: list[tuple[int, int]], **options: Any
+    ) -> list[bytes]:
+        """Read several byte ranges of the object at once.
+
+        Ranges that overlap or are less than `gap` bytes apart are merged into
+        one ranged read, and up to `concurrent` reads are sent at once, which
+        suits formats like Parquet that read a footer and a few column chunks.
+
+        Args:
+            path (str|Path): The path to the object.
+            ranges (list[tuple[int, int]]): The `(offset, length)` of each
+                range to read.
+            **options (Any): The read options of `read`, like `version` or
+                `if_match`, but `range`, `offset` and `size`, which raise
+                `ValueError`. `gap` defaults to 1 MiB and `concurrent` to 16.
 
         Returns
         -------
-            bytes: The content of the object as bytes.
+            list[bytes]: The content of each range, in the order of `ranges`.
+                Like `read`, the ranges are clamped to the size of the object,
+                so ones reaching past its end come back short.
         """
-    def write(self, path: PathBuf, bs: bytes, **options: Any) -> None:
+    def write(
+        self,
+        path: PathBuf,
+        bs: BytesLike | Iterable[BytesLike],
+        *,
+        progress: ProgressCallback | None = None,
+        timeout: float | timedelta | None = None,
+        **options: Any,
+    ) -> Metadata:
         """Write the content to the object at the given path.
 
         Args:
             path (str | Path): The path to the object.
-            bs (bytes): The content to write.
+            bs (bytes-like | Iterable[bytes-like]): The content to write. Any
+                contiguous one-dimensional object implementing the buffer
+                protocol is accepted, like `bytearray`, `memoryview` or a numpy
+                array, without copying it as a whole. An iterable of them, like
+                a generator, is streamed chunk by chunk as it's produced. If
+                the iterable raises, the upload is aborted. Content sent in a
+                single request, see `strategy`, is copied or held first.
+            progress (Callable[[int, int | None], object]): Called with
+                `(bytes_so_far, total)` as the content is written. `total` is
+                the length of `bs`, or `None` for an iterable. An exception
+                raised by the callback aborts the write and propagates.
+            timeout (float | timedelta): Raise `Unexpected` if the call takes
+                longer than this many seconds, cancelling the request.
             **options (Any): Optional write parameters matching the
                 [OpenDAL `WriteOptions`](https://opendal.apache.org/docs/rust/opendal/options/struct.WriteOptions.html):
 
                 - append (bool): If True, append to the object instead of overwriting.
+                    Raises `Unsupported` if the service can't append.
                 - chunk (int): Specify the chunk size in bytes for multipart uploads.
+                    Raises `ValueError` if outside the service's
+                    `write_multi_min_size` and `write_multi_max_size`.
                 - concurrent (int): Number of concurrent upload parts. Larger values can
                     improve performance.
+                - strategy (str): How to send the content. `"auto"`, the
+                    default, sends content fitting in one part in a single
+                    request and uploads larger content, or an iterable, in
+                    parts of `chunk` bytes, 8 MiB if unset, `concurrent` at a
+                    time, 4 if unset. Services that can't upload in parts get
+                    a single request, with an iterable buffered first.
+                    `"single"` and `"multipart"` force either way, and
+                    `"multipart"` raises `Unsupported` if the service can't
+                    upload in parts.
                 - cache_control (str): Override the cache-control header for the object.
                 - content_type (str): Explicitly set the Content-Type header for
                     the object.
@@ -140,23 +533,67 @@ class Operator(_Base):
                     (e.g., as an attachment).
                 - content_encoding (str): Override the Content-Encoding header.
                 - if_match (str): Perform the write only if the object's current
-                    ETag matches the given one.
+                    ETag matches the given one. Use it for compare-and-swap.
                 - if_none_match (str): Perform the write only if the object's
                     current ETag does NOT match the given one.
                 - if_not_exists (bool): Only write the object if it doesn't
-                    already exist.
+                    already exist, e.g. to create a lock file atomically.
                 - user_metadata (dict[str, str]): Custom user metadata to associate
                     with the object.
+                - extra_headers (dict[str, str]): Extra HTTP headers to send with
+                    the requests, added after they're signed. Headers OpenDAL
+                    sets itself, like `Authorization`, raise `ValueError`.
+
+                Metadata options (`cache_control`, `content_type`,
+                `content_disposition`, `content_encoding` and `user_metadata`) raise
+                `Unsupported` if the service can't persist them.
+
+                Conditions (`if_match`, `if_none_match` and `if_not_exists`)
+                raise `Unsupported` if the service can't check them. A failed
+                condition raises `ConditionNotMatch`.
+
+        Returns
+        -------
+            Metadata: The metadata of the written object as reported by the
+                service, e.g. its `etag` or `version`. Fields the service
+                doesn't return are unset, so `stat` the object if they're
+                needed.
+        """
+    def write_at(self, path: PathBuf, offset: int, bs: BytesLike) -> Metadata:
+        """Overwrite part of an existing object, starting at `offset`.
+
+        Content outside the written range is kept, so a region can be patched
+        without rewriting the whole object. Only services with the
+        `write_with_offset` capability, like `fs`, support this.
+
+        Args:
+            path (str | Path): The path to the object, which must exist.
+            offset (int): The position to start writing at. Must not be past
+                the end of the object.
+            bs (bytes-like): The content to write.
 
         Returns
         -------
-            None
+            Metadata: The metadata of the written object as reported by the
+                service.
+
+        Raises
+        ------
+            Unsupported: If the service can't write at an offset.
+            ValueError: If the write would exceed the service's
+                `write_total_max_size`.
+            NotFound: If the object doesn't exist.
+            RangeNotSatisfied: If `offset` is past the end of the object.
         """
-    def stat(self, path: PathBuf, **kwargs) -> Metadata:
+    def stat(
+        self, path: PathBuf, *, timeout: float | timedelta | None = None, **kwargs
+    ) -> Metadata:
         """Get the metadata of the object at the given path.
 
         Args:
             path (str | Path): The path to the object.
+            timeout (float | timedelta): Raise `Unexpected` if the call takes
+                longer than this many seconds, cancelling the request.
             **kwargs (Any): Optional stat parameters matching the
                 [OpenDAL `StatOptions`](https://opendal.apache.org/docs/rust/opendal/options/struct.StatOptions.html):
 
@@ -166,14 +603,21 @@ class Operator(_Base):
                 - if_none_match (str): Read-only if the ETag does not match the
                     given value.
                 - if_modified_since (datetime): Only read if the object was modified
-                    since this timestamp. This timestamp must be in UTC.
+                    since this timestamp. The timestamp must be timezone-aware.
                 - if_unmodified_since (datetime): Only read if the object was not
-                    modified since this timestamp. This timestamp must be in UTC.
+                    modified since this timestamp. The timestamp must be
+                    timezone-aware.
                 - cache_control (str): Override the cache-control header for the object.
                 - content_type (str): Explicitly set the Content-Type header for
                     the object.
                 - content_disposition (str): Sets how the object should be presented
                     (e.g., as an attachment).
+                - extra_headers (dict[str, str]): Extra HTTP headers to send with
+                    the requests, added after they're signed. Headers OpenDAL
+                    sets itself, like `Authorization`, raise `ValueError`.
+
+                Raises `ConditionNotMatch` if a condition fails.
+
         Returns
         -------
             Metadata: The metadata of the object.
@@ -181,14 +625,46 @@ class Operator(_Base):
     def create_dir(self, path: PathBuf) -> None:
         """Create a directory at the given path.
 
+        Parent directories are created as needed and an existing directory is
+        not an error. On services where directories are virtual, like object
+        stores, an empty object marks the directory so that it can be listed.
+
         Args:
-            path (str|Path): The path to the directory.
+            path (str|Path): The path to the directory, ending with `/`. Pass a
+                `str`, since `pathlib.Path` drops the trailing `/`.
+
+        Raises
+        ------
+            NotADirectory: If the path doesn't end with `/`.
         """
-    def delete(self, path: PathBuf) -> None:
+    def delete(
+        self, path: PathBuf, *, version: str | None = None, missing_ok: bool = True
+    ) -> None:
         """Delete the object at the given path.
 
         Args:
             path (str|Path): The path to the object.
+            version (str): Delete this version of the object instead of the
+                current one. Raises `Unsupported` if the service can't.
+            missing_ok (bool): If `True`, deleting an object that doesn't exist
+                succeeds. If `False`, it raises `NotFound`. Most services
+                don't report a missing object on delete, so the path is
+                stat'ed first, and a concurrent delete between the two can
+                still make it succeed.
+        """
+    def delete_iter(self, paths: Iterable[PathBuf]) -> DeleteResult:
+        """Delete every path of the given iterable.
+
+        Paths are grouped into batch requests of up to the service's
+        `delete_max_size`, falling back to one request per path otherwise.
+        A failed path doesn't stop the others.
+
+        Args:
+            paths (Iterable[str|Path]): The paths to delete.
+
+        Returns
+        -------
+            DeleteResult: The deleted paths and the failures per path.
         """
     def exists(self, path: PathBuf) -> bool:
         """Check if the object at the given path exists.
@@ -199,36 +675,66 @@ class Operator(_Base):
         Returns
         -------
             True if the object exists, False otherwise.
+
+        Raises
+        ------
+            Error: If the check fails for any reason other than the object
+                being missing, e.g. `PermissionDenied`.
         """
-    def list(self, path: PathBuf, **kwargs) -> Iterable[Entry]:
+    def list(
+        self, path: PathBuf, *, timeout: float | timedelta | None = None, **kwargs
+    ) -> BlockingLister:
         """List objects at the given path.
 
         Args:
             path (str | Path): The path to the directory/ prefix.
+            timeout (float | timedelta): Raise `Unexpected` if listing, including
+                iterating the returned lister, takes longer than this many
+                seconds, cancelling the request.
             **kwargs (Any): Optional listing parameters matching the
                 [OpenDAL `ListOptions`](https://opendal.apache.org/docs/rust/opendal/options/struct.ListOptions.html):
 
-                - limit (int): The limit passed to the underlying service to specify the
-                    max results that could return per-request. Users could use this to
-                    control the memory usage of list operation. If not set, all matching
-                    entries will be listed.
-                - start_after (str): Start listing after this key. Useful for pagination
-                    or resuming interrupted listings.
+                - limit (int): The maximum number of entries to yield, also passed
+                    to the service as the page size. Must be positive. If not set,
+                    all matching entries will be listed.
+                - start_after (str): Start listing after this key, exclusive. Together
+                    with `limit` this fetches one page at a time. Raises
+                    `Unsupported` if the service can't start after a key.
                 - recursive (bool): Whether to list entries recursively through all
                     subdirectories. If False, lists only top-level entries (entries
                     under the given path).
-                - versions (bool): Whether to include all versions of objects, if the
-                    underlying service supports versioning.
-                - deleted (bool): Whether to include deleted objects, if the underlying
-                    service supports soft-deletes or versioning.
+                - versions (bool): Whether to include all versions of objects. Each
+                    entry's `metadata.version` and `metadata.is_current` tell them
+                    apart. Raises `Unsupported` if the service can't list versions.
+                - deleted (bool): Whether to include deleted objects, flagged by
+                    `metadata.is_deleted`. Raises `Unsupported` if the service can't
+                    list them.
+                - dirs_only (bool): Only yield the directories directly under the
+                    given path, like the common prefixes of an object store, and
+                    not the path itself. Raises `ValueError` with `recursive`.
+
+        Each entry carries the metadata the service returns while listing, so
+        no extra `stat()` is needed for it. What's available differs per
+        service:
+
+        - s3, gcs and azblob: `content_length`, `etag`, `content_md5` and
+          `last_modified`, plus `content_type` on gcs and azblob.
+        - fs and other services without listing metadata: only `mode`, with
+          `content_length` left as 0.
+
+        Call `stat()` on an entry's path when a field you need is missing.
+
+        Pages are fetched from the service only as the iterator advances, so
+        `for entry in op.list(prefix):` walks huge prefixes without holding
+        them in memory. The iterator raises `StopIteration` once exhausted.
 
         Returns
         -------
-            Iterable[Entry]: An iterable of entries representing the objects in the
+            BlockingLister: A lazy iterator of entries representing the objects in the
                 directory or prefix.
         """
     @deprecated("Use `list()` instead.")
-    def scan(self, path: PathBuf, **kwargs) -> Iterable[Entry]:
+    def scan(self, path: PathBuf, **kwargs) -> BlockingLister:
         """Scan the objects at the given path recursively.
 
         Args:
@@ -239,11 +745,139 @@ class Operator(_Base):
 
         Returns
         -------
-            Iterable[Entry]: An iterable of all entries under the given path,
+            BlockingLister: A lazy iterator of all entries under the given path,
                 recursively traversing all subdirectories. Each entry represents
                 an object (e.g., file or directory) discovered within the full
                 descendant hierarchy of the specified path.
         """
+    def glob(self, pattern: str) -> BlockingLister:
+        """List the entries matching a glob pattern.
+
+        Only the literal prefix before the first wildcard, like `logs/2024-`
+        of `logs/2024-*/**.json`, is listed from the service, recursively if
+        the rest of the pattern spans directories. The entries are filtered
+        while iterating, so nothing is loaded upfront.
+
+        `*`, `?` and `[...]` match within a directory, while `**` crosses
+        directory boundaries and `**/` also matches no directory at all.
+        Directories match without their trailing `/`. Escape a wildcard with
+        a backslash to match it literally.
+
+        Args:
+            pattern (str): The glob pattern, relative to the root.
+
+        Returns
+        -------
+            BlockingLister: A lazy iterator of the matching entries.
+
+        Raises
+        ------
+            ValueError: If the pattern is invalid, like an unclosed `[`.
+        """
+    def tree(
+        self, path: PathBuf, *, max_depth: int | None = None
+    ) -> dict[str, Any]:
+        """Return the entries below a directory as a nested tree.
+
+        Every node is a dict with the `name` and `path` of the entry. Files
+        carry their `metadata`, directories a list of `children` sorted by
+        name. Directories the service doesn't list, like the parents of
+        objects in a bucket, are filled in.
+
+        Without `max_depth` the whole tree is fetched with one recursive
+        listing. With it, directories are listed level by level and the ones
+        `max_depth` levels below `path` get `None` for `children`.
+
+        Args:
+            path (str|Path): The directory to start from.
+            max_depth (int | None): How many levels below `path` to list.
+
+        Returns
+        -------
+            dict[str, Any]: The node of `path`.
+
+        Raises
+        ------
+            ValueError: If `max_depth` isn't positive.
+        """
+    def presign_stat(self, path: PathBuf, expire_second: Duration) -> PresignedRequest:
+        """Generate a presigned URL for stat operation.
+
+        Args:
+            path (str|Path): The path to the object.
+            expire_second (int | float | timedelta): The expiration time, in
+                seconds or as a `timedelta`.
+
+        Returns
+        -------
+            A presigned request object.
+        """
+    def presign_read(self, path: PathBuf, expire_second: Duration) -> PresignedRequest:
+        """Generate a presigned URL for read operation.
+
+        Args:
+            path (str|Path): The path to the object.
+            expire_second (int | float | timedelta): The expiration time, in
+                seconds or as a `timedelta`.
+
+        Returns
+        -------
+            A presigned request object.
+        """
+    def presign_write(
+        self, path: PathBuf, expire_second: Duration
+    ) -> PresignedRequest:
+        """Generate a presigned URL for write operation.
+
+        Args:
+            path (str|Path): The path to the object.
+            expire_second (int | float | timedelta): The expiration time, in
+                seconds or as a `timedelta`.
+
+        Returns
+        -------
+            A presigned request object.
+        """
+    def presign_delete(
+        self, path: PathBuf, expire_second: Duration
+    ) -> PresignedRequest:
+        """Generate a presigned URL for delete operation.
+
+        Args:
+            path (str|Path): The path to the object.
+            expire_second (int | float | timedelta): The expiration time, in
+                seconds or as a `timedelta`.
+
+        Returns
+        -------
+            A presigned request object.
+        """
+    def presign(
+        self,
+        path: PathBuf,
+        operation: Literal["read", "write", "stat", "delete"],
+        expire: Duration,
+    ) -> PresignedRequest:
+        """Generate a presigned request for the given operation.
+
+        Args:
+            path (str|Path): The path to the object.
+            operation (str): The operation to sign, `"read"` for a `GET`,
+                `"write"` for a `PUT`, `"stat"` for a `HEAD` or `"delete"`
+                for a `DELETE`.
+            expire (int | float | timedelta): The expiration time, in seconds
+                or as a `timedelta`.
+
+        Returns
+        -------
+            A presigned request object with the `method`, `url` and
+            `headers` to send.
+
+        Raises
+        ------
+            ValueError: If `operation` isn't one of the above.
+            Unsupported: If the service can't presign the operation.
+        """
     def capability(self) -> Capability:
         """Get the capability of the operator.
 
@@ -251,30 +885,180 @@ class Operator(_Base):
         -------
             The capability of the operator.
         """
+    def require(self, **flags: bool) -> None:
+        """Raise `Unsupported` unless every given capability flag is supported.
+
+        Library code can validate an operator handed in by its user at setup
+        time instead of failing on the first unsupported call.
+
+        Args:
+            **flags (bool): Capability flags, like `read=True` or
+                `write_can_append=True`. Flags set to `False` aren't required.
+
+        Raises
+        ------
+            Unsupported: Naming every required flag the service lacks.
+            TypeError: For an unknown flag or a limit like `delete_max_size`.
+        """
+    def info(self) -> OperatorInfo:
+        """Get the information of the operator.
+
+        Returns
+        -------
+            The scheme, root, name and capability of the operator.
+        """
     def copy(self, source: PathBuf, target: PathBuf) -> None:
         """Copy the object from source to target.
 
+        The target is overwritten if it exists.
+
         Args:
             source (str|Path): The source path.
             target (str|Path): The target path.
         """
-    def rename(self, source: PathBuf, target: PathBuf) -> None:
+    def copy_from(
+        self, source_op: Operator, source: PathBuf, target: PathBuf
+    ) -> None:
+        """Copy `source` of `source_op` to `target` of this operator.
+
+        The service copies the object itself, without the content passing
+        through this process, when both operators are built with the same
+        scheme and options apart from `root`, the service supports copy and
+        the root of one operator contains both paths. Otherwise the content
+        is streamed from `source_op` to the target.
+
+        Services are split across packages, each with its own `Operator`
+        class, so `source_op` may come from another package, like an
+        `Operator("dropbox")` copied to an `Operator("s3")`. It is then read
+        through its `open`.
+
+        The target is overwritten if it exists.
+
+        Args:
+            source_op (Operator): The operator to copy from.
+            source (str|Path): The source path in `source_op`.
+            target (str|Path): The target path in this operator.
+        """
+    def rename(
+        self, source: PathBuf, target: PathBuf, *, emulate: bool = False
+    ) -> None:
         """Rename the object from source to target.
 
+        The target is overwritten if it exists. Raises `Unsupported` if the
+        service can't rename, unless `emulate` is set.
+
         Args:
             source (str|Path): The source path.
             target (str|Path): The target path.
+            emulate (bool): If the service can't rename, emulate it by
+                copying to the target, checking the copy against the source
+                and then deleting the source. This is not atomic, and the
+                source is kept if any step fails. Raises `Unsupported` if
+                the service also lacks copy, stat or delete.
+        """
+    def put_tags(self, path: PathBuf, tags: dict[str, str]) -> None:
+        """Replace the tags of the object at the given path.
+
+        Tags missing from `tags` are removed, so an empty dict clears them.
+        Tags follow the rules of S3 object tagging: at most 10 tags, keys of
+        1 to 128 and values of up to 256 characters, made of letters, digits,
+        whitespace and `+ - = . _ : / @`. Keys can't start with `aws:`.
+
+        Args:
+            path (str | Path): The path to the object.
+            tags (dict[str, str]): The tags to set.
+
+        Raises
+        ------
+            Unsupported: If the service doesn't support tagging.
+            ValueError: If a tag breaks the rules above.
+        """
+    def get_tags(self, path: PathBuf) -> dict[str, str]:
+        """Get the tags of the object at the given path.
+
+        Args:
+            path (str | Path): The path to the object.
+
+        Returns
+        -------
+            dict[str, str]: The tags of the object, empty if it has none.
+
+        Raises
+        ------
+            Unsupported: If the service doesn't support tagging.
+        """
+    def remove_all(
+        self,
+        path: PathBuf,
+        *,
+        dry_run: bool = False,
+        on_delete: Callable[[list[str]], object] | None = None,
+    ) -> int | list[str]:
+        """Remove all objects at the given path recursively.
+
+        Uses batch delete when the service supports it, falling back to
+        deleting entries one by one. Removing a prefix that doesn't exist,
+        such as a virtual directory on an object store, is a no-op.
+
+        Args:
+            path (str|Path): The path to the directory.
+            dry_run (bool): List the paths that would be deleted without
+                deleting anything.
+            on_delete (Callable[[list[str]], object] | None): Called with
+                the paths of each batch once it's deleted. An exception
+                raised by it stops the removal.
+
+        Returns
+        -------
+            The number of deleted paths, or the paths that would be deleted
+            when `dry_run` is set.
         """
-    def remove_all(self, path: PathBuf) -> None:
+    def check(self) -> None:
+        """Check that the operator can reach its service.
+
+        This sends a lightweight list request on the root, so misconfigured
+        endpoints or credentials are caught at startup instead of on the
+        first real request. A root that doesn't exist yet isn't an error.
+
+        Raises
+        ------
+            Error: The mapped exception, e.g. `PermissionDenied`, if the
+                service can't be reached.
+        """
+    def to_async_operator(self) -> AsyncOperator:
         """Convert into an async operator."""
-    def to_async_operator(self) -> AsyncOperator: ...
 
 @final
 class AsyncOperator(_Base):
     """The entry class for all public async APIs.
 
+    Pickling rebuilds the operator from `scheme` and `options`, so it can be
+    passed to `multiprocessing` workers. The options, including any
+    credentials, are stored in the pickle in plain text, so only send it to
+    processes you trust. Layers are not carried over and must be added again,
+    while `copy.copy` and `copy.deepcopy` keep them. Forked processes can't
+    use the runtime of a parent that already ran operations, so prefer the
+    `spawn` or `forkserver` start methods.
+
+    Cancelling the awaitable of a call, for example through
+    `asyncio.wait_for` or `Task.cancel`, stops the underlying request and
+    closes its connection, so no work keeps running in the background.
+
     Args:
         scheme (str): The service name that OpenDAL supports.
+        http_options (HttpOptions): Settings of the HTTP client, like a proxy or
+            a private CA. Ignored by services that don't use HTTP. Raises
+            `ValueError` for an invalid proxy URL or a file without
+            certificates.
+        credential_provider (CredentialProvider): A callable returning
+            `(access_key, secret_key, session_token, expiry)`, called to sign
+            requests instead of static credentials. The result is cached until
+            two minutes before `expiry`, a timezone-aware `datetime` or `None`
+            for no expiry, and fetched again once the service rejects it with
+            401 or 403. Calls that can be sent again, like `stat` or `read`,
+            are retried once with the fresh credentials. Only `s3` supports
+            it, other services raise `Unsupported`. Pickling the operator
+            pickles the callable too.
         **options (any): The options for the service.
             See the documentation of each service for more details.
 
@@ -287,21 +1071,70 @@ class AsyncOperator(_Base):
         ```
     """
 
-    def __init__(self, scheme: str, **options: Any) -> None: ...
-    def layer(self, layer: Layer) -> AsyncOperator: ...
+    def __init__(
+        self,
+        scheme: str,
+        *,
+        http_options: HttpOptions | None = None,
+        credential_provider: CredentialProvider | None = None,
+        **options: Any,
+    ) -> None: ...
+    @staticmethod
+    def from_uri(
+        uri: str,
+        *,
+        http_options: HttpOptions | None = None,
+        credential_provider: CredentialProvider | None = None,
+        **options: Any,
+    ) -> AsyncOperator:
+        """Create a new operator from a URI.
+
+        See `Operator.from_uri`.
+        """
+    def layer(self, layer: Layer) -> AsyncOperator:
+        """Add new layers upon the current operator.
+
+        Like `Operator.layer`, this returns a new operator and leaves the
+        current one unchanged.
+        """
+    def with_default_write_options(self, **options: Any) -> AsyncOperator:
+        """Apply the given write options to every write of a new operator.
+
+        See `Operator.with_default_write_options`.
+        """
+    def with_chunk_sizes(
+        self, *, read_chunk: int | None = None, write_chunk: int | None = None
+    ) -> AsyncOperator:
+        """Read and write in chunks of the given sizes with a new operator.
+
+        See `Operator.with_chunk_sizes`.
+        """
     async def open(self, path: PathBuf, mode: str, **options: Any) -> AsyncFile:
         """Open a file at the given path for reading or writing.
 
         Args:
             path (str | Path): The path to the file.
-            mode (str): The mode to open the file. Must be either `"rb"` for reading or
-                `"wb"` for writing.
+            mode (str): The mode to open the file. Must be `"rb"` for reading,
+                `"wb"` for writing or `"ab"` for appending. Appending raises
+                `Unsupported` if the service can't append.
             **options (Any): Additional options passed to the underlying OpenDAL reader
                 or writer.
                 - If `mode == "rb"`: options match the
                   [OpenDAL `ReaderOptions`](https://opendal.apache.org/docs/rust/opendal/options/struct.ReaderOptions.html).
-                - If `mode == "wb"`: options match the
+                  Pass `chunk=SIZE` with `concurrent=N` to prefetch up to `N`
+                  chunks of `SIZE` bytes ahead of the read position. Reads are
+                  served from the prefetched chunks first, and seeking outside
+                  of them discards the prefetch.
+                  Pass `range=(start, end)` to read only the bytes from
+                  `start` up to, but not including, `end`. The file then
+                  reports EOF at `end`, and `seek` and `tell` positions are
+                  relative to `start`.
+                - If `mode == "wb"` or `mode == "ab"`: options match the
                   [OpenDAL `WriteOptions`](https://opendal.apache.org/docs/rust/opendal/options/struct.WriteOptions.html).
+                `extra_headers` raises `ValueError`, the file sends its requests
+                after `open` returns.
+                `strategy="single"` raises `ValueError` too, since the file
+                streams what's written instead of holding it.
 
         Returns
         -------
@@ -314,13 +1147,89 @@ class AsyncOperator(_Base):
             op = opendal.AsyncOperator("s3", bucket="bucket", region="us-east-1")
             async with await op.open("hello.txt", "wb") as f:
                 await f.write(b"hello world")
+            async with await op.open(
+                "data.parquet", "rb", concurrent=8, chunk=8 * 1024 * 1024
+            ) as f:
+                data = await f.read()
+            ```
+        """
+    @staticmethod
+    def from_env(
+        scheme: str,
+        prefix: str = "OPENDAL",
+        *,
+        http_options: HttpOptions | None = None,
+        credential_provider: CredentialProvider | None = None,
+        **options: Any,
+    ) -> AsyncOperator:
+        """Create a new operator from environment variables.
+
+        The variables named `{PREFIX}_{SCHEME}_{KEY}`, like
+        `OPENDAL_S3_BUCKET`, are collected and `KEY` is lowercased into the
+        config key, so `OPENDAL_S3_REGION` sets `region`. Keyword arguments
+        override the values read from the environment.
+
+        A missing bucket or container, like `OPENDAL_S3_BUCKET`, or the
+        root of `fs` raises `ConfigInvalid` naming its variable. Other
+        config errors come from the service and name the config key.
+
+        Args:
+            scheme (str): The scheme of the service, like `"s3"`.
+            prefix (str): The prefix of the variables. Pass `""` to read
+                variables like `S3_BUCKET`.
+            http_options (HttpOptions | None): See `Operator`.
+            credential_provider (CredentialProvider | None): See `Operator`.
+            **options (Any): Additional service options.
+
+        Returns
+        -------
+            The new operator.
+
+        Raises
+        ------
+            ConfigInvalid: If a config key the service requires, like the
+                bucket of `s3`, is missing. The message names its variable.
+
+        Example:
+            ```python
+            import opendal
+
+            # With OPENDAL_S3_BUCKET and OPENDAL_S3_REGION set.
+            op = opendal.AsyncOperator.from_env("s3")
             ```
         """
-    async def read(self, path: PathBuf, **options: Any) -> bytes:
+    async def read(
+        self,
+        path: PathBuf,
+        *,
+        progress: ProgressCallback | None = None,
+        buffer_protocol: bool = False,
+        verify: bool = False,
+        require_checksum: bool = False,
+        timeout: float | timedelta | None = None,
+        **options: Any,
+    ) -> bytes | Buffer:
         """Read the content of the object at the given path.
 
         Args:
             path (str | Path): The path to the object.
+            progress (Callable[[int, int | None], object]): Called with
+                `(bytes_so_far, total)` as the content arrives. `total` is the
+                content length to read. An exception raised by the callback
+                aborts the read and propagates. The callback runs outside the
+                event loop and may use a blocking `Operator`.
+            buffer_protocol (bool): If True, return a `Buffer` that shares the
+                memory read from the service instead of copying it into
+                `bytes`. Useful to feed large reads into numpy or pyarrow.
+            verify (bool): If True, check the content against the MD5 the
+                service reports as `content_md5`, and raise `ChecksumMismatch`
+                if it differs. Etags aren't trusted as checksums, so objects
+                without a `content_md5` are returned unchecked. Can't be
+                combined with `offset` or `size`.
+            require_checksum (bool): Like `verify`, but raise
+                `ChecksumMismatch` if the object has no checksum either.
+            timeout (float | timedelta): Raise `Unexpected` if the call takes
+                longer than this many seconds, cancelling the request.
             **options (Any): Optional read parameters matching the
                 [OpenDAL `ReadOptions`](https://opendal.apache.org/docs/rust/opendal/options/struct.ReadOptions.html):
 
@@ -329,11 +1238,15 @@ class AsyncOperator(_Base):
                 - size (int): Number of bytes to read. If not specified, reads until
                     the end of the object.
                   Together, `offset` and `size` define the byte range for reading.
+                  A range past the end of the object is truncated, so an
+                  `offset` beyond EOF returns empty bytes. The object is only
+                  stat-ed to truncate the range once the service refuses it.
                 - version (str): Specify the version of the object to read, if
                     supported by the backend.
-                - concurrent (int): Level of concurrency for reading. Defaults to
-                    backend-specific value.
-                - chunk (int): Read chunk size in bytes.
+                - concurrent (int): Number of ranged reads to run at once,
+                    reassembled in order. Defaults to 1.
+                - chunk (int): Size in bytes of each ranged read. Defaults to
+                    8 MiB when `concurrent` is above 1.
                 - gap (int): Minimum gap (in bytes) between chunks to consider
                     them separate.
                 - override_content_type (str): Override the returned content type.
@@ -341,27 +1254,171 @@ class AsyncOperator(_Base):
                 - if_none_match (str): Read-only if the ETag does not match the
                     given value.
                 - if_modified_since (datetime): Only read if the object was modified
-                    since this timestamp. This timestamp must be in UTC.
+                    since this timestamp. The timestamp must be timezone-aware.
                 - if_unmodified_since (datetime): Only read if the object was not
-                    modified since this timestamp. This timestamp must be in UTC.
+                    modified since this timestamp. The timestamp must be
+                    timezone-aware.
+                - extra_headers (dict[str, str]): Extra HTTP headers to send with
+                    the requests, added after they're signed. Headers OpenDAL
+                    sets itself, like `Authorization`, raise `ValueError`.
+
+                Raises `ConditionNotMatch` if a condition fails.
+
+        Returns
+        -------
+            bytes | Buffer: The content of the object, as a `Buffer` if
+                `buffer_protocol` is set.
+        """
+    async def read_stream(self, path: PathBuf, **options: Any) -> BufferReader:
+        """Read the whole object into memory as a file-like object.
+
+        The returned `BufferReader` shares the memory the content was read
+        into, so it can be handed to parsers expecting a file without copying
+        it into `io.BytesIO` first.
+
+        Args:
+            path (str|Path): The path to the object.
+            **options (Any): The same options as `read`.
+
+        Returns
+        -------
+            BufferReader: A readable and seekable file-like object.
+        """
+    async def reader_with_size(
+        self, path: PathBuf, **options: Any
+    ) -> tuple[int | None, AsyncFile]:
+        """Open a reader for the object along with the number of bytes it yields.
+
+        Suits responses that need a `Content-Length` before streaming the
+        content, like a Starlette `StreamingResponse`, without a separate
+        `stat`.
+
+        Args:
+            path (str|Path): The path to the object.
+            **options (Any): The read options of `open` in `"rb"` mode.
+                `extra_headers` raises `ValueError`.
+
+        Returns
+        -------
+            tuple[int | None, AsyncFile]: The size and a reader that can be
+                iterated with `async for` in chunks. The size is looked up with
+                a stat and accounts for `range`, `offset` and `size`, which are
+                clamped to the object like `read` does. It's `None` if the
+                service can't stat.
+        """
+    async def read_if_modified(
+        self, path: PathBuf, since: datetime
+    ) -> tuple[Metadata, bytes | None]:
+        """Read the object unless it hasn't changed since `since`.
+
+        Services supporting it are sent a conditional read with
+        `if_modified_since`, and a `304 Not Modified` is returned as `None`
+        instead of raising. Other services compare the object's
+        `last_modified` to `since`, to the second.
+
+        Args:
+            path (str|Path): The path to the object.
+            since (datetime): A timezone-aware datetime, like the
+                `last_modified` of a cached copy.
+
+        Returns
+        -------
+            tuple[Metadata, bytes | None]: The metadata of the object and its
+                content, or `None` if it wasn't modified after `since`.
+        """
+    async def read_with_metadata(
+        self, path: PathBuf, **options: Any
+    ) -> tuple[Metadata, bytes]:
+        """Read the whole object along with the metadata of what was read.
+
+        For services speaking HTTP, the metadata, like `etag`,
+        `content_length`, `content_type` and `last_modified`, is parsed from
+        the headers of the same request that returned the content, so it
+        can't describe another version of the object, unlike a `stat` before
+        the `read`. Other services, like `fs`, stat the object after reading
+        it.
+
+        Args:
+            path (str|Path): The path to the object.
+            **options (Any): The read options of `read`, but `range`, `offset`
+                and `size`, which raise `ValueError`.
+
+        Returns
+        -------
+            tuple[Metadata, bytes]: The metadata of the object and its content.
+        """
+    async def read_ranges(
+        self, path: PathBuf, ranges: list[tuple[int, int]], **options: Any
+    ) -> list[bytes]:
+        """Read several byte ranges of the object at once.
+
+        Ranges that overlap or are less than `gap` bytes apart are merged into
+        one ranged read, and up to `concurrent` reads are sent at once, which
+        suits formats like Parquet that read a footer and a few column chunks.
+
+        Args:
+            path (str|Path): The path to the object.
+            ranges (list[tuple[int, int]]): The `(offset, length)` of each
+                range to read.
+            **options (Any): The read options of `read`, like `version` or
+                `if_match`, but `range`, `offset` and `size`, which raise
+                `ValueError`. `gap` defaults to 1 MiB and `concurrent` to 16.
 
         Returns
         -------
-            The content of the object as bytes.
+            list[bytes]: The content of each range, in the order of `ranges`.
+                Like `read`, the ranges are clamped to the size of the object,
+                so ones reaching past its end come back short.
         """
-    async def write(self, path: PathBuf, bs: bytes, **options: Any) -> None:
+    async def write(
+        self,
+        path: PathBuf,
+        bs: BytesLike | Iterable[BytesLike] | AsyncIterable[BytesLike],
+        *,
+        progress: ProgressCallback | None = None,
+        timeout: float | timedelta | None = None,
+        **options: Any,
+    ) -> Metadata:
         """Write the content to the object at the given path.
 
         Args:
             path (str | Path): The path to the object.
-            bs (bytes): The content to write.
+            bs (bytes-like | Iterable | AsyncIterable): The content to write.
+                Any contiguous one-dimensional object implementing the buffer
+                protocol is accepted, like `bytearray`, `memoryview` or a numpy
+                array, without copying it as a whole. An async iterable, like
+                an async generator, or an iterable of them is streamed chunk by
+                chunk. The next chunk is only pulled once the previous one is
+                written, so a slow service pauses the producer. If the
+                iterable raises, the upload is aborted. Content sent in a
+                single request, see `strategy`, is copied or held first.
+            progress (Callable[[int, int | None], object]): Called with
+                `(bytes_so_far, total)` as the content is written. `total` is
+                the length of `bs`, or `None` for an iterable. An exception
+                raised by the callback aborts the write and propagates. The
+                callback runs outside the event loop and may use a blocking
+                `Operator`.
+            timeout (float | timedelta): Raise `Unexpected` if the call takes
+                longer than this many seconds, cancelling the request.
             **options (Any): Optional write parameters matching the
                 [OpenDAL `WriteOptions`](https://opendal.apache.org/docs/rust/opendal/options/struct.WriteOptions.html):
 
                 - append (bool): If True, append to the object instead of overwriting.
+                    Raises `Unsupported` if the service can't append.
                 - chunk (int): Specify the chunk size in bytes for multipart uploads.
+                    Raises `ValueError` if outside the service's
+                    `write_multi_min_size` and `write_multi_max_size`.
                 - concurrent (int): Number of concurrent upload parts. Larger values can
                     improve performance.
+                - strategy (str): How to send the content. `"auto"`, the
+                    default, sends content fitting in one part in a single
+                    request and uploads larger content, or an iterable, in
+                    parts of `chunk` bytes, 8 MiB if unset, `concurrent` at a
+                    time, 4 if unset. Services that can't upload in parts get
+                    a single request, with an iterable buffered first.
+                    `"single"` and `"multipart"` force either way, and
+                    `"multipart"` raises `Unsupported` if the service can't
+                    upload in parts.
                 - cache_control (str): Override the cache-control header for the object.
                 - content_type (str): Explicitly set the Content-Type header for
                     the object.
@@ -369,23 +1426,119 @@ class AsyncOperator(_Base):
                     (e.g., as an attachment).
                 - content_encoding (str): Override the Content-Encoding header.
                 - if_match (str): Perform the write only if the object's current
-                    ETag matches the given one.
+                    ETag matches the given one. Use it for compare-and-swap.
                 - if_none_match (str): Perform the write only if the object's
                     current ETag does NOT match the given one.
                 - if_not_exists (bool): Only write the object if it doesn't
-                    already exist.
+                    already exist, e.g. to create a lock file atomically.
                 - user_metadata (dict[str, str]): Custom user metadata to associate
                     with the object.
+                - extra_headers (dict[str, str]): Extra HTTP headers to send with
+                    the requests, added after they're signed. Headers OpenDAL
+                    sets itself, like `Authorization`, raise `ValueError`.
+
+                Metadata options (`cache_control`, `content_type`,
+                `content_disposition`, `content_encoding` and `user_metadata`) raise
+                `Unsupported` if the service can't persist them.
+
+                Conditions (`if_match`, `if_none_match` and `if_not_exists`)
+                raise `Unsupported` if the service can't check them. A failed
+                condition raises `ConditionNotMatch`.
+
+        Returns
+        -------
+            Metadata: The metadata of the written object as reported by the
+                service, e.g. its `etag` or `version`. Fields the service
+                doesn't return are unset, so `stat` the object if they're
+                needed.
+        """
+    async def write_at(self, path: PathBuf, offset: int, bs: BytesLike) -> Metadata:
+        """Overwrite part of an existing object, starting at `offset`.
+
+        Content outside the written range is kept, so a region can be patched
+        without rewriting the whole object. Only services with the
+        `write_with_offset` capability, like `fs`, support this.
+
+        Args:
+            path (str | Path): The path to the object, which must exist.
+            offset (int): The position to start writing at. Must not be past
+                the end of the object.
+            bs (bytes-like): The content to write.
+
+        Returns
+        -------
+            Metadata: The metadata of the written object as reported by the
+                service.
+
+        Raises
+        ------
+            Unsupported: If the service can't write at an offset.
+            ValueError: If the write would exceed the service's
+                `write_total_max_size`.
+            NotFound: If the object doesn't exist.
+            RangeNotSatisfied: If `offset` is past the end of the object.
+        """
+    async def read_to_file(
+        self,
+        path: PathBuf,
+        local_path: PathBuf,
+        *,
+        verify: bool = False,
+        require_checksum: bool = False,
+        **options: Any,
+    ) -> None:
+        """Download the object at the given path into a local file.
+
+        The object is streamed to disk without being buffered in memory. If
+        the transfer fails midway, the partial local file is removed.
+
+        Large objects download faster with `concurrent`, which fetches
+        `chunk`-sized ranges at once and writes them to the file in order,
+        holding at most `concurrent` chunks in memory.
+
+        Args:
+            path (str | Path): The path to the object.
+            local_path (str | Path): The local file to write to. It's
+                overwritten if it exists.
+            verify (bool): Check the content while streaming, like `read`.
+                The local file is removed on a mismatch.
+            require_checksum (bool): Like `verify`, but also fail if the
+                object has no checksum.
+            **options (Any): Read options like for `read`, such as
+                `concurrent`, `chunk`, `version` or `if_match`. Ranges aren't
+                accepted, the whole object is always downloaded.
+
+        Raises
+        ------
+            ValueError: If a range is given.
+        """
+    async def write_from_file(
+        self, path: PathBuf, local_path: PathBuf, **options: Any
+    ) -> Metadata:
+        """Upload a local file to the object at the given path.
+
+        The file is streamed in pieces of `chunk` bytes without being
+        buffered in memory, unless `strategy` sends it in a single request.
+
+        Args:
+            path (str | Path): The path to the object.
+            local_path (str | Path): The local file to read from.
+            **options (Any): Optional write parameters, the same as `write`,
+                e.g. `content_type`.
 
         Returns
         -------
-            None
+            Metadata: The metadata of the written object, like `write`.
         """
-    async def stat(self, path: PathBuf, **kwargs) -> Metadata:
+    async def stat(
+        self, path: PathBuf, *, timeout: float | timedelta | None = None, **kwargs
+    ) -> Metadata:
         """Get the metadata of the object at the given path.
 
         Args:
             path (str | Path): The path to the object.
+            timeout (float | timedelta): Raise `Unexpected` if the call takes
+                longer than this many seconds, cancelling the request.
             **kwargs (Any): Optional stat parameters matching the
                 [OpenDAL `StatOptions`](https://opendal.apache.org/docs/rust/opendal/options/struct.StatOptions.html):
 
@@ -395,29 +1548,85 @@ class AsyncOperator(_Base):
                 - if_none_match (str): Read-only if the ETag does not match the
                     given value.
                 - if_modified_since (datetime): Only read if the object was modified
-                    since this timestamp. This timestamp must be in UTC.
+                    since this timestamp. The timestamp must be timezone-aware.
                 - if_unmodified_since (datetime): Only read if the object was not
-                    modified since this timestamp. This timestamp must be in UTC.
+                    modified since this timestamp. The timestamp must be
+                    timezone-aware.
                 - cache_control (str): Override the cache-control header for the object.
                 - content_type (str): Explicitly set the Content-Type header for
                     the object.
                 - content_disposition (str): Sets how the object should be presented
                     (e.g., as an attachment).
+                - extra_headers (dict[str, str]): Extra HTTP headers to send with
+                    the requests, added after they're signed. Headers OpenDAL
+                    sets itself, like `Authorization`, raise `ValueError`.
+
+                Raises `ConditionNotMatch` if a condition fails.
+
         Returns
         -------
             Metadata: The metadata of the object.
         """
+    async def stat_many(
+        self, paths: Iterable[PathBuf], *, concurrency: int = 16
+    ) -> list[Metadata | exceptions.Error]:
+        """Get the metadata of every object of the given iterable.
+
+        Unlike `asyncio.gather` over `stat`, a failed path doesn't abort the
+        others: its exception is returned in place of its metadata.
+
+        Args:
+            paths (Iterable[str|Path]): The paths to stat.
+            concurrency (int): The maximum number of stats in flight at once.
+
+        Returns
+        -------
+            list[Metadata | Error]: The metadata or the exception of each
+                path, in the order of `paths`.
+        """
     async def create_dir(self, path: PathBuf) -> None:
         """Create a directory at the given path.
 
+        Parent directories are created as needed and an existing directory is
+        not an error. On services where directories are virtual, like object
+        stores, an empty object marks the directory so that it can be listed.
+
         Args:
-            path (str|Path): The path to the directory.
+            path (str|Path): The path to the directory, ending with `/`. Pass a
+                `str`, since `pathlib.Path` drops the trailing `/`.
+
+        Raises
+        ------
+            NotADirectory: If the path doesn't end with `/`.
         """
-    async def delete(self, path: PathBuf) -> None:
+    async def delete(
+        self, path: PathBuf, *, version: str | None = None, missing_ok: bool = True
+    ) -> None:
         """Delete the object at the given path.
 
         Args:
             path (str|Path): The path to the object.
+            version (str): Delete this version of the object instead of the
+                current one. Raises `Unsupported` if the service can't.
+            missing_ok (bool): If `True`, deleting an object that doesn't exist
+                succeeds. If `False`, it raises `NotFound`. Most services
+                don't report a missing object on delete, so the path is
+                stat'ed first, and a concurrent delete between the two can
+                still make it succeed.
+        """
+    async def delete_iter(self, paths: Iterable[PathBuf]) -> DeleteResult:
+        """Delete every path of the given iterable.
+
+        Paths are grouped into batch requests of up to the service's
+        `delete_max_size`, falling back to one request per path otherwise.
+        A failed path doesn't stop the others.
+
+        Args:
+            paths (Iterable[str|Path]): The paths to delete.
+
+        Returns
+        -------
+            DeleteResult: The deleted paths and the failures per path.
         """
     async def exists(self, path: PathBuf) -> bool:
         """Check if the object at the given path exists.
@@ -428,36 +1637,62 @@ class AsyncOperator(_Base):
         Returns
         -------
             True if the object exists, False otherwise.
+
+        Raises
+        ------
+            Error: If the check fails for any reason other than the object
+                being missing, e.g. `PermissionDenied`.
         """
-    async def list(self, path: PathBuf, **kwargs) -> AsyncIterable[Entry]:
+    async def list(
+        self, path: PathBuf, *, timeout: float | timedelta | None = None, **kwargs
+    ) -> AsyncLister:
         """List objects at the given path.
 
         Args:
             path (str | Path): The path to the directory/ prefix.
+            timeout (float | timedelta): Raise `Unexpected` if listing, including
+                iterating the returned lister, takes longer than this many
+                seconds, cancelling the request.
             **kwargs (Any): Optional listing parameters matching the
                 [OpenDAL `ListOptions`](https://opendal.apache.org/docs/rust/opendal/options/struct.ListOptions.html):
 
-                - limit (int): The limit passed to the underlying service to specify the
-                    max results that could return per-request. Users could use this to
-                    control the memory usage of list operation. If not set, all matching
-                    entries will be listed.
-                - start_after (str): Start listing after this key. Useful for pagination
-                    or resuming interrupted listings.
+                - limit (int): The maximum number of entries to yield, also passed
+                    to the service as the page size. Must be positive. If not set,
+                    all matching entries will be listed.
+                - start_after (str): Start listing after this key, exclusive. Together
+                    with `limit` this fetches one page at a time. Raises
+                    `Unsupported` if the service can't start after a key.
                 - recursive (bool): Whether to list entries recursively through all
                     subdirectories. If False, lists only top-level entries (entries
                     under the given path).
-                - versions (bool): Whether to include all versions of objects, if the
-                    underlying service supports versioning.
-                - deleted (bool): Whether to include deleted objects, if the underlying
-                    service supports soft-deletes or versioning.
+                - versions (bool): Whether to include all versions of objects. Each
+                    entry's `metadata.version` and `metadata.is_current` tell them
+                    apart. Raises `Unsupported` if the service can't list versions.
+                - deleted (bool): Whether to include deleted objects, flagged by
+                    `metadata.is_deleted`. Raises `Unsupported` if the service can't
+                    list them.
+                - dirs_only (bool): Only yield the directories directly under the
+                    given path, like the common prefixes of an object store, and
+                    not the path itself. Raises `ValueError` with `recursive`.
+
+        Each entry carries the metadata the service returns while listing, so
+        no extra `stat()` is needed for it. What's available differs per
+        service:
+
+        - s3, gcs and azblob: `content_length`, `etag`, `content_md5` and
+          `last_modified`, plus `content_type` on gcs and azblob.
+        - fs and other services without listing metadata: only `mode`, with
+          `content_length` left as 0.
+
+        Call `stat()` on an entry's path when a field you need is missing.
 
         Returns
         -------
-            Iterable[Entry]: An iterable of entries representing the objects in the
-                directory or prefix.
+            AsyncLister: A lazy async iterator of entries representing the objects
+                in the directory or prefix.
         """
     @deprecated("Use `list()` instead.")
-    async def scan(self, path: PathBuf, **kwargs) -> AsyncIterable[Entry]:
+    async def scan(self, path: PathBuf, **kwargs) -> AsyncLister:
         """Scan the objects at the given path recursively.
 
 
@@ -469,79 +1704,268 @@ class AsyncOperator(_Base):
 
         Returns
         -------
-            Iterable[Entry]: An iterable of all entries under the given path,
+            AsyncLister: A lazy async iterator of all entries under the given path,
                 recursively traversing all subdirectories. Each entry represents
                 an object (e.g., file or directory) discovered within the full
                 descendant hierarchy of the specified path.
         """
-    async def presign_stat(self, path: PathBuf, expire_second: int) -> PresignedRequest:
+    async def glob(self, pattern: str) -> AsyncLister:
+        """List the entries matching a glob pattern.
+
+        Only the literal prefix before the first wildcard, like `logs/2024-`
+        of `logs/2024-*/**.json`, is listed from the service, recursively if
+        the rest of the pattern spans directories. The entries are filtered
+        while iterating, so nothing is loaded upfront.
+
+        `*`, `?` and `[...]` match within a directory, while `**` crosses
+        directory boundaries and `**/` also matches no directory at all.
+        Directories match without their trailing `/`. Escape a wildcard with
+        a backslash to match it literally.
+
+        Args:
+            pattern (str): The glob pattern, relative to the root.
+
+        Returns
+        -------
+            AsyncLister: A lazy iterator of the matching entries.
+
+        Raises
+        ------
+            ValueError: If the pattern is invalid, like an unclosed `[`.
+        """
+    async def tree(
+        self, path: PathBuf, *, max_depth: int | None = None
+    ) -> dict[str, Any]:
+        """Return the entries below a directory as a nested tree.
+
+        Every node is a dict with the `name` and `path` of the entry. Files
+        carry their `metadata`, directories a list of `children` sorted by
+        name. Directories the service doesn't list, like the parents of
+        objects in a bucket, are filled in.
+
+        Without `max_depth` the whole tree is fetched with one recursive
+        listing. With it, directories are listed level by level and the ones
+        `max_depth` levels below `path` get `None` for `children`.
+
+        Args:
+            path (str|Path): The directory to start from.
+            max_depth (int | None): How many levels below `path` to list.
+
+        Returns
+        -------
+            dict[str, Any]: The node of `path`.
+
+        Raises
+        ------
+            ValueError: If `max_depth` isn't positive.
+        """
+    async def presign_stat(
+        self, path: PathBuf, expire_second: Duration
+    ) -> PresignedRequest:
         """Generate a presigned URL for stat operation.
 
         Args:
             path (str|Path): The path to the object.
-            expire_second (int): The expiration time in seconds.
+            expire_second (int | float | timedelta): The expiration time, in
+                seconds or as a `timedelta`.
 
         Returns
         -------
             A presigned request object.
         """
-    async def presign_read(self, path: PathBuf, expire_second: int) -> PresignedRequest:
+    async def presign_read(
+        self, path: PathBuf, expire_second: Duration
+    ) -> PresignedRequest:
         """Generate a presigned URL for read operation.
 
         Args:
             path (str|Path): The path to the object.
-            expire_second (int): The expiration time in seconds.
+            expire_second (int | float | timedelta): The expiration time, in
+                seconds or as a `timedelta`.
 
         Returns
         -------
             A presigned request object.
         """
     async def presign_write(
-        self, path: PathBuf, expire_second: int
+        self, path: PathBuf, expire_second: Duration
     ) -> PresignedRequest:
         """Generate a presigned URL for write operation.
 
         Args:
             path (str|Path): The path to the object.
-            expire_second (int): The expiration time in seconds.
+            expire_second (int | float | timedelta): The expiration time, in
+                seconds or as a `timedelta`.
 
         Returns
         -------
             A presigned request object.
         """
     async def presign_delete(
-        self, path: PathBuf, expire_second: int
+        self, path: PathBuf, expire_second: Duration
     ) -> PresignedRequest:
         """Generate a presigned URL for delete operation.
 
         Args:
             path (str|Path): The path to the object.
-            expire_second (int): The expiration time in seconds.
+            expire_second (int | float | timedelta): The expiration time, in
+                seconds or as a `timedelta`.
 
         Returns
         -------
             A presigned request object.
         """
+    async def presign(
+        self,
+        path: PathBuf,
+        operation: Literal["read", "write", "stat", "delete"],
+        expire: Duration,
+    ) -> PresignedRequest:
+        """Generate a presigned request for the given operation.
+
+        Args:
+            path (str|Path): The path to the object.
+            operation (str): The operation to sign, `"read"` for a `GET`,
+                `"write"` for a `PUT`, `"stat"` for a `HEAD` or `"delete"`
+                for a `DELETE`.
+            expire (int | float | timedelta): The expiration time, in seconds
+                or as a `timedelta`.
+
+        Returns
+        -------
+            A presigned request object with the `method`, `url` and
+            `headers` to send.
+
+        Raises
+        ------
+            ValueError: If `operation` isn't one of the above.
+            Unsupported: If the service can't presign the operation.
+        """
     def capability(self) -> Capability: ...
+    def require(self, **flags: bool) -> None:
+        """Raise `Unsupported` unless every given capability flag is supported.
+
+        See `Operator.require`.
+        """
+    def info(self) -> OperatorInfo:
+        """Get the scheme, root, name and capability of the operator."""
+    async def check(self) -> None:
+        """Check that the operator can reach its service.
+
+        This sends a lightweight list request on the root, so misconfigured
+        endpoints or credentials are caught at startup instead of on the
+        first real request. A root that doesn't exist yet isn't an error.
+
+        Raises
+        ------
+            Error: The mapped exception, e.g. `PermissionDenied`, if the
+                service can't be reached.
+        """
     async def copy(self, source: PathBuf, target: PathBuf) -> None:
         """Copy the object from source to target.
 
+        The target is overwritten if it exists.
+
         Args:
             source (str|Path): The source path.
             target (str|Path): The target path.
         """
-    async def rename(self, source: PathBuf, target: PathBuf) -> None:
+    async def copy_from(
+        self, source_op: AsyncOperator, source: PathBuf, target: PathBuf
+    ) -> None:
+        """Copy `source` of `source_op` to `target` of this operator.
+
+        The service copies the object itself, without the content passing
+        through this process, when both operators are built with the same
+        scheme and options apart from `root`, the service supports copy and
+        the root of one operator contains both paths. Otherwise the content
+        is streamed from `source_op` to the target.
+
+        Services are split across packages, each with its own
+        `AsyncOperator` class, so `source_op` may come from another package,
+        like an `AsyncOperator("dropbox")` copied to an
+        `AsyncOperator("s3")`. It is then read through its `open`.
+
+        The target is overwritten if it exists.
+
+        Args:
+            source_op (AsyncOperator): The operator to copy from.
+            source (str|Path): The source path in `source_op`.
+            target (str|Path): The target path in this operator.
+        """
+    async def rename(
+        self, source: PathBuf, target: PathBuf, *, emulate: bool = False
+    ) -> None:
         """Rename the object from source to target.
 
+        The target is overwritten if it exists. Raises `Unsupported` if the
+        service can't rename, unless `emulate` is set.
+
         Args:
             source (str|Path): The source path.
             target (str|Path): The target path.
+            emulate (bool): If the service can't rename, emulate it by
+                copying to the target, checking the copy against the source
+                and then deleting the source. This is not atomic, and the
+                source is kept if any step fails. Raises `Unsupported` if
+                the service also lacks copy, stat or delete.
         """
-    async def remove_all(self, path: PathBuf) -> None:
-        """Remove all objects at the given path.
+    async def put_tags(self, path: PathBuf, tags: dict[str, str]) -> None:
+        """Replace the tags of the object at the given path.
+
+        Tags missing from `tags` are removed, so an empty dict clears them.
+        Tags follow the rules of S3 object tagging: at most 10 tags, keys of
+        1 to 128 and values of up to 256 characters, made of letters, digits,
+        whitespace and `+ - = . _ : / @`. Keys can't start with `aws:`.
+
+        Args:
+            path (str | Path): The path to the object.
+            tags (dict[str, str]): The tags to set.
+
+        Raises
+        ------
+            Unsupported: If the service doesn't support tagging.
+            ValueError: If a tag breaks the rules above.
+        """
+    async def get_tags(self, path: PathBuf) -> dict[str, str]:
+        """Get the tags of the object at the given path.
+
+        Args:
+            path (str | Path): The path to the object.
+
+        Returns
+        -------
+            dict[str, str]: The tags of the object, empty if it has none.
+
+        Raises
+        ------
+            Unsupported: If the service doesn't support tagging.
+        """
+    async def remove_all(
+        self,
+        path: PathBuf,
+        *,
+        dry_run: bool = False,
+        on_delete: Callable[[list[str]], object] | None = None,
+    ) -> int | list[str]:
+        """Remove all objects at the given path recursively.
+
+        Uses batch delete when the service supports it, falling back to
+        deleting entries one by one. Removing a prefix that doesn't exist,
+        such as a virtual directory on an object store, is a no-op.
 
         Args:
             path (str|Path): The path to the directory.
+            dry_run (bool): List the paths that would be deleted without
+                deleting anything.
+            on_delete (Callable[[list[str]], object] | None): Called with
+                the paths of each batch once it's deleted. An exception
+                raised by it stops the removal.
+
+        Returns
+        -------
+            The number of deleted paths, or the paths that would be deleted
+            when `dry_run` is set.
         """
     def to_operator(self) -> Operator: ...
 
@@ -573,22 +1997,58 @@ class File:
         -------
             The line read from the file as bytes.
         """
-    def write(self, bs: bytes) -> None:
+    def read_chunks(self, size: int = 65536) -> FileChunks:
+        """Iterate over the file in chunks of bytes.
+
+        Reading starts at the current position and runs to EOF. Every chunk
+        is `size` bytes long except the last one, which may be shorter.
+
+        Args:
+            size (int): The number of bytes per chunk. Defaults to 64 KiB.
+
+        Returns
+        -------
+            FileChunks: An iterator of `bytes`.
+
+        Raises
+        ------
+            ValueError: If `size` is zero.
+        """
+    def __iter__(self) -> FileChunks:
+        """Iterate over the file in chunks of 64 KiB.
+
+        Unlike Python's built-in files, iteration yields fixed-size chunks
+        rather than lines. Use `readline` to read line by line.
+        """
+    def write(self, bs: BytesLike) -> int:
         """Write the content to the file.
 
         Args:
-            bs (bytes): The content to write.
+            bs (bytes-like): The content to write. Any contiguous
+                one-dimensional object implementing the buffer protocol is
+                accepted.
+
+        Returns
+        -------
+            The number of bytes written.
         """
     def seek(self, pos: int, whence: int = 0) -> int:
         """Set the file's current position.
 
+        Seeking is lazy: nothing is fetched until the next read.
+
         Args:
             pos (int): The position to set.
-            whence (int): The reference point for the position. Can be 0, 1, or 2.
+            whence (int): The reference point for the position. Can be
+                `os.SEEK_SET` (0), `os.SEEK_CUR` (1) or `os.SEEK_END` (2).
 
         Returns
         -------
             The new position in the file.
+
+        Raises
+        ------
+            Unsupported: If the file was opened for writing.
         """
     def tell(self) -> int:
         """Get the current position in the file.
@@ -597,8 +2057,23 @@ class File:
         -------
             The current position in the file.
         """
-    def close(self) -> None:
-        """Close the file."""
+    def close(self) -> Metadata | None:
+        """Close the file.
+
+        Returns
+        -------
+            Metadata | None: For a file opened for writing, the metadata of
+                the written object as reported by the service, e.g. its
+                `etag`. `None` otherwise.
+        """
+    def abort(self) -> None:
+        """Abort the file, discarding what was written instead of committing it.
+
+        For a multipart upload the parts already uploaded are cleaned up, so
+        no incomplete upload is left behind. Single-shot writes haven't sent
+        anything yet, so this only drops the buffered data. Is a no-op if the
+        file isn't opened for writing.
+        """
     def __enter__(self) -> File:
         """Enter the runtime context related to this object."""
     def __exit__(
@@ -607,7 +2082,12 @@ class File:
         exc_value: BaseException | None,
         traceback: TracebackType | None,
     ) -> None:
-        """Exit the runtime context related to this object."""
+        """Exit the runtime context related to this object.
+
+        The file is closed, committing what was written. If the block raised,
+        the file is aborted instead, and the original exception propagates
+        even if aborting fails.
+        """
     @property
     def closed(self) -> bool:
         """Check if the file is closed."""
@@ -615,21 +2095,30 @@ class File:
         """Flush the internal buffer."""
     def readable(self) -> bool:
         """Check if the file is readable."""
-    def readinto(self, buffer: bytes | bytearray) -> int:
+    def readinto(self, buffer: bytearray | memoryview) -> int:
         """Read bytes into a buffer.
 
         Args:
-            buffer (bytes|bytearray): The buffer to read into.
+            buffer (bytearray|memoryview): Any writable, C-contiguous object
+                implementing the buffer protocol. At most `len(buffer)` bytes
+                are read.
 
         Returns
         -------
-            The number of bytes read.
+            The number of bytes read, `0` at EOF.
         """
     def seekable(self) -> bool:
         """Check if the file supports seeking."""
     def writable(self) -> bool:
         """Check if the file is writable."""
 
+@final
+class FileChunks(Iterator[bytes]):
+    """An iterator over the chunks of a `File`, created by `File.read_chunks`."""
+
+    def __iter__(self) -> FileChunks: ...
+    def __next__(self) -> bytes: ...
+
 @final
 class AsyncFile:
     """
@@ -648,22 +2137,66 @@ class AsyncFile:
         -------
             The content of the file as bytes.
         """
-    async def write(self, bs: bytes) -> None:
+    async def readinto(self, buffer: bytearray | memoryview) -> int:
+        """Read bytes into a buffer.
+
+        Args:
+            buffer (bytearray|memoryview): Any writable, C-contiguous object
+                implementing the buffer protocol. At most `len(buffer)` bytes
+                are read.
+
+        Returns
+        -------
+            The number of bytes read, `0` at EOF.
+        """
+    def read_chunks(self, size: int = 65536) -> AsyncFileChunks:
+        """Iterate asynchronously over the file in chunks of bytes.
+
+        Reading starts at the current position and runs to EOF. Every chunk
+        is `size` bytes long except the last one, which may be shorter.
+
+        Args:
+            size (int): The number of bytes per chunk. Defaults to 64 KiB.
+
+        Returns
+        -------
+            AsyncFileChunks: An async iterator of `bytes`.
+
+        Raises
+        ------
+            ValueError: If `size` is zero.
+        """
+    def __aiter__(self) -> AsyncFileChunks:
+        """Iterate asynchronously over the file in chunks of 64 KiB."""
+    async def write(self, bs: BytesLike) -> int:
         """Write the content to the file.
 
         Args:
-            bs (bytes): The content to write.
+            bs (bytes-like): The content to write. Any contiguous
+                one-dimensional object implementing the buffer protocol is
+                accepted.
+
+        Returns
+        -------
+            The number of bytes written.
         """
     async def seek(self, pos: int, whence: int = 0) -> int:
         """Set the file's current position.
 
+        Seeking is lazy: nothing is fetched until the next read.
+
         Args:
             pos (int): The position to set.
-            whence (int): The reference point for the position. Can be 0, 1, or 2.
+            whence (int): The reference point for the position. Can be
+                `os.SEEK_SET` (0), `os.SEEK_CUR` (1) or `os.SEEK_END` (2).
 
         Returns
         -------
             The new position in the file.
+
+        Raises
+        ------
+            Unsupported: If the file was opened for writing.
         """
     async def tell(self) -> int:
         """Get the current position in the file.
@@ -672,17 +2205,34 @@ class AsyncFile:
         -------
             The current position in the file.
         """
-    async def close(self) -> None:
-        """Close the file."""
-    def __aenter__(self) -> AsyncFile:
+    async def close(self) -> Metadata | None:
+        """Close the file.
+
+        Returns
+        -------
+            Metadata | None: For a file opened for writing, the metadata of
+                the written object as reported by the service, e.g. its
+                `etag`. `None` otherwise.
+        """
+    async def abort(self) -> None:
+        """Abort the file, discarding what was written instead of committing it.
+
+        See `File.abort`.
+        """
+    async def __aenter__(self) -> AsyncFile:
         """Enter the runtime context related to this object."""
-    def __aexit__(
+    async def __aexit__(
         self,
         exc_type: type[BaseException] | None,
         exc_value: BaseException | None,
         traceback: TracebackType | None,
     ) -> None:
-        """Exit the runtime context related to this object."""
+        """Exit the runtime context related to this object.
+
+        The file is closed, committing what was written. If the block raised,
+        the file is aborted instead, and the original exception propagates
+        even if aborting fails.
+        """
     @property
     async def closed(self) -> bool:
         """Check if the file is closed."""
@@ -693,6 +2243,30 @@ class AsyncFile:
     async def writable(self) -> bool:
         """Check if the file is writable."""
 
+@final
+class AsyncFileChunks(AsyncIterator[bytes]):
+    """An async iterator over the chunks of an `AsyncFile`.
+
+    Created by `AsyncFile.read_chunks`.
+    """
+
+    def __aiter__(self) -> AsyncFileChunks: ...
+    async def __anext__(self) -> bytes: ...
+
+@final
+class BlockingLister(Iterator[Entry]):
+    """An iterator over listed entries, fetched lazily from the service."""
+
+    def __iter__(self) -> BlockingLister: ...
+    def __next__(self) -> Entry: ...
+
+@final
+class AsyncLister(AsyncIterator[Entry]):
+    """An async iterator over listed entries, fetched lazily from the service."""
+
+    def __aiter__(self) -> AsyncLister: ...
+    async def __anext__(self) -> Entry: ...
+
 @final
 class Entry:
     """An entry in the directory listing."""
@@ -703,9 +2277,22 @@ class Entry:
     @property
     def metadata(self) -> Metadata:
         """The metadata of the entry."""
+    def to_dict(self) -> dict[str, Any]:
+        """Return the path and the fields of `metadata` as a dict.
+
+        See `Metadata.to_dict`.
+        """
 
 @final
 class Metadata:
+    """The metadata of an object.
+
+    Fields that the service doesn't populate are `None`.
+    """
+
+    @property
+    def cache_control(self) -> str | None:
+        """The cache control of the object."""
     @property
     def content_disposition(self) -> str | None:
         """The content disposition of the object."""
@@ -726,27 +2313,51 @@ class Metadata:
         """The ETag of the object."""
     @property
     def mode(self) -> EntryMode:
-        """The mode of the object."""
+        """The mode of the object, `EntryMode.UNKNOWN` if the service can't tell."""
     @property
     def is_file(self) -> bool:
         """Returns `True` if this metadata is for a file."""
     @property
     def is_dir(self) -> bool:
-        """Returns `True` if this metadata is for a directory."""
+        """Returns `True` if this metadata is for a directory.
+
+        Directory markers of object stores, whose paths end with `/`, are
+        directories. Both `is_file` and `is_dir` are `False` for an unknown mode.
+        """
     @property
     def last_modified(self) -> datetime | None:
-        """The last modified time of the object."""
+        """The last modified time of the object, as a timezone-aware datetime in UTC."""
     @property
     def version(self) -> str | None:
         """The version of the object, if available."""
     @property
-    def user_metadata(self) -> str | None:
-        """The user defined metadata of the object."""
+    def is_current(self) -> bool | None:
+        """Whether this is the latest version, or None if the service doesn't tell."""
+    @property
+    def is_deleted(self) -> bool:
+        """Whether this version has been deleted, like a listed delete marker."""
+    @property
+    def user_metadata(self) -> dict[str, str] | None:
+        """The user defined metadata of the object.
+
+        Keys are lowercased and stripped of the service's prefix like
+        `x-amz-meta-`, so they read back the same from every service.
+        """
+    def to_dict(self) -> dict[str, Any]:
+        """Return the fields as a dict of plain types for `json.dumps`.
+
+        `mode` is `"file"`, `"dir"` or `"unknown"` and `last_modified` is an
+        ISO 8601 string, like `"2024-01-02T03:04:05.000000+00:00"`.
+        """
 
 @final
 class EntryMode:
-    def is_file(self) -> bool: ...
-    def is_dir(self) -> bool: ...
+    """The mode of an entry: a file, a directory or unknown."""
+
+    def is_file(self) -> bool:
+        """Returns `True` if this is a file."""
+    def is_dir(self) -> bool:
+        """Returns `True` if this is a directory."""
 
 @final
 class PresignedRequest:
@@ -757,9 +2368,130 @@ class PresignedRequest:
     @property
     def headers(self) -> dict[str, str]: ...
 
+@final
+class DeleteResult:
+    """The outcome of `delete_iter`."""
+
+    @property
+    def deleted(self) -> list[str]:
+        """The paths that have been deleted."""
+    @property
+    def failed(self) -> dict[str, exceptions.Error]:
+        """The paths that failed to delete, mapped to their exception."""
+
+@final
+class Buffer:
+    """A read-only bytes-like object implementing the buffer protocol.
+
+    Returned by `read(..., buffer_protocol=True)`. It shares the memory read
+    from the service, which stays alive as long as the buffer or any
+    `memoryview` on it does.
+    """
+
+    def __len__(self) -> int: ...
+    def __buffer__(self, flags: int, /) -> memoryview: ...
+
+@final
+class BufferReader:
+    """A read-only, seekable file-like object over a `Buffer`.
+
+    Returned by `read_stream`. It works like `io.BytesIO` but shares the
+    memory the content was read into, which stays alive as long as the
+    reader does.
+    """
+
+    def read(self, size: int | None = None) -> bytes:
+        """Read at most `size` bytes, or until EOF if `size` is None or negative.
+
+        Args:
+            size (int): The number of bytes to read.
+
+        Returns
+        -------
+            The bytes read, empty at EOF.
+        """
+    def readinto(self, buffer: bytearray | memoryview) -> int:
+        """Read bytes into a writable buffer.
+
+        Args:
+            buffer (bytearray|memoryview): The buffer to fill.
+
+        Returns
+        -------
+            The number of bytes read.
+        """
+    def seek(self, pos: int, whence: int = 0) -> int:
+        """Change the position to `pos`, relative to `whence`.
+
+        Seeking past the end is allowed, reads there return nothing.
+
+        Args:
+            pos (int): The offset to seek to.
+            whence (int): `0` for the start, `1` for the current position and
+                `2` for the end.
+
+        Returns
+        -------
+            The new absolute position.
+        """
+    def tell(self) -> int:
+        """Return the current position."""
+    def getbuffer(self) -> Buffer:
+        """Return the whole content as a `Buffer`, without copying it."""
+    def close(self) -> None:
+        """Release the content. Reading afterwards raises `ValueError`."""
+    def __enter__(self) -> BufferReader:
+        """Enter the runtime context related to this object."""
+    def __exit__(
+        self,
+        exc_type: type[BaseException] | None,
+        exc_value: BaseException | None,
+        traceback: TracebackType | None,
+    ) -> None:
+        """Close the reader."""
+    def readable(self) -> bool:
+        """Return True, the reader can be read from."""
+    def writable(self) -> bool:
+        """Return False, the reader can't be written to."""
+    def seekable(self) -> bool:
+        """Return True, the reader can be repositioned."""
+    @property
+    def closed(self) -> bool:
+        """Whether the reader is closed."""
+
+@final
+class OperatorInfo:
+    """The information of an operator, obtained from `Operator.info()`."""
+
+    @property
+    def scheme(self) -> str:
+        """The scheme of the operator, e.g. `s3`."""
+    @property
+    def root(self) -> str:
+        """The root path of the operator."""
+    @property
+    def name(self) -> str:
+        """The name of the operator, e.g. the bucket for s3."""
+    @property
+    def capability(self) -> Capability:
+        """The full capability of the operator."""
+
 @final
 class Capability:
-    """Storage capability information."""
+    """Storage capability information.
+
+    Obtained from `Operator.capability()`. Capabilities compare equal
+    field by field, show every field in `repr()` and can be pickled, so
+    they are easy to log and diff across services.
+    """
+
+    def __init__(self, **kwargs: bool | int | None) -> None:
+        """Build a capability, leaving unspecified fields unset.
+
+        Raises
+        ------
+            TypeError: If an unknown capability is given.
+        """
 
     stat: bool
     """If operator supports stat."""
@@ -770,6 +2502,15 @@ class Capability:
     stat_with_if_none_match: bool
     """If operator supports stat with if none match."""
 
+    stat_with_if_modified_since: bool
+    """If operator supports stat with if modified since."""
+
+    stat_with_if_unmodified_since: bool
+    """If operator supports stat with if unmodified since."""
+
+    stat_with_version: bool
+    """If operator supports stat with version."""
+
     read: bool
     """Indicates if the operator supports read operations."""
 
@@ -809,6 +2550,9 @@ class Capability:
     write_can_append: bool
     """Indicates if append operations are supported."""
 
+    write_with_offset: bool
+    """Indicates if writes can start at an offset of an existing object."""
+
     write_with_content_type: bool
     """Indicates if Content-Type can be specified during write operations."""
 
@@ -848,12 +2592,21 @@ class Capability:
     delete: bool
     """If operator supports delete."""
 
+    delete_max_size: int | None
+    """Maximum number of paths in a single batch delete request."""
+
+    delete_with_version: bool
+    """If operator supports delete with version."""
+
     copy: bool
     """If operator supports copy."""
 
     rename: bool
     """If operator supports rename."""
 
+    tagging: bool
+    """If operator supports setting and reading object tags."""
+
     list: bool
     """If operator supports list."""
 
@@ -866,6 +2619,12 @@ class Capability:
     list_with_recursive: bool
     """If backend supports list with recursive."""
 
+    list_with_versions: bool
+    """If backend supports list with versions included."""
+
+    list_with_deleted: bool
+    """If backend supports list with deleted entries included."""
+
     presign: bool
     """If operator supports presign."""
 
@@ -883,3 +2642,31 @@ class Capability:
 
     shared: bool
     """If operator supports shared."""
+
+@final
+class ReadOptions:
+    """The options of `read`, passed to it as keyword arguments.
+
+    Exported for type checking only, it can't be constructed.
+    """
+
+@final
+class WriteOptions:
+    """The options of `write`, passed to it as keyword arguments.
+
+    Exported for type checking only, it can't be constructed.
+    """
+
+@final
+class ListOptions:
+    """The options of `list`, passed to it as keyword arguments.
+
+    Exported for type checking only, it can't be constructed.
+    """
+
+@final
+class StatOptions:
+    """The options of `stat`, passed to it as keyword arguments.
+
+    Exported for type checking only, it can't be constructed.
+    """
@@ -18,6 +18,13 @@
 class Error(Exception):
     """Base class for exceptions in this module."""
 
+    @property
+    def kind(self) -> str:
+        """The OpenDAL error kind, for example `"NotFound"`."""
+
+    def is_temporary(self) -> bool:
+        """Whether the error is temporary and the operation can be retried."""
+
 class Unexpected(Error):
     """Unexpected errors."""
 
@@ -42,8 +49,26 @@ class NotADirectory(Error):
 class AlreadyExists(Error):
     """Already exists."""
 
+class RateLimited(Error):
+    """Rate limited."""
+
 class IsSameFile(Error):
     """Is same file."""
 
 class ConditionNotMatch(Error):
-    """Condition not match."""
+    """Condition not match.
+
+    Raised when a conditional option such as `if_match` or
+    `if_modified_since` fails, e.g. an HTTP 304 or 412 response.
+    """
+
+class RangeNotSatisfied(Error):
+    """Range not satisfied."""
+
+class ChecksumMismatch(Error):
+    """Checksum mismatch.
+
+    Raised by `read(..., verify=True)` when the content doesn't match the
+    checksum reported by the service, or when no checksum is available and
+    `require_checksum=True`. It's temporary: reading again may succeed.
+    """
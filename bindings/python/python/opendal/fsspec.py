@@ -0,0 +1,286 @@
+# Licensed to the Apache Software Foundation (ASF) under one
+# or more contributor license agreements.  See the NOTICE file
+# distributed with this work for additional information
+# regarding copyright ownership.  The ASF licenses this file
+# to you under the Apache License, Version 2.0 (the
+# "License"); you may not use this file except in compliance
+# with the License.  You may obtain a copy of the License at
+#
+#   http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing,
+# software distributed under the License is distributed on an
+# "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+# KIND, either express or implied.  See the License for the
+# specific language governing permissions and limitations
+# under the License.
+
+
+"""
+fsspec integration for OpenDAL.
+
+Exposes an `Operator` as an fsspec filesystem so that libraries speaking
+fsspec (pandas, pyarrow, dask) can read and write through OpenDAL:
+
+    import pandas as pd
+
+    df = pd.read_parquet(
+        "odal://data/table.parquet",
+        storage_options={"scheme": "s3", "bucket": "bucket", "region": "us-east-1"},
+    )
+
+Requires the optional `fsspec` dependency: `pip install opendal[fsspec]`.
+"""
+
+from typing import Any, Dict, List, Optional, Union
+
+from fsspec.spec import AbstractBufferedFile, AbstractFileSystem
+
+from opendal import Operator
+from opendal.exceptions import NotFound
+
+__all__ = ["OpendalFile", "OpendalFileSystem"]
+
+# Options consumed by `AbstractFileSystem` itself.
+_FS_OPTIONS = (
+    "skip_instance_cache",
+    "use_listings_cache",
+    "listings_expiry_time",
+    "max_paths",
+)
+
+
+class OpendalFileSystem(AbstractFileSystem):
+    """fsspec filesystem delegating to an OpenDAL `Operator`.
+
+    Args:
+        scheme (str): The service name, used when `operator` is not given.
+        operator (Operator): An existing operator to wrap. Takes precedence over
+            `scheme` and `options`.
+        **options (Any): The options for the service, as passed to `Operator`.
+    """
+
+    protocol = ("odal", "opendal")
+    root_marker = ""
+
+    def __init__(
+        self,
+        scheme: str = "memory",
+        operator: Optional[Operator] = None,
+        **options: Any,
+    ) -> None:
+        # Split fsspec's own options off before handing the rest to OpenDAL.
+        fs_options = {key: options.pop(key) for key in _FS_OPTIONS if key in options}
+        super().__init__(**fs_options)
+        if operator is None:
+            operator = Operator(scheme, **options)
+        self.operator = operator
+
+    @classmethod
+    def _strip_protocol(cls, path: Union[str, List[str]]) -> Union[str, List[str]]:
+        if isinstance(path, list):
+            return [cls._strip_protocol(p) for p in path]
+        path = super()._strip_protocol(path)
+        return path.lstrip("/").rstrip("/")
+
+    @staticmethod
+    def _dir(path: str) -> str:
+        return f"{path}/" if path else ""
+
+    def _info_from_metadata(self, path: str, metadata: Any) -> Dict[str, Any]:
+        is_dir = metadata.is_dir
+        info = {
+            "name": path.rstrip("/"),
+            "size": 0 if is_dir else metadata.content_length,
+            "type": "directory" if is_dir else "file",
+        }
+        if metadata.last_modified is not None:
+            info["last_modified"] = metadata.last_modified
+        if metadata.etag is not None:
+            info["etag"] = metadata.etag
+        if metadata.content_type is not None:
+            info["content_type"] = metadata.content_type
+        return info
+
+    def _entry_info(self, entry: Any, detail: bool) -> Dict[str, Any]:
+        metadata = entry.metadata
+        # Some services, like `fs`, list bare entries without sizes. An entry
+        # with a modification time or an etag was listed with its metadata,
+        # so a size of 0 there is an empty file.
+        if (
+            detail
+            and metadata.is_file
+            and not metadata.content_length
+            and metadata.last_modified is None
+            and metadata.etag is None
+        ):
+            metadata = self.operator.stat(entry.path)
+        return self._info_from_metadata(entry.path, metadata)
+
+    def ls(self, path: str, detail: bool = True, **kwargs: Any) -> List[Any]:
+        path = self._strip_protocol(path)
+        prefix = self._dir(path)
+        try:
+            entries = [
+                self._entry_info(entry, detail)
+                for entry in self.operator.list(prefix)
+                # Services may return the listed directory itself.
+                if entry.path != prefix
+            ]
+        except NotFound as e:
+            raise FileNotFoundError(path) from e
+        if not entries and path:
+            # A file or an empty directory; an unknown path raises here.
+            info = self.info(path)
+            entries = [] if info["type"] == "directory" else [info]
+        if detail:
+            return entries
+        return sorted(entry["name"] for entry in entries)
+
+    def info(self, path: str, **kwargs: Any) -> Dict[str, Any]:
+        path = self._strip_protocol(path)
+        if not path:
+            return {"name": "", "size": 0, "type": "directory"}
+        for candidate in (path, self._dir(path)):
+            try:
+                metadata = self.operator.stat(candidate)
+            except NotFound:
+                continue
+            return self._info_from_metadata(candidate, metadata)
+        # Object stores may only know about directories as key prefixes.
+        try:
+            for _ in self.operator.list(self._dir(path), limit=1):
+                return {"name": path, "size": 0, "type": "directory"}
+        except NotFound:
+            pass
+        raise FileNotFoundError(path)
+
+    def exists(self, path: str, **kwargs: Any) -> bool:
+        try:
+            self.info(path)
+        except FileNotFoundError:
+            return False
+        return True
+
+    def cat_file(
+        self,
+        path: str,
+        start: Optional[int] = None,
+        end: Optional[int] = None,
+        **kwargs: Any,
+    ) -> bytes:
+        path = self._strip_protocol(path)
+        if (start or 0) < 0 or (end or 0) < 0:
+            # Negative offsets count from the end of the file.
+            size = self.size(path)
+            if start is not None and start < 0:
+                start = max(size + start, 0)
+            if end is not None and end < 0:
+                end = max(size + end, 0)
+        options: Dict[str, int] = {}
+        if start:
+            options["offset"] = start
+        if end is not None:
+            options["size"] = max(end - (start or 0), 0)
+        try:
+            return self.operator.read(path, **options)
+        except NotFound as e:
+            raise FileNotFoundError(path) from e
+
+    def pipe_file(self, path: str, value: bytes, **kwargs: Any) -> None:
+        self.operator.write(self._strip_protocol(path), value, **kwargs)
+
+    def rm_file(self, path: str) -> None:
+        self.operator.delete(self._strip_protocol(path))
+
+    def rm(
+        self,
+        path: Union[str, List[str]],
+        recursive: bool = False,
+        maxdepth: Optional[int] = None,
+    ) -> None:
+        paths = path if isinstance(path, list) else [path]
+        for p in paths:
+            p = self._strip_protocol(p)
+            if self.info(p)["type"] == "file":
+                self.operator.delete(p)
+            elif recursive:
+                self.operator.remove_all(self._dir(p))
+            else:
+                self.operator.delete(self._dir(p))
+
+    def mkdir(self, path: str, create_parents: bool = True, **kwargs: Any) -> None:
+        self.operator.create_dir(self._dir(self._strip_protocol(path)))
+
+    def makedirs(self, path: str, exist_ok: bool = False) -> None:
+        if not exist_ok and self.exists(path):
+            raise FileExistsError(path)
+        self.mkdir(path)
+
+    def rmdir(self, path: str) -> None:
+        self.operator.delete(self._dir(self._strip_protocol(path)))
+
+    def cp_file(self, path1: str, path2: str, **kwargs: Any) -> None:
+        path1, path2 = self._strip_protocol(path1), self._strip_protocol(path2)
+        self.operator.copy(path1, path2)
+
+    def mv(self, path1: str, path2: str, **kwargs: Any) -> None:
+        path1, path2 = self._strip_protocol(path1), self._strip_protocol(path2)
+        self.operator.rename(path1, path2)
+
+    def modified(self, path: str) -> Any:
+        return self.info(path).get("last_modified")
+
+    def _open(
+        self,
+        path: str,
+        mode: str = "rb",
+        block_size: Optional[int] = None,
+        autocommit: bool = True,
+        cache_options: Optional[Dict[str, Any]] = None,
+        **kwargs: Any,
+    ) -> Any:
+        if mode not in ("rb", "wb"):
+            raise ValueError(f"unsupported mode: {mode!r}")
+        return OpendalFile(
+            self,
+            self._strip_protocol(path),
+            mode,
+            block_size=block_size,
+            autocommit=autocommit,
+            cache_options=cache_options,
+            **kwargs,
+        )
+
+
+class OpendalFile(AbstractBufferedFile):
+    """A file of `OpendalFileSystem`.
+
+    Reads fetch the ranges fsspec's cache asks for, writes stream each block
+    through an OpenDAL writer, which commits the file once it's closed.
+    Options left over by fsspec are passed on to `Operator.read`, or to
+    `Operator.open` when writing, like `content_type`.
+    """
+
+    def _fetch_range(self, start: int, end: int) -> bytes:
+        try:
+            return self.fs.operator.read(
+                self.path, offset=start, size=end - start, **self.kwargs
+            )
+        except NotFound as e:
+            raise FileNotFoundError(self.path) from e
+
+    def _initiate_upload(self) -> None:
+        self._writer = self.fs.operator.open(self.path, "wb", **self.kwargs)
+
+    def _upload_chunk(self, final: bool = False) -> bool:
+        self._writer.write(self.buffer.getvalue())
+        if final and self.autocommit:
+            self.commit()
+        return True
+
+    def commit(self) -> None:
+        self._writer.close()
+
+    def discard(self) -> None:
+        self._writer.abort()
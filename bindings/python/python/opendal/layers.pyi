@@ -15,26 +15,222 @@
 # specific language governing permissions and limitations
 # under the License.
 
-from typing import Optional, final
+from collections.abc import Callable
+from datetime import timedelta
+from typing import Optional, Union, final
+
+from opendal.exceptions import Error
+
+Duration = Union[int, float, timedelta]
 
 class Layer:
     pass
 
 @final
 class RetryLayer(Layer):
+    """Retry failed operations with exponential backoff.
+
+    Args:
+        max_times (int): The maximum number of retries.
+        factor (float): The backoff factor applied between retries.
+        jitter (bool): Whether to add random jitter to each delay.
+        max_delay (int | float | timedelta): The maximum delay between retries,
+            in seconds or as a `timedelta`.
+        min_delay (int | float | timedelta): The minimum delay between retries,
+            in seconds or as a `timedelta`.
+        on_retry (Callable[[Error, int, float], None]): Called before each
+            retry with the error, the number of the attempt that failed
+            starting from 1 and the delay in seconds before the next one.
+            Exceptions it raises are reported through `sys.unraisablehook`
+            instead of stopping the retries. Calls from async operations are
+            made on a background thread.
+    """
+
     def __init__(
         self,
         max_times: Optional[int] = None,
         factor: Optional[float] = None,
         jitter: bool = False,
-        max_delay: Optional[float] = None,
-        min_delay: Optional[float] = None,
+        max_delay: Optional[Duration] = None,
+        min_delay: Optional[Duration] = None,
+        on_retry: Optional[Callable[[Error, int, float], None]] = None,
+    ) -> None: ...
+
+@final
+class TimeoutLayer(Layer):
+    """Fail operations that take too long instead of letting them hang.
+
+    A timed-out operation raises `Unexpected` whose `is_temporary()` is `True`.
+
+    Args:
+        timeout (int | float | timedelta): Timeout for non-IO operations like
+            `stat`, `delete`, `copy` and `list`, as well as opening a reader or
+            writer. Defaults to 60 seconds.
+        io_timeout (int | float | timedelta): Timeout for each IO call on data,
+            like a single read from a reader or a single write to a writer.
+            Defaults to 10 seconds.
+    """
+
+    def __init__(
+        self,
+        timeout: Optional[Duration] = None,
+        io_timeout: Optional[Duration] = None,
     ) -> None: ...
 
 @final
 class ConcurrentLimitLayer(Layer):
+    """Cap the number of operations in flight against the service.
+
+    Operations beyond `limit` wait until a permit is released.
+
+    Args:
+        limit (int): The maximum number of concurrent operations. Must be
+            positive.
+
+    Raises
+    ------
+        ValueError: If `limit` isn't positive.
+    """
+
     def __init__(self, limit: int) -> None: ...
 
 @final
 class MimeGuessLayer(Layer):
+    """Fill in `content_type` from the path's extension.
+
+    For example, writing `report.pdf` sets `application/pdf`. The guess
+    applies to writes and to the metadata of `stat` and `list`, but only
+    when no `content_type` was given or returned by the service, so an
+    explicit `content_type` always wins. Unknown extensions are left unset.
+    """
+
     def __init__(self) -> None: ...
+
+@final
+class ThrottleLayer(Layer):
+    """Cap the bytes per second read from and written to the service.
+
+    Transfers draw from a token bucket that refills at `bandwidth` bytes per
+    second and holds up to `burst` bytes, so short bursts pass without
+    waiting. The bucket is shared by all reads and writes of the operator the
+    layer is applied to, but not across operators.
+
+    Args:
+        bandwidth (int): The sustained rate in bytes per second. Must be
+            positive.
+        burst (int): The number of bytes that may pass at once. Must be
+            positive.
+    """
+
+    def __init__(self, bandwidth: int, burst: int) -> None: ...
+
+@final
+class CacheLayer(Layer):
+    """Serve repeated reads of a path from memory for `ttl` after reading it.
+
+    Whole reads fill the cache, and every read of a cached path, ranged or
+    not, is served from it. Once `max_entries` paths are cached, the one read
+    longest ago is evicted. Reads with a `version` or a condition like
+    `if_match` always go to the service, and objects larger than
+    `max_entry_size` are streamed without being cached.
+
+    Writes, deletes, copies and renames through the operator drop the paths
+    they change from the cache, but changes made by other clients are only
+    seen once the entry expires. Each operator the layer is applied to gets
+    its own cache.
+
+    Args:
+        max_entries (int): The number of paths to keep. Must be positive.
+        ttl (int | float | timedelta): How long a read is served from the
+            cache, in seconds or as a `timedelta`. Must be positive.
+        max_entry_size (int): The size in bytes of the largest object to
+            cache, 8 MiB by default. Must be positive.
+    """
+
+    def __init__(
+        self,
+        max_entries: int,
+        ttl: Duration,
+        max_entry_size: int = 8388608,
+    ) -> None: ...
+
+@final
+class LoggingLayer(Layer):
+    """Route operation logs into Python's `logging` module.
+
+    Records honor the logger's level, so nothing is emitted unless it's
+    enabled. Each record carries `service`, `operation`, `outcome` and the
+    operation's context such as `path` as attributes. Progress is logged at
+    `DEBUG`, failures at `WARNING`, or `ERROR` for unexpected errors.
+
+    Records from async operations are handed to a background thread, so they
+    may reach the handlers shortly after the operation completes.
+
+    Args:
+        name (str): The name of the logger to emit records on.
+    """
+
+    def __init__(self, name: str = "opendal") -> None: ...
+
+@final
+class MetricsLayer(Layer):
+    """Report every read, write, stat, list and delete to a callback.
+
+    The callback is called as `callback(operation, scheme, duration_seconds,
+    outcome)`, where `outcome` is `"ok"`, `"aborted"` for an aborted write, or
+    the kind of the error raised, like `"NotFound"`. Reads, writes and lists
+    are timed from opening them until they're finished, failed or dropped;
+    deletes once per batch sent to the service.
+
+    Callbacks run in order on a background thread, so they never hold up IO
+    but may be called shortly after the operation completes. Exceptions raised
+    by the callback are reported as unraisable.
+
+    Args:
+        callback (Callable[[str, str, float, str], None]): Called once for
+            each request.
+
+    Raises
+    ------
+        TypeError: If `callback` isn't callable.
+    """
+
+    def __init__(self, callback: Callable[[str, str, float, str], None]) -> None: ...
+
+@final
+class TracingLayer(Layer):
+    """Record a span with OpenTelemetry for every operation.
+
+    Spans are named `opendal.<operation>`, like `opendal.read`, and carry the
+    `opendal.scheme`, `opendal.operation` and `opendal.path` attributes. Reads,
+    writes and lists span from opening them until they're finished, failed or
+    dropped; deletes once per batch sent to the service. Failed spans get an
+    error status and the kind of the error as `error.type`.
+
+    Spans nest under the span active in the caller: the calling thread's for
+    blocking operations, the one active when the call was made for async
+    operations. Pass `parent` to nest every span under a given trace instead.
+
+    Spans are recorded on a background thread once the operation completes,
+    with the operation's own timestamps. Requires `opentelemetry-api`.
+
+    Args:
+        tracer (object | None): The tracer to start spans with, defaults to
+            `opentelemetry.trace.get_tracer("opendal")`.
+        parent (tuple[int, int] | object | None): The parent of every span,
+            either a `(trace_id, span_id)` pair or an OpenTelemetry
+            `Context`.
+
+    Raises
+    ------
+        ImportError: If `opentelemetry-api` isn't installed.
+        TypeError: If `parent` isn't a pair of ids or a `Context`.
+        ValueError: If an id of `parent` is zero.
+    """
+
+    def __init__(
+        self,
+        tracer: object | None = None,
+        *,
+        parent: tuple[int, int] | object | None = None,
+    ) -> None: ...
@@ -15,153 +15,223 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use pyo3::exceptions::PyTypeError;
 use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use pyo3::types::PyTuple;
+use pyo3::IntoPyObjectExt;
 
-/// Capability is used to describe what operations are supported
-/// by current Operator.
-#[pyclass(get_all, module = "opendal")]
-pub struct Capability {
+/// Define `Capability` from a single field list so that the constructor,
+/// `repr()` and pickling never drift from the fields themselves.
+macro_rules! capability {
+    ($($(#[$meta:meta])* $name:ident: $ty:ty,)*) => {
+        /// Capability is used to describe what operations are supported
+        /// by current Operator.
+        #[pyclass(get_all, eq, module = "opendal")]
+        #[derive(Clone, Default, PartialEq)]
+        pub struct Capability {
+            $($(#[$meta])* pub $name: $ty,)*
+        }
+
+        impl Capability {
+            pub fn new(capability: opendal::Capability) -> Self {
+                Self {
+                    $($name: capability.$name,)*
+                }
+            }
+
+            fn to_dict<'p>(&self, py: Python<'p>) -> PyResult<Bound<'p, PyDict>> {
+                let dict = PyDict::new(py);
+                $(dict.set_item(stringify!($name), self.$name)?;)*
+                Ok(dict)
+            }
+        }
+
+        #[pymethods]
+        impl Capability {
+            #[new]
+            #[pyo3(signature = (**kwargs))]
+            fn py_new(kwargs: Option<&Bound<PyDict>>) -> PyResult<Self> {
+                let mut this = Self::default();
+                for (key, value) in kwargs.into_iter().flatten() {
+                    match key.extract::<String>()?.as_str() {
+                        $(stringify!($name) => this.$name = value.extract()?,)*
+                        key => {
+                            return Err(PyTypeError::new_err(format!(
+                                "unexpected capability: {key}"
+                            )))
+                        }
+                    }
+                }
+                Ok(this)
+            }
+
+            fn __repr__(&self, py: Python) -> PyResult<String> {
+                let fields = [$(format!(
+                    "{}={}",
+                    stringify!($name),
+                    self.$name.into_pyobject(py)?.repr()?
+                ),)*];
+                Ok(format!("Capability({})", fields.join(", ")))
+            }
+
+            fn __getnewargs_ex__(&self, py: Python) -> PyResult<PyObject> {
+                let args = PyTuple::empty(py).into_py_any(py)?;
+                let kwargs = self.to_dict(py)?.into_py_any(py)?;
+                PyTuple::new(py, [args, kwargs])?.into_py_any(py)
+            }
+        }
+    };
+}
+
+capability! {
     /// If operator supports stat.
-    pub stat: bool,
+    stat: bool,
     /// If operator supports stat with if match.
-    pub stat_with_if_match: bool,
+    stat_with_if_match: bool,
     /// If operator supports stat with if none match.
-    pub stat_with_if_none_match: bool,
+    stat_with_if_none_match: bool,
+    /// If operator supports stat with if modified since.
+    stat_with_if_modified_since: bool,
+    /// If operator supports stat with if unmodified since.
+    stat_with_if_unmodified_since: bool,
+    /// If operator supports stat with version.
+    stat_with_version: bool,
 
     /// Indicates if the operator supports read operations.
-    pub read: bool,
+    read: bool,
     /// Indicates if conditional read operations using If-Match are supported.
-    pub read_with_if_match: bool,
+    read_with_if_match: bool,
     /// Indicates if conditional read operations using If-None-Match are supported.
-    pub read_with_if_none_match: bool,
+    read_with_if_none_match: bool,
     /// Indicates if conditional read operations using If-Modified-Since are supported.
-    pub read_with_if_modified_since: bool,
+    read_with_if_modified_since: bool,
     /// Indicates if conditional read operations using If-Unmodified-Since are supported.
-    pub read_with_if_unmodified_since: bool,
+    read_with_if_unmodified_since: bool,
     /// Indicates if Cache-Control header override is supported during read operations.
-    pub read_with_override_cache_control: bool,
+    read_with_override_cache_control: bool,
     /// Indicates if Content-Disposition header override is supported during read operations.
-    pub read_with_override_content_disposition: bool,
+    read_with_override_content_disposition: bool,
     /// Indicates if Content-Type header override is supported during read operations.
-    pub read_with_override_content_type: bool,
+    read_with_override_content_type: bool,
     /// Indicates if versions read operations are supported.
-    pub read_with_version: bool,
+    read_with_version: bool,
 
     /// Indicates if the operator supports write operations.
-    pub write: bool,
+    write: bool,
     /// Indicates if multiple write operations can be performed on the same object.
-    pub write_can_multi: bool,
+    write_can_multi: bool,
     /// Indicates if writing empty content is supported.
-    pub write_can_empty: bool,
+    write_can_empty: bool,
     /// Indicates if append operations are supported.
-    pub write_can_append: bool,
+    write_can_append: bool,
+    /// Indicates if writes can start at an offset of an existing file, patching it in place.
+    write_with_offset: bool,
     /// Indicates if Content-Type can be specified during write operations.
-    pub write_with_content_type: bool,
+    write_with_content_type: bool,
     /// Indicates if Content-Disposition can be specified during write operations.
-    pub write_with_content_disposition: bool,
+    write_with_content_disposition: bool,
     /// Indicates if Content-Encoding can be specified during write operations.
-    pub write_with_content_encoding: bool,
+    write_with_content_encoding: bool,
     /// Indicates if Cache-Control can be specified during write operations.
-    pub write_with_cache_control: bool,
+    write_with_cache_control: bool,
     /// Indicates if conditional write operations using If-Match are supported.
-    pub write_with_if_match: bool,
+    write_with_if_match: bool,
     /// Indicates if conditional write operations using If-None-Match are supported.
-    pub write_with_if_none_match: bool,
+    write_with_if_none_match: bool,
     /// Indicates if write operations can be conditional on object non-existence.
-    pub write_with_if_not_exists: bool,
+    write_with_if_not_exists: bool,
     /// Indicates if custom user metadata can be attached during write operations.
-    pub write_with_user_metadata: bool,
+    write_with_user_metadata: bool,
     /// Maximum size supported for multipart uploads.
     /// For example, AWS S3 supports up to 5GiB per part in multipart uploads.
-    pub write_multi_max_size: Option<usize>,
+    write_multi_max_size: Option<usize>,
     /// Minimum size required for multipart uploads (except for the last part).
     /// For example, AWS S3 requires at least 5MiB per part.
-    pub write_multi_min_size: Option<usize>,
+    write_multi_min_size: Option<usize>,
     /// Maximum total size supported for write operations.
     /// For example, Cloudflare D1 has a 1MB total size limit.
-    pub write_total_max_size: Option<usize>,
+    write_total_max_size: Option<usize>,
 
     /// If operator supports create dir.
-    pub create_dir: bool,
+    create_dir: bool,
 
     /// If operator supports delete.
-    pub delete: bool,
+    delete: bool,
+    /// Maximum number of paths in a single batch delete request.
+    delete_max_size: Option<usize>,
+    /// If operator supports delete with version.
+    delete_with_version: bool,
 
     /// If operator supports copy.
-    pub copy: bool,
+    copy: bool,
 
     /// If operator supports rename.
-    pub rename: bool,
+    rename: bool,
+
+    /// If operator supports setting and reading object tags.
+    tagging: bool,
 
     /// If operator supports list.
-    pub list: bool,
+    list: bool,
     /// If backend supports list with limit.
-    pub list_with_limit: bool,
+    list_with_limit: bool,
     /// If backend supports list with start after.
-    pub list_with_start_after: bool,
+    list_with_start_after: bool,
     /// If backend supports list without delimiter.
-    pub list_with_recursive: bool,
+    list_with_recursive: bool,
+    /// If backend supports list with versions included.
+    list_with_versions: bool,
+    /// If backend supports list with deleted entries included.
+    list_with_deleted: bool,
 
     /// If operator supports presign.
-    pub presign: bool,
+    presign: bool,
     /// If operator supports presign read.
-    pub presign_read: bool,
+    presign_read: bool,
     /// If operator supports presign stat.
-    pub presign_stat: bool,
+    presign_stat: bool,
     /// If operator supports presign write.
-    pub presign_write: bool,
+    presign_write: bool,
     /// If operator supports presign delete.
-    pub presign_delete: bool,
+    presign_delete: bool,
 
     /// If operator supports shared.
-    pub shared: bool,
+    shared: bool,
 }
 
 impl Capability {
-    pub fn new(capability: opendal::Capability) -> Self {
-        Self {
-            stat: capability.stat,
-            stat_with_if_match: capability.stat_with_if_match,
-            stat_with_if_none_match: capability.stat_with_if_none_match,
-            read: capability.read,
-            read_with_if_match: capability.read_with_if_match,
-            read_with_if_none_match: capability.read_with_if_none_match,
-            read_with_override_cache_control: capability.read_with_override_cache_control,
-            read_with_override_content_disposition: capability
-                .read_with_override_content_disposition,
-            read_with_override_content_type: capability.read_with_override_content_type,
-            read_with_if_modified_since: capability.read_with_if_modified_since,
-            read_with_if_unmodified_since: capability.read_with_if_unmodified_since,
-            read_with_version: capability.read_with_version,
-            write: capability.write,
-            write_can_multi: capability.write_can_multi,
-            write_can_empty: capability.write_can_empty,
-            write_can_append: capability.write_can_append,
-            write_with_content_type: capability.write_with_content_type,
-            write_with_content_disposition: capability.write_with_content_disposition,
-            write_with_cache_control: capability.write_with_cache_control,
-            write_multi_max_size: capability.write_multi_max_size,
-            write_multi_min_size: capability.write_multi_min_size,
-            write_total_max_size: capability.write_total_max_size,
-            write_with_content_encoding: capability.write_with_content_encoding,
-            write_with_if_match: capability.write_with_if_match,
-            write_with_if_none_match: capability.write_with_if_none_match,
-            write_with_if_not_exists: capability.write_with_if_not_exists,
-            write_with_user_metadata: capability.write_with_user_metadata,
-            create_dir: capability.create_dir,
-            delete: capability.delete,
-            copy: capability.copy,
-            rename: capability.rename,
-            list: capability.list,
-            list_with_limit: capability.list_with_limit,
-            list_with_start_after: capability.list_with_start_after,
-            list_with_recursive: capability.list_with_recursive,
-            presign: capability.presign,
-            presign_read: capability.presign_read,
-            presign_stat: capability.presign_stat,
-            presign_write: capability.presign_write,
-            presign_delete: capability.presign_delete,
-            shared: capability.shared,
+    /// Raise `Unsupported` naming each flag in `kwargs` that's required with
+    /// `True` but not supported by `info`'s service.
+    pub fn require(info: &opendal::OperatorInfo, kwargs: Option<&Bound<PyDict>>) -> PyResult<()> {
+        let Some(kwargs) = kwargs else {
+            return Ok(());
+        };
+        let flags = Self::new(info.full_capability()).to_dict(kwargs.py())?;
+        let mut missing = Vec::new();
+        for (key, required) in kwargs {
+            let key = key.extract::<String>()?;
+            let Some(supported) = flags.get_item(&key)? else {
+                return Err(PyTypeError::new_err(format!(
+                    "unexpected capability: {key}"
+                )));
+            };
+            let Ok(supported) = supported.extract::<bool>() else {
+                return Err(PyTypeError::new_err(format!(
+                    "capability {key} is a limit, not a flag"
+                )));
+            };
+            if required.extract::<bool>()? && !supported {
+                missing.push(key);
+            }
+        }
+        if missing.is_empty() {
+            return Ok(());
         }
+        Err(crate::Unsupported::new_err(format!(
+            "service {} doesn't support: {}",
+            info.scheme(),
+            missing.join(", ")
+        )))
     }
 }
@@ -0,0 +1,334 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use chrono::DateTime;
+use chrono::Utc;
+use opendal::raw::*;
+use pyo3::exceptions::PyTypeError;
+use pyo3::prelude::*;
+
+use crate::*;
+
+/// The credential returned by a `credential_provider`: the access key, the
+/// secret key, the session token and when it expires.
+type Credential = (String, String, Option<String>, Option<DateTime<Utc>>);
+
+/// Credentials fetched from a Python callable, for the `credential_provider`
+/// argument of the operators.
+///
+/// The credential is cached until it's about to expire, or until the service
+/// rejects a request with it.
+pub struct CredentialProvider {
+    callback: PyObject,
+    cached: Mutex<Option<Credential>>,
+}
+
+impl CredentialProvider {
+    pub fn new(py: Python, callback: PyObject) -> PyResult<Arc<Self>> {
+        if !callback.bind(py).is_callable() {
+            return Err(PyTypeError::new_err("credential_provider must be callable"));
+        }
+        Ok(Arc::new(Self {
+            callback,
+            cached: Mutex::new(None),
+        }))
+    }
+
+    pub fn callback(&self, py: Python) -> PyObject {
+        self.callback.clone_ref(py)
+    }
+
+    /// Return the cached credential, calling the provider for a fresh one if
+    /// there is none or it expires within two minutes, like the credentials
+    /// the core loads itself.
+    fn load(&self) -> PyResult<Credential> {
+        if let Some(cred) = self.cached.lock().unwrap().as_ref() {
+            if cred
+                .3
+                .is_none_or(|expiry| expiry > Utc::now() + chrono::Duration::minutes(2))
+            {
+                return Ok(cred.clone());
+            }
+        }
+        // The lock isn't held while calling back, a blocking call holding the
+        // GIL could be waiting for it.
+        let cred: Credential = Python::with_gil(|py| self.callback.call0(py)?.extract(py))?;
+        *self.cached.lock().unwrap() = Some(cred.clone());
+        Ok(cred)
+    }
+
+    /// Drop the cached credential, returning whether there was one.
+    fn invalidate(&self) -> bool {
+        self.cached.lock().unwrap().take().is_some()
+    }
+}
+
+/// Hand the credentials of a provider to the signer of the core's S3 service.
+#[cfg(feature = "services-s3")]
+struct AwsCredentialProvider(Arc<CredentialProvider>);
+
+#[cfg(feature = "services-s3")]
+#[async_trait::async_trait]
+impl reqsign::AwsCredentialLoad for AwsCredentialProvider {
+    async fn load_credential(
+        &self,
+        _: reqwest::Client,
+    ) -> anyhow::Result<Option<reqsign::AwsCredential>> {
+        let (access_key_id, secret_access_key, session_token, expires_in) = self
+            .0
+            .load()
+            .map_err(|err| anyhow::anyhow!("credential_provider failed: {err}"))?;
+        Ok(Some(reqsign::AwsCredential {
+            access_key_id,
+            secret_access_key,
+            session_token,
+            expires_in,
+        }))
+    }
+}
+
+/// Build an operator for `scheme` that signs its requests with the
+/// credentials of `provider`. Only S3 can take them for now.
+pub fn build_with_credential(
+    scheme: ocore::Scheme,
+    map: HashMap<String, String>,
+    provider: &Arc<CredentialProvider>,
+) -> PyResult<ocore::Operator> {
+    let op = match scheme {
+        #[cfg(feature = "services-s3")]
+        ocore::Scheme::S3 => {
+            use ocore::Configurator;
+
+            let builder = ocore::services::S3Config::from_iter(map)
+                .map_err(format_pyerr)?
+                .into_builder()
+                .customized_credential_load(Box::new(AwsCredentialProvider(provider.clone())));
+            ocore::Operator::new(builder)
+                .map_err(format_pyerr)?
+                .finish()
+        }
+        _ => {
+            let _ = map;
+            return Err(Unsupported::new_err(format!(
+                "service {scheme} doesn't support credential_provider"
+            )));
+        }
+    };
+    Ok(op.layer(CredentialRefreshLayer(provider.clone())))
+}
+
+/// Fetch fresh credentials from the provider once the service rejects the
+/// cached ones with `PermissionDenied`.
+///
+/// Calls that can be sent again, like `stat`, a `read` or a page of `list`,
+/// are retried once with the fresh credentials. Writes and deletes still
+/// fail, but the next one uses the fresh credentials.
+#[derive(Clone)]
+struct CredentialRefreshLayer(Arc<CredentialProvider>);
+
+impl CredentialRefreshLayer {
+    async fn run<F: Future<Output = ocore::Result<T>>, T>(
+        &self,
+        f: impl Fn() -> F,
+    ) -> ocore::Result<T> {
+        match f().await {
+            Err(err) if self.rejected(&err) => f().await,
+            res => res,
+        }
+    }
+
+    /// Whether `err` rejects the cached credential, dropping it if so.
+    fn rejected(&self, err: &ocore::Error) -> bool {
+        err.kind() == ocore::ErrorKind::PermissionDenied && self.0.invalidate()
+    }
+
+    fn check<T>(&self, res: ocore::Result<T>) -> ocore::Result<T> {
+        if let Err(err) = &res {
+            self.rejected(err);
+        }
+        res
+    }
+}
+
+impl<A: Access> ocore::raw::Layer<A> for CredentialRefreshLayer {
+    type LayeredAccess = CredentialRefreshAccessor<A>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccess {
+        CredentialRefreshAccessor {
+            inner,
+            layer: self.clone(),
+        }
+    }
+}
+
+struct CredentialRefreshAccessor<A: Access> {
+    inner: A,
+    layer: CredentialRefreshLayer,
+}
+
+impl<A: Access> std::fmt::Debug for CredentialRefreshAccessor<A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CredentialRefreshAccessor")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl<A: Access> LayeredAccess for CredentialRefreshAccessor<A> {
+    type Inner = A;
+    type Reader = CredentialRefreshWrapper<A::Reader>;
+    type Writer = CredentialRefreshWrapper<A::Writer>;
+    type Lister = CredentialRefreshWrapper<A::Lister>;
+    type Deleter = CredentialRefreshWrapper<A::Deleter>;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    async fn create_dir(&self, path: &str, args: OpCreateDir) -> ocore::Result<RpCreateDir> {
+        self.layer
+            .run(|| self.inner.create_dir(path, args.clone()))
+            .await
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> ocore::Result<(RpRead, Self::Reader)> {
+        let (rp, r) = self
+            .layer
+            .run(|| self.inner.read(path, args.clone()))
+            .await?;
+        Ok((rp, CredentialRefreshWrapper::new(r, &self.layer)))
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> ocore::Result<(RpWrite, Self::Writer)> {
+        let (rp, w) = self
+            .layer
+            .run(|| self.inner.write(path, args.clone()))
+            .await?;
+        Ok((rp, CredentialRefreshWrapper::new(w, &self.layer)))
+    }
+
+    async fn copy(&self, from: &str, to: &str, args: OpCopy) -> ocore::Result<RpCopy> {
+        self.layer
+            .run(|| self.inner.copy(from, to, args.clone()))
+            .await
+    }
+
+    async fn rename(&self, from: &str, to: &str, args: OpRename) -> ocore::Result<RpRename> {
+        self.layer
+            .run(|| self.inner.rename(from, to, args.clone()))
+            .await
+    }
+
+    async fn stat(&self, path: &str, args: OpStat) -> ocore::Result<RpStat> {
+        self.layer.run(|| self.inner.stat(path, args.clone())).await
+    }
+
+    async fn delete(&self) -> ocore::Result<(RpDelete, Self::Deleter)> {
+        let (rp, d) = self.layer.run(|| self.inner.delete()).await?;
+        Ok((rp, CredentialRefreshWrapper::new(d, &self.layer)))
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> ocore::Result<(RpList, Self::Lister)> {
+        let (rp, l) = self
+            .layer
+            .run(|| self.inner.list(path, args.clone()))
+            .await?;
+        Ok((rp, CredentialRefreshWrapper::new(l, &self.layer)))
+    }
+
+    async fn presign(&self, path: &str, args: OpPresign) -> ocore::Result<RpPresign> {
+        self.layer
+            .run(|| self.inner.presign(path, args.clone()))
+            .await
+    }
+
+    async fn put_tags(&self, path: &str, args: OpPutTags) -> ocore::Result<RpPutTags> {
+        self.layer
+            .run(|| self.inner.put_tags(path, args.clone()))
+            .await
+    }
+
+    async fn get_tags(&self, path: &str, args: OpGetTags) -> ocore::Result<RpGetTags> {
+        self.layer
+            .run(|| self.inner.get_tags(path, args.clone()))
+            .await
+    }
+}
+
+struct CredentialRefreshWrapper<R> {
+    inner: R,
+    layer: CredentialRefreshLayer,
+}
+
+impl<R> CredentialRefreshWrapper<R> {
+    fn new(inner: R, layer: &CredentialRefreshLayer) -> Self {
+        Self {
+            inner,
+            layer: layer.clone(),
+        }
+    }
+}
+
+impl<R: oio::Read> oio::Read for CredentialRefreshWrapper<R> {
+    async fn read(&mut self) -> ocore::Result<ocore::Buffer> {
+        let res = self.inner.read().await;
+        self.layer.check(res)
+    }
+}
+
+impl<R: oio::Write> oio::Write for CredentialRefreshWrapper<R> {
+    async fn write(&mut self, bs: ocore::Buffer) -> ocore::Result<()> {
+        let res = self.inner.write(bs).await;
+        self.layer.check(res)
+    }
+
+    async fn abort(&mut self) -> ocore::Result<()> {
+        let res = self.inner.abort().await;
+        self.layer.check(res)
+    }
+
+    async fn close(&mut self) -> ocore::Result<ocore::Metadata> {
+        let res = self.inner.close().await;
+        self.layer.check(res)
+    }
+}
+
+impl<R: oio::List> oio::List for CredentialRefreshWrapper<R> {
+    async fn next(&mut self) -> ocore::Result<Option<oio::Entry>> {
+        // A page that failed is fetched again by the next call.
+        match self.inner.next().await {
+            Err(err) if self.layer.rejected(&err) => self.inner.next().await,
+            res => res,
+        }
+    }
+}
+
+impl<R: oio::Delete> oio::Delete for CredentialRefreshWrapper<R> {
+    fn delete(&mut self, path: &str, args: OpDelete) -> ocore::Result<()> {
+        self.inner.delete(path, args)
+    }
+
+    async fn flush(&mut self) -> ocore::Result<usize> {
+        let res = self.inner.flush().await;
+        self.layer.check(res)
+    }
+}
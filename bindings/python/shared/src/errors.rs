@@ -18,15 +18,52 @@
 use pyo3::create_exception;
 use pyo3::exceptions::PyException;
 use pyo3::exceptions::PyIOError;
+use pyo3::prelude::*;
 
 use crate::*;
 
-create_exception!(
-    opendal.exceptions,
-    Error,
-    PyException,
-    "OpenDAL Base Exception"
-);
+/// OpenDAL Base Exception
+///
+/// It's raised with `(message, kind, temporary)` as its arguments, so the
+/// error can be handed over without the GIL and instantiated once raised.
+#[pyclass(module = "opendal.exceptions", extends = PyException, subclass)]
+pub struct Error {
+    kind: Option<String>,
+    temporary: bool,
+}
+
+#[pymethods]
+impl Error {
+    #[new]
+    #[pyo3(signature = (message = None, kind = None, temporary = false, /))]
+    fn new(message: Option<PyObject>, kind: Option<String>, temporary: bool) -> Self {
+        let _ = message;
+        Self { kind, temporary }
+    }
+
+    /// The OpenDAL error kind, like `"NotFound"`, or the name of the
+    /// exception class if it wasn't given.
+    #[getter]
+    fn kind(slf: &Bound<Self>) -> PyResult<String> {
+        match &slf.borrow().kind {
+            Some(kind) => Ok(kind.clone()),
+            None => Ok(slf.get_type().name()?.to_string()),
+        }
+    }
+
+    /// Whether the error is temporary and the operation can be retried.
+    fn is_temporary(&self) -> bool {
+        self.temporary
+    }
+
+    fn __str__(slf: &Bound<Self>) -> PyResult<String> {
+        let args = slf.getattr("args")?;
+        match args.get_item(0) {
+            Ok(message) => Ok(message.str()?.to_string()),
+            Err(_) => Ok(String::new()),
+        }
+    }
+}
 create_exception!(opendal.exceptions, Unexpected, Error, "Unexpected errors");
 create_exception!(
     opendal.exceptions,
@@ -50,6 +87,7 @@ create_exception!(
 create_exception!(opendal.exceptions, IsADirectory, Error, "Is a directory");
 create_exception!(opendal.exceptions, NotADirectory, Error, "Not a directory");
 create_exception!(opendal.exceptions, AlreadyExists, Error, "Already exists");
+create_exception!(opendal.exceptions, RateLimited, Error, "Rate limited");
 create_exception!(opendal.exceptions, IsSameFile, Error, "Is same file");
 create_exception!(
     opendal.exceptions,
@@ -57,21 +95,68 @@ create_exception!(
     Error,
     "Condition not match"
 );
+create_exception!(
+    opendal.exceptions,
+    RangeNotSatisfied,
+    Error,
+    "Range not satisfied"
+);
+
+create_exception!(
+    opendal.exceptions,
+    ChecksumMismatch,
+    Error,
+    "Checksum mismatch"
+);
 
+/// Raise `ChecksumMismatch`, which has no OpenDAL error kind of its own.
+///
+/// It's temporary: the data was most likely corrupted in transit, so reading
+/// it again may succeed.
+pub fn checksum_mismatch(message: String) -> PyErr {
+    ChecksumMismatch::new_err((message, "ChecksumMismatch", true))
+}
+
+/// Convert an OpenDAL error into the matching exception subclass, keeping
+/// the original `kind` and whether it `is_temporary()` on the exception.
 fn format_pyerr_impl(err: &ocore::Error) -> PyErr {
-    let e = format!("{err:?}");
-    match err.kind() {
-        ocore::ErrorKind::Unexpected => Unexpected::new_err(e),
-        ocore::ErrorKind::Unsupported => Unsupported::new_err(e),
-        ocore::ErrorKind::ConfigInvalid => ConfigInvalid::new_err(e),
-        ocore::ErrorKind::NotFound => NotFound::new_err(e),
-        ocore::ErrorKind::PermissionDenied => PermissionDenied::new_err(e),
-        ocore::ErrorKind::IsADirectory => IsADirectory::new_err(e),
-        ocore::ErrorKind::NotADirectory => NotADirectory::new_err(e),
-        ocore::ErrorKind::AlreadyExists => AlreadyExists::new_err(e),
-        ocore::ErrorKind::IsSameFile => IsSameFile::new_err(e),
-        ocore::ErrorKind::ConditionNotMatch => ConditionNotMatch::new_err(e),
-        _ => Unexpected::new_err(e),
+    CapturedError::new(err).into_pyerr()
+}
+
+/// What it takes to raise an OpenDAL error, captured without the GIL so the
+/// exception can be created later by the thread that hands it to Python.
+pub struct CapturedError {
+    kind: ocore::ErrorKind,
+    message: String,
+    temporary: bool,
+}
+
+impl CapturedError {
+    pub fn new(err: &ocore::Error) -> Self {
+        Self {
+            kind: err.kind(),
+            message: format!("{err:?}"),
+            temporary: err.is_temporary(),
+        }
+    }
+
+    pub fn into_pyerr(self) -> PyErr {
+        let e = (self.message, self.kind.into_static(), self.temporary);
+        match self.kind {
+            ocore::ErrorKind::Unexpected => Unexpected::new_err(e),
+            ocore::ErrorKind::Unsupported => Unsupported::new_err(e),
+            ocore::ErrorKind::ConfigInvalid => ConfigInvalid::new_err(e),
+            ocore::ErrorKind::NotFound => NotFound::new_err(e),
+            ocore::ErrorKind::PermissionDenied => PermissionDenied::new_err(e),
+            ocore::ErrorKind::IsADirectory => IsADirectory::new_err(e),
+            ocore::ErrorKind::NotADirectory => NotADirectory::new_err(e),
+            ocore::ErrorKind::AlreadyExists => AlreadyExists::new_err(e),
+            ocore::ErrorKind::RateLimited => RateLimited::new_err(e),
+            ocore::ErrorKind::IsSameFile => IsSameFile::new_err(e),
+            ocore::ErrorKind::ConditionNotMatch => ConditionNotMatch::new_err(e),
+            ocore::ErrorKind::RangeNotSatisfied => RangeNotSatisfied::new_err(e),
+            _ => Unexpected::new_err(e),
+        }
     }
 }
 
@@ -28,14 +28,17 @@ use futures::AsyncSeekExt;
 use futures::AsyncWriteExt;
 use pyo3::buffer::PyBuffer;
 use pyo3::exceptions::PyIOError;
+use pyo3::exceptions::PyStopAsyncIteration;
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3::IntoPyObjectExt;
-use pyo3_async_runtimes::tokio::future_into_py;
 use tokio::sync::Mutex;
 
 use crate::*;
 
+/// How many bytes iterating over a file yields at a time.
+const DEFAULT_CHUNK_SIZE: usize = 64 * 1024;
+
 /// A file-like object.
 /// Can be used as a context manager.
 #[pyclass(module = "opendal")]
@@ -82,11 +85,13 @@ impl File {
 
         let buffer = match size {
             Some(size) => {
-                let mut bs = vec![0; size];
-                let n = reader
-                    .read(&mut bs)
+                // Keep reading across chunk boundaries so that a chunked or
+                // prefetching reader only returns less than `size` at EOF.
+                let mut bs = Vec::with_capacity(size);
+                reader
+                    .take(size as u64)
+                    .read_to_end(&mut bs)
                     .map_err(|err| PyIOError::new_err(err.to_string()))?;
-                bs.truncate(n);
                 bs
             }
             None => {
@@ -181,8 +186,10 @@ impl File {
         })
     }
 
-    /// Write bytes into the file.
-    pub fn write(&mut self, bs: &[u8]) -> PyResult<usize> {
+    /// Write a bytes-like object into the file without copying it first.
+    pub fn write(&mut self, bs: &Bound<PyAny>) -> PyResult<usize> {
+        let bs = BytesView::get(bs)?;
+        let bs = bs.as_slice();
         let writer = match &mut self.0 {
             FileState::Reader(_) => {
                 return Err(PyIOError::new_err(
@@ -212,18 +219,16 @@ impl File {
     /// * `SEEK_END` or `2` – end of the stream; offset is usually negative
     ///
     /// Return the new absolute position.
+    ///
+    /// Seeking is lazy: nothing is fetched until the next read. Raise
+    /// `Unsupported` on write only files.
     #[pyo3(signature = (pos, whence = 0))]
     pub fn seek(&mut self, pos: i64, whence: u8) -> PyResult<u64> {
-        if !self.seekable()? {
-            return Err(PyIOError::new_err(
-                "Seek operation is not supported by the backing service.",
-            ));
-        }
         let reader = match &mut self.0 {
             FileState::Reader(r) => r,
             FileState::Writer(_) => {
-                return Err(PyIOError::new_err(
-                    "I/O operation failed for reading on write only file.",
+                return Err(Unsupported::new_err(
+                    "Seek operation is not supported on write only file.",
                 ));
             }
             FileState::Closed => {
@@ -234,6 +239,11 @@ impl File {
         };
 
         let whence = match whence {
+            0 if pos < 0 => {
+                return Err(PyValueError::new_err(format!(
+                    "negative seek position {pos}"
+                )))
+            }
             0 => SeekFrom::Start(pos as u64),
             1 => SeekFrom::Current(pos),
             2 => SeekFrom::End(pos),
@@ -266,25 +276,71 @@ impl File {
             .map_err(|err| PyIOError::new_err(err.to_string()))
     }
 
-    fn close(&mut self) -> PyResult<()> {
+    /// Close the file.
+    ///
+    /// For a file opened for writing, return the `Metadata` of the written
+    /// object as reported by the service, like its etag or version.
+    fn close(&mut self) -> PyResult<Option<Metadata>> {
+        let mut metadata = None;
         if let FileState::Writer(w) = &mut self.0 {
             w.close().map_err(format_pyerr_from_io_error)?;
+            metadata = w.metadata().cloned().map(Metadata::new);
         };
         self.0 = FileState::Closed;
+        Ok(metadata)
+    }
+
+    /// Abort the file, discarding what was written instead of committing it.
+    ///
+    /// For a multipart upload the parts already uploaded are cleaned up, so
+    /// no incomplete upload is left behind. Single-shot writes haven't sent
+    /// anything yet, so this only drops the buffered data. Is a no-op if the
+    /// file isn't opened for writing.
+    fn abort(&mut self) -> PyResult<()> {
+        if let FileState::Writer(w) = &mut self.0 {
+            w.abort().map_err(format_pyerr)?;
+            self.0 = FileState::Closed;
+        }
         Ok(())
     }
 
+    /// Iterate over the file in chunks of `size` bytes, from the current
+    /// position to EOF. Only the last chunk may be shorter.
+    #[pyo3(signature = (size = DEFAULT_CHUNK_SIZE))]
+    pub fn read_chunks(slf: Py<Self>, size: usize) -> PyResult<FileChunks> {
+        if size == 0 {
+            return Err(PyValueError::new_err("size must be a positive integer"));
+        }
+        Ok(FileChunks { file: slf, size })
+    }
+
+    /// Iterate over the file in chunks of 64 KiB, like `read_chunks()`.
+    pub fn __iter__(slf: Py<Self>) -> PyResult<FileChunks> {
+        Self::read_chunks(slf, DEFAULT_CHUNK_SIZE)
+    }
+
     pub fn __enter__(slf: Py<Self>) -> Py<Self> {
         slf
     }
 
+    /// Close the file on exit.
+    ///
+    /// If the block raised, the file is aborted instead so that a partial
+    /// write isn't committed, and the original exception is surfaced instead
+    /// of any error raised while aborting.
     pub fn __exit__(
         &mut self,
-        _exc_type: PyObject,
-        _exc_value: PyObject,
-        _traceback: PyObject,
+        exc_type: &Bound<PyAny>,
+        _exc_value: &Bound<PyAny>,
+        _traceback: &Bound<PyAny>,
     ) -> PyResult<()> {
-        self.close()
+        if exc_type.is_none() {
+            return self.close().map(|_| ());
+        }
+
+        let _ = self.abort();
+        self.0 = FileState::Closed;
+        Ok(())
     }
 
     /// Flush the underlying writer. Is a no-op if the file is opened in reading mode.
@@ -328,6 +384,47 @@ impl File {
     }
 }
 
+/// An iterator over the chunks of a `File`, created by `File.read_chunks()`.
+#[pyclass(module = "opendal")]
+pub struct FileChunks {
+    file: Py<File>,
+    size: usize,
+}
+
+#[pymethods]
+impl FileChunks {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__<'p>(&self, py: Python<'p>) -> PyResult<Option<Bound<'p, PyAny>>> {
+        let mut file = self.file.bind(py).borrow_mut();
+        let reader = match &mut file.0 {
+            FileState::Reader(r) => r,
+            FileState::Writer(_) => {
+                return Err(PyIOError::new_err(
+                    "I/O operation failed for reading on write only file.",
+                ));
+            }
+            FileState::Closed => {
+                return Err(PyIOError::new_err(
+                    "I/O operation failed for reading on closed file.",
+                ));
+            }
+        };
+
+        let mut buffer = Vec::with_capacity(self.size);
+        reader
+            .take(self.size as u64)
+            .read_to_end(&mut buffer)
+            .map_err(|err| PyIOError::new_err(err.to_string()))?;
+        if buffer.is_empty() {
+            return Ok(None);
+        }
+        Buffer::new(buffer).into_bytes_ref(py).map(Some)
+    }
+}
+
 /// A file-like async reader.
 /// Can be used as an async context manager.
 #[pyclass(module = "opendal")]
@@ -374,13 +471,14 @@ impl AsyncFile {
 
             let buffer = match size {
                 Some(size) => {
-                    // TODO: optimize here by using uninit slice.
-                    let mut bs = vec![0; size];
-                    let n = reader
-                        .read(&mut bs)
+                    // Keep reading across chunk boundaries so that a chunked or
+                    // prefetching reader only returns less than `size` at EOF.
+                    let mut bs = Vec::with_capacity(size);
+                    reader
+                        .take(size as u64)
+                        .read_to_end(&mut bs)
                         .await
                         .map_err(|err| PyIOError::new_err(err.to_string()))?;
-                    bs.truncate(n);
                     bs
                 }
                 None => {
@@ -397,14 +495,62 @@ impl AsyncFile {
         })
     }
 
-    /// Write bytes into the file.
-    pub fn write<'p>(&'p mut self, py: Python<'p>, bs: &'p [u8]) -> PyResult<Bound<'p, PyAny>> {
+    /// Read bytes into a pre-allocated, writable buffer.
+    ///
+    /// Return the number of bytes read, `0` at EOF.
+    pub fn readinto<'p>(
+        &'p self,
+        py: Python<'p>,
+        buffer: PyBuffer<u8>,
+    ) -> PyResult<Bound<'p, PyAny>> {
+        if buffer.readonly() {
+            return Err(PyIOError::new_err("Buffer is not writable."));
+        }
+
+        if !buffer.is_c_contiguous() {
+            return Err(PyIOError::new_err("Buffer is not C contiguous."));
+        }
+
         let state = self.0.clone();
+        future_into_py(py, async move {
+            let mut guard = state.lock().await;
+            let reader = match guard.deref_mut() {
+                AsyncFileState::Reader(r) => r,
+                AsyncFileState::Writer(_) => {
+                    return Err(PyIOError::new_err(
+                        "I/O operation failed for reading on write only file.",
+                    ));
+                }
+                _ => {
+                    return Err(PyIOError::new_err(
+                        "I/O operation failed for reading on closed file.",
+                    ));
+                }
+            };
 
-        // FIXME: can we avoid this clone?
-        let bs = bs.to_vec();
+            // The buffer view is held until the read completes, which keeps
+            // the underlying memory alive.
+            let view: &mut [u8] = unsafe {
+                std::slice::from_raw_parts_mut(buffer.buf_ptr() as *mut u8, buffer.len_bytes())
+            };
+            reader
+                .read(view)
+                .await
+                .map_err(|err| PyIOError::new_err(err.to_string()))
+        })
+    }
+
+    /// Write a bytes-like object into the file without copying it first.
+    pub fn write<'p>(
+        &'p mut self,
+        py: Python<'p>,
+        bs: &Bound<PyAny>,
+    ) -> PyResult<Bound<'p, PyAny>> {
+        let state = self.0.clone();
+        let bs = BytesView::get(bs)?;
 
         future_into_py(py, async move {
+            let bs = bs.as_slice();
             let mut guard = state.lock().await;
             let writer = match guard.deref_mut() {
                 AsyncFileState::Reader(_) => {
@@ -422,7 +568,7 @@ impl AsyncFile {
 
             let len = bs.len();
             writer
-                .write_all(&bs)
+                .write_all(bs)
                 .await
                 .map(|_| len)
                 .map_err(|err| PyIOError::new_err(err.to_string()))
@@ -438,6 +584,9 @@ impl AsyncFile {
     /// * `SEEK_END` or `2` – end of the stream; offset is usually negative
     ///
     /// Return the new absolute position.
+    ///
+    /// Seeking is lazy: nothing is fetched until the next read. Raise
+    /// `Unsupported` on write only files.
     #[pyo3(signature = (pos, whence = 0))]
     pub fn seek<'p>(
         &'p mut self,
@@ -448,6 +597,11 @@ impl AsyncFile {
         let state = self.0.clone();
 
         let whence = match whence {
+            0 if pos < 0 => {
+                return Err(PyValueError::new_err(format!(
+                    "negative seek position {pos}"
+                )))
+            }
             0 => SeekFrom::Start(pos as u64),
             1 => SeekFrom::Current(pos),
             2 => SeekFrom::End(pos),
@@ -459,8 +613,8 @@ impl AsyncFile {
             let reader = match guard.deref_mut() {
                 AsyncFileState::Reader(r) => r,
                 AsyncFileState::Writer(_) => {
-                    return Err(PyIOError::new_err(
-                        "I/O operation failed for reading on write only file.",
+                    return Err(Unsupported::new_err(
+                        "Seek operation is not supported on write only file.",
                     ));
                 }
                 _ => {
@@ -508,31 +662,72 @@ impl AsyncFile {
         .and_then(|pos| pos.into_bound_py_any(py))
     }
 
+    /// Close the file.
+    ///
+    /// For a file opened for writing, return the `Metadata` of the written
+    /// object as reported by the service, like its etag or version.
     fn close<'p>(&'p mut self, py: Python<'p>) -> PyResult<Bound<'p, PyAny>> {
         let state = self.0.clone();
-        future_into_py(py, async move {
-            let mut state = state.lock().await;
-            if let AsyncFileState::Writer(w) = &mut *state {
-                w.close().await.map_err(format_pyerr_from_io_error)?;
-            }
-            *state = AsyncFileState::Closed;
-            Ok(())
+        future_into_py(py, async move { close_async_file(&state).await })
+    }
+
+    /// Abort the file, discarding what was written instead of committing it.
+    ///
+    /// See `File.abort`.
+    fn abort<'p>(&'p mut self, py: Python<'p>) -> PyResult<Bound<'p, PyAny>> {
+        let state = self.0.clone();
+        future_into_py(py, async move { abort_async_file(&state).await })
+    }
+
+    /// Iterate asynchronously over the file in chunks of `size` bytes, from
+    /// the current position to EOF. Only the last chunk may be shorter.
+    #[pyo3(signature = (size = DEFAULT_CHUNK_SIZE))]
+    pub fn read_chunks(&self, size: usize) -> PyResult<AsyncFileChunks> {
+        if size == 0 {
+            return Err(PyValueError::new_err("size must be a positive integer"));
+        }
+        Ok(AsyncFileChunks {
+            state: self.0.clone(),
+            size,
         })
     }
 
+    /// Iterate asynchronously over the file in chunks of 64 KiB, like
+    /// `read_chunks()`.
+    pub fn __aiter__(&self) -> PyResult<AsyncFileChunks> {
+        self.read_chunks(DEFAULT_CHUNK_SIZE)
+    }
+
     fn __aenter__<'a>(slf: PyRef<'a, Self>, py: Python<'a>) -> PyResult<Bound<'a, PyAny>> {
         let slf = slf.into_py_any(py)?;
         future_into_py(py, async move { Ok(slf) })
     }
 
+    /// Close the file on exit.
+    ///
+    /// If the block raised, the file is aborted instead so that a partial
+    /// write isn't committed, and the original exception is surfaced instead
+    /// of any error raised while aborting.
     fn __aexit__<'a>(
         &'a mut self,
         py: Python<'a>,
-        _exc_type: &Bound<'a, PyAny>,
+        exc_type: &Bound<'a, PyAny>,
         _exc_value: &Bound<'a, PyAny>,
         _traceback: &Bound<'a, PyAny>,
     ) -> PyResult<Bound<'a, PyAny>> {
-        self.close(py)
+        let state = self.0.clone();
+        if exc_type.is_none() {
+            return future_into_py(
+                py,
+                async move { close_async_file(&state).await.map(|_| ()) },
+            );
+        }
+
+        future_into_py(py, async move {
+            let _ = abort_async_file(&state).await;
+            *state.lock().await = AsyncFileState::Closed;
+            Ok(())
+        })
     }
 
     /// Check if the stream may be read from.
@@ -572,3 +767,70 @@ impl AsyncFile {
         })
     }
 }
+
+async fn close_async_file(state: &Mutex<AsyncFileState>) -> PyResult<Option<Metadata>> {
+    let mut state = state.lock().await;
+    let mut metadata = None;
+    if let AsyncFileState::Writer(w) = &mut *state {
+        w.close().await.map_err(format_pyerr_from_io_error)?;
+        metadata = w.metadata().cloned().map(Metadata::new);
+    }
+    *state = AsyncFileState::Closed;
+    Ok(metadata)
+}
+
+async fn abort_async_file(state: &Mutex<AsyncFileState>) -> PyResult<()> {
+    let mut state = state.lock().await;
+    if let AsyncFileState::Writer(w) = &mut *state {
+        w.abort().await.map_err(format_pyerr)?;
+        *state = AsyncFileState::Closed;
+    }
+    Ok(())
+}
+
+/// An async iterator over the chunks of an `AsyncFile`, created by
+/// `AsyncFile.read_chunks()`.
+#[pyclass(module = "opendal")]
+pub struct AsyncFileChunks {
+    state: Arc<Mutex<AsyncFileState>>,
+    size: usize,
+}
+
+#[pymethods]
+impl AsyncFileChunks {
+    fn __aiter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __anext__<'p>(&self, py: Python<'p>) -> PyResult<Bound<'p, PyAny>> {
+        let state = self.state.clone();
+        let size = self.size;
+        future_into_py(py, async move {
+            let mut guard = state.lock().await;
+            let reader = match guard.deref_mut() {
+                AsyncFileState::Reader(r) => r,
+                AsyncFileState::Writer(_) => {
+                    return Err(PyIOError::new_err(
+                        "I/O operation failed for reading on write only file.",
+                    ));
+                }
+                _ => {
+                    return Err(PyIOError::new_err(
+                        "I/O operation failed for reading on closed file.",
+                    ));
+                }
+            };
+
+            let mut buffer = Vec::with_capacity(size);
+            reader
+                .take(size as u64)
+                .read_to_end(&mut buffer)
+                .await
+                .map_err(|err| PyIOError::new_err(err.to_string()))?;
+            if buffer.is_empty() {
+                return Err(PyStopAsyncIteration::new_err("stream exhausted"));
+            }
+            Python::with_gil(|py| Buffer::new(buffer).into_bytes(py))
+        })
+    }
+}
@@ -0,0 +1,206 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Char(char),
+    /// `?`, any character but `/`.
+    Any,
+    /// `*`, any run of characters without `/`.
+    Star,
+    /// `**`, any run of characters, `/` included.
+    DoubleStar,
+    /// `**/`, any number of whole directories, none included.
+    Dirs,
+    /// `[abc]`, `[a-z]` or `[!a]`, one character but `/`.
+    Class {
+        negated: bool,
+        ranges: Vec<(char, char)>,
+    },
+}
+
+/// A glob pattern over paths, split into the prefix to list and the
+/// matcher to filter the listed entries with.
+///
+/// `*`, `?` and `[...]` stay within a directory, while `**` crosses
+/// directory boundaries and `**/` also matches no directory at all.
+#[derive(Debug, Clone)]
+pub struct Glob {
+    tokens: Vec<Token>,
+    prefix: String,
+}
+
+impl Glob {
+    pub fn new(pattern: &str) -> PyResult<Self> {
+        let tokens = parse(pattern.trim_start_matches('/'))?;
+        // The literal prefix before the first wildcard is listed instead of
+        // the whole tree, so services listing by prefix prune the rest.
+        let prefix = tokens
+            .iter()
+            .map_while(|token| match token {
+                Token::Char(c) => Some(*c),
+                _ => None,
+            })
+            .collect::<String>();
+        Ok(Self { tokens, prefix })
+    }
+
+    /// The prefix to list.
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// Whether entries below the direct children of the directory of the
+    /// prefix can match.
+    pub fn recursive(&self) -> bool {
+        let skip = self.prefix.chars().count();
+        self.tokens[skip..]
+            .iter()
+            .any(|token| matches!(token, Token::Char('/') | Token::DoubleStar | Token::Dirs))
+    }
+
+    /// Whether the listed `path` matches, ignoring the trailing `/` of a
+    /// directory.
+    pub fn is_match(&self, path: &str) -> bool {
+        if self.prefix.ends_with('/') && path == self.prefix {
+            return false;
+        }
+        let path = path.strip_suffix('/').unwrap_or(path);
+        let chars: Vec<char> = path.chars().collect();
+        matches(&self.tokens, &chars)
+    }
+}
+
+fn parse(pattern: &str) -> PyResult<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        let token = match c {
+            '\\' => Token::Char(chars.next().ok_or_else(|| {
+                PyValueError::new_err(format!("glob pattern {pattern:?} ends with an escape"))
+            })?),
+            '?' => Token::Any,
+            '*' if chars.peek() == Some(&'*') => {
+                chars.next();
+                if chars.peek() == Some(&'/') {
+                    chars.next();
+                    Token::Dirs
+                } else {
+                    Token::DoubleStar
+                }
+            }
+            '*' => Token::Star,
+            '[' => {
+                let negated = chars.next_if(|c| *c == '!' || *c == '^').is_some();
+                let mut ranges = Vec::new();
+                let mut closed = false;
+                // A `]` right after the opening bracket is a literal.
+                let mut first = true;
+                while let Some(c) = chars.next() {
+                    if c == ']' && !first {
+                        closed = true;
+                        break;
+                    }
+                    first = false;
+                    let end = match chars.next_if(|c| *c == '-') {
+                        Some(_) => match chars.next_if(|c| *c != ']') {
+                            Some(end) => end,
+                            None => {
+                                ranges.push(('-', '-'));
+                                c
+                            }
+                        },
+                        None => c,
+                    };
+                    ranges.push((c, end));
+                }
+                if !closed {
+                    return Err(PyValueError::new_err(format!(
+                        "glob pattern {pattern:?} has an unclosed `[`"
+                    )));
+                }
+                Token::Class { negated, ranges }
+            }
+            c => Token::Char(c),
+        };
+        tokens.push(token);
+    }
+    Ok(tokens)
+}
+
+/// Match `path` against `tokens` in `O(tokens * path)`, tracking after
+/// each token which prefixes of `path` the tokens so far can match.
+fn matches(tokens: &[Token], path: &[char]) -> bool {
+    let n = path.len();
+    let mut reached = vec![false; n + 1];
+    reached[0] = true;
+    let mut next = vec![false; n + 1];
+    for token in tokens {
+        next.fill(false);
+        match token {
+            Token::Char(c) => {
+                for i in 0..n {
+                    next[i + 1] = reached[i] && path[i] == *c;
+                }
+            }
+            Token::Any => {
+                for i in 0..n {
+                    next[i + 1] = reached[i] && path[i] != '/';
+                }
+            }
+            Token::Class { negated, ranges } => {
+                for i in 0..n {
+                    let c = &path[i];
+                    next[i + 1] = reached[i]
+                        && *c != '/'
+                        && ranges.iter().any(|(lo, hi)| lo <= c && c <= hi) != *negated;
+                }
+            }
+            Token::Star => {
+                // Extend any match by characters up to the next `/`.
+                let mut open = false;
+                for i in 0..=n {
+                    open = (open && path[i - 1] != '/') || reached[i];
+                    next[i] = open;
+                }
+            }
+            Token::DoubleStar => {
+                let mut open = false;
+                for i in 0..=n {
+                    open = open || reached[i];
+                    next[i] = open;
+                }
+            }
+            Token::Dirs => {
+                // Skip no directory, or jump to right after any `/` past a match.
+                let mut open = false;
+                for i in 0..=n {
+                    next[i] = reached[i] || (open && path[i - 1] == '/');
+                    open = open || reached[i];
+                }
+            }
+        }
+        std::mem::swap(&mut reached, &mut next);
+        if !reached.contains(&true) {
+            return false;
+        }
+    }
+    reached[n]
+}
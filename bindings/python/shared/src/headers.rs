@@ -0,0 +1,273 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use http::HeaderMap;
+use http::HeaderName;
+use http::HeaderValue;
+use http::Method;
+use http::Request;
+use http::Response;
+use opendal::raw::*;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::*;
+
+/// Headers the HTTP client and the signer manage, which `extra_headers`
+/// can't override.
+const RESERVED_HEADERS: [&str; 6] = [
+    "authorization",
+    "connection",
+    "content-length",
+    "host",
+    "proxy-authorization",
+    "transfer-encoding",
+];
+/// Prefixes of the headers services expect to be signed. The extra headers
+/// are added after signing, so these would be rejected anyway.
+const RESERVED_PREFIXES: [&str; 3] = ["x-amz-", "x-goog-", "x-ms-"];
+
+/// The `extra_headers` of a call, added to every request it sends.
+pub type ExtraHeaders = Arc<HeaderMap>;
+
+/// Validate the `extra_headers` option, rejecting invalid and reserved
+/// headers.
+pub fn parse_extra_headers(
+    headers: Option<HashMap<String, String>>,
+) -> PyResult<Option<ExtraHeaders>> {
+    let Some(headers) = headers else {
+        return Ok(None);
+    };
+    let mut map = HeaderMap::with_capacity(headers.len());
+    for (name, value) in headers {
+        let key = HeaderName::try_from(name.as_str())
+            .map_err(|_| PyValueError::new_err(format!("invalid header name {name:?}")))?;
+        if RESERVED_HEADERS.contains(&key.as_str())
+            || RESERVED_PREFIXES
+                .iter()
+                .any(|p| key.as_str().starts_with(p))
+        {
+            return Err(PyValueError::new_err(format!(
+                "header {name:?} is set by OpenDAL and can't be passed in extra_headers"
+            )));
+        }
+        let value = HeaderValue::try_from(value.as_str())
+            .map_err(|_| PyValueError::new_err(format!("invalid value of header {name:?}")))?;
+        map.insert(key, value);
+    }
+    Ok(Some(Arc::new(map)))
+}
+
+/// Reject `extra_headers` for calls whose requests outlive the call, like
+/// the reads of a file returned by `open`.
+pub fn reject_extra_headers(
+    headers: &Option<HashMap<String, String>>,
+    method: &str,
+) -> PyResult<()> {
+    if headers.is_some() {
+        return Err(PyValueError::new_err(format!(
+            "{method} doesn't support extra_headers"
+        )));
+    }
+    Ok(())
+}
+
+/// The response headers of the last successful `GET` a call sent.
+pub type ResponseHeaders = Arc<Mutex<Option<HeaderMap>>>;
+
+/// The OpenTelemetry context active in the caller of an async call.
+pub type TraceContext = Arc<PyObject>;
+
+/// What a call hands down to the requests it sends.
+#[derive(Clone, Default)]
+struct Scope {
+    headers: Option<ExtraHeaders>,
+    response: Option<ResponseHeaders>,
+    trace: Option<TraceContext>,
+}
+
+tokio::task_local! {
+    static SCOPE: Scope;
+}
+
+thread_local! {
+    /// The scope of the blocking call running on this thread, whose
+    /// requests are sent from the calling thread itself.
+    static BLOCKING_SCOPE: RefCell<Option<Scope>> = const { RefCell::new(None) };
+}
+
+fn current() -> Option<Scope> {
+    SCOPE
+        .try_with(|scope| scope.clone())
+        .ok()
+        .or_else(|| BLOCKING_SCOPE.with_borrow(|scope| scope.clone()))
+}
+
+fn blocking_scoped<T>(scope: Scope, f: impl FnOnce() -> T) -> T {
+    struct Reset(Option<Scope>);
+
+    impl Drop for Reset {
+        fn drop(&mut self) {
+            BLOCKING_SCOPE.set(self.0.take());
+        }
+    }
+
+    let _reset = Reset(BLOCKING_SCOPE.replace(Some(scope)));
+    f()
+}
+
+/// Run `f` outside the scope of the call it's nested in, like a callback
+/// that may send requests of its own.
+pub fn unscoped<T>(f: impl FnOnce() -> T) -> T {
+    blocking_scoped(Scope::default(), || SCOPE.sync_scope(Scope::default(), f))
+}
+
+/// Add `headers` to the requests sent by `f`.
+pub async fn with_extra_headers<F: Future>(headers: Option<ExtraHeaders>, f: F) -> F::Output {
+    match headers {
+        Some(headers) => {
+            let scope = Scope {
+                headers: Some(headers),
+                ..current().unwrap_or_default()
+            };
+            SCOPE.scope(scope, f).await
+        }
+        None => f.await,
+    }
+}
+
+/// Add `headers` to the requests sent by the blocking call `f`.
+pub fn blocking_with_extra_headers<T>(headers: Option<ExtraHeaders>, f: impl FnOnce() -> T) -> T {
+    match headers {
+        Some(headers) => {
+            let scope = Scope {
+                headers: Some(headers),
+                ..current().unwrap_or_default()
+            };
+            blocking_scoped(scope, f)
+        }
+        None => f(),
+    }
+}
+
+/// Nest the spans of the operations run by `f` under `context`.
+pub async fn with_trace_context<F: Future>(context: Option<TraceContext>, f: F) -> F::Output {
+    match context {
+        Some(context) => {
+            let scope = Scope {
+                trace: Some(context),
+                ..current().unwrap_or_default()
+            };
+            SCOPE.scope(scope, f).await
+        }
+        None => f.await,
+    }
+}
+
+/// The context captured by `with_trace_context` for the current call.
+pub fn trace_context() -> Option<TraceContext> {
+    current().and_then(|scope| scope.trace)
+}
+
+/// Run `f`, returning the headers of the last successful `GET` it sent, if
+/// any. Services that don't speak HTTP, like `fs`, never return any.
+pub async fn with_response_headers<F: Future>(f: F) -> (F::Output, Option<HeaderMap>) {
+    let response = ResponseHeaders::default();
+    let scope = Scope {
+        response: Some(response.clone()),
+        ..current().unwrap_or_default()
+    };
+    let output = SCOPE.scope(scope, f).await;
+    let headers = response.lock().unwrap().take();
+    (output, headers)
+}
+
+/// Run the blocking call `f`, returning the headers of the last successful
+/// `GET` it sent, if any.
+pub fn blocking_with_response_headers<T>(f: impl FnOnce() -> T) -> (T, Option<HeaderMap>) {
+    let response = ResponseHeaders::default();
+    let scope = Scope {
+        response: Some(response.clone()),
+        ..current().unwrap_or_default()
+    };
+    let output = blocking_scoped(scope, f);
+    let headers = response.lock().unwrap().take();
+    (output, headers)
+}
+
+/// The metadata of the object a `GET` returned, from its response headers.
+///
+/// A ranged `GET`, like a chunk of a chunked read, reports the size of the
+/// whole object in its `Content-Range`.
+pub fn response_metadata(path: &str, headers: &HeaderMap) -> ocore::Result<ocore::Metadata> {
+    let mut meta = parse_into_metadata(path, headers)?;
+    if let Some(size) = parse_content_range(headers)?.and_then(|range| range.size()) {
+        meta.set_content_length(size);
+    }
+    Ok(meta)
+}
+
+/// Send the requests of an operator through a client adding the extra
+/// headers of the current call and keeping its response headers, and carry
+/// both over to the tasks it spawns, like the chunks of a concurrent read.
+pub fn install_extra_headers(op: &ocore::Operator) {
+    op.inner()
+        .info()
+        .update_http_client(|client| HttpClient::with(HeaderFetcher(client)));
+    op.update_executor(|_| ocore::Executor::with(HeaderExecutor));
+}
+
+struct HeaderFetcher(HttpClient);
+
+impl HttpFetch for HeaderFetcher {
+    async fn fetch(&self, mut req: Request<ocore::Buffer>) -> ocore::Result<Response<HttpBody>> {
+        let Some(scope) = current() else {
+            return self.0.fetch(req).await;
+        };
+        if let Some(headers) = &scope.headers {
+            for (name, value) in headers.iter() {
+                req.headers_mut().insert(name, value.clone());
+            }
+        }
+        let is_get = req.method() == Method::GET;
+        let resp = self.0.fetch(req).await?;
+        if let Some(response) = scope
+            .response
+            .filter(|_| is_get && resp.status().is_success())
+        {
+            *response.lock().unwrap() = Some(resp.headers().clone());
+        }
+        Ok(resp)
+    }
+}
+
+struct HeaderExecutor;
+
+impl ocore::Execute for HeaderExecutor {
+    fn execute(&self, f: BoxedStaticFuture<()>) {
+        match current() {
+            Some(scope) => tokio::task::spawn(SCOPE.scope(scope, f)),
+            None => tokio::task::spawn(f),
+        };
+    }
+}
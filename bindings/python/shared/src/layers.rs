@@ -15,10 +15,23 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::OnceLock;
 use std::time::Duration;
+use std::time::Instant;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
 
+use opendal::raw::*;
 use opendal::Operator;
+use pyo3::exceptions::PyTypeError;
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
+use pyo3::types::PyDict;
 
 use crate::*;
 
@@ -29,9 +42,14 @@ pub trait PythonLayer: Send + Sync {
 #[pyclass(module = "opendal.layers", subclass)]
 pub struct Layer(pub Box<dyn PythonLayer>);
 
+/// Retry failed operations with exponential backoff.
+///
+/// `min_delay` and `max_delay` accept seconds or a `datetime.timedelta`.
+/// `on_retry` is called with the error, the attempt that failed and the delay
+/// in seconds before each retry.
 #[pyclass(module = "opendal.layers", extends=Layer)]
 #[derive(Clone)]
-pub struct RetryLayer(ocore::layers::RetryLayer);
+pub struct RetryLayer(ocore::layers::RetryLayer<PythonRetryInterceptor>);
 
 impl PythonLayer for RetryLayer {
     fn layer(&self, op: Operator) -> Operator {
@@ -47,16 +65,26 @@ impl RetryLayer {
         factor = None,
         jitter = false,
         max_delay = None,
-        min_delay = None
+        min_delay = None,
+        on_retry = None
     ))]
     fn new(
+        py: Python,
         max_times: Option<usize>,
         factor: Option<f32>,
         jitter: bool,
-        max_delay: Option<f64>,
-        min_delay: Option<f64>,
+        max_delay: Option<PyDuration>,
+        min_delay: Option<PyDuration>,
+        on_retry: Option<PyObject>,
     ) -> PyResult<PyClassInitializer<Self>> {
-        let mut retry = ocore::layers::RetryLayer::default();
+        if let Some(on_retry) = &on_retry {
+            if !on_retry.bind(py).is_callable() {
+                return Err(PyTypeError::new_err("on_retry must be callable"));
+            }
+        }
+        let mut retry = ocore::layers::RetryLayer::default().with_notify(PythonRetryInterceptor {
+            on_retry: on_retry.map(Arc::new),
+        });
         if let Some(max_times) = max_times {
             retry = retry.with_max_times(max_times);
         }
@@ -67,10 +95,10 @@ impl RetryLayer {
             retry = retry.with_jitter();
         }
         if let Some(max_delay) = max_delay {
-            retry = retry.with_max_delay(Duration::from_micros((max_delay * 1000000.0) as u64));
+            retry = retry.with_max_delay(max_delay.0);
         }
         if let Some(min_delay) = min_delay {
-            retry = retry.with_min_delay(Duration::from_micros((min_delay * 1000000.0) as u64));
+            retry = retry.with_min_delay(min_delay.0);
         }
 
         let retry_layer = Self(retry);
@@ -81,6 +109,77 @@ impl RetryLayer {
     }
 }
 
+pub struct PythonRetryInterceptor {
+    on_retry: Option<Arc<PyObject>>,
+}
+
+impl ocore::layers::RetryInterceptor for PythonRetryInterceptor {
+    fn intercept(&self, _: &ocore::Error, _: Duration) {
+        // Retries are reported through `intercept_attempt` instead.
+    }
+
+    fn intercept_attempt(&self, err: &ocore::Error, dur: Duration, attempt: usize) {
+        let Some(on_retry) = self.on_retry.clone() else {
+            return;
+        };
+        let err = CapturedError::new(err);
+        let delay = dur.as_secs_f64();
+        // A failing callback is reported but must not stop the retries.
+        with_gil_or_dispatch(move |py| {
+            let err = err.into_pyerr().into_value(py);
+            if let Err(e) = on_retry.call1(py, (err, attempt, delay)) {
+                e.write_unraisable(py, Some(on_retry.bind(py)));
+            }
+        });
+    }
+}
+
+/// Fail operations that take too long instead of letting them hang.
+///
+/// - `timeout` governs non-IO operations like `stat`, `delete`, `copy` and
+///   `list`, as well as opening a reader or writer. Defaults to 60 seconds.
+/// - `io_timeout` governs each IO call on data, like a single read from a
+///   reader or a single write to a writer. Defaults to 10 seconds.
+///
+/// Both accept seconds or a `datetime.timedelta`. A timed-out operation
+/// raises `Unexpected` whose `is_temporary()` is true, so it can be retried.
+#[pyclass(module = "opendal.layers", extends=Layer)]
+#[derive(Clone)]
+pub struct TimeoutLayer(ocore::layers::TimeoutLayer);
+
+impl PythonLayer for TimeoutLayer {
+    fn layer(&self, op: Operator) -> Operator {
+        op.layer(self.0.clone())
+    }
+}
+
+#[pymethods]
+impl TimeoutLayer {
+    #[new]
+    #[pyo3(signature = (timeout = None, io_timeout = None))]
+    fn new(
+        timeout: Option<PyDuration>,
+        io_timeout: Option<PyDuration>,
+    ) -> PyResult<PyClassInitializer<Self>> {
+        let mut layer = ocore::layers::TimeoutLayer::default();
+        if let Some(timeout) = timeout {
+            layer = layer.with_timeout(timeout.0);
+        }
+        if let Some(io_timeout) = io_timeout {
+            layer = layer.with_io_timeout(io_timeout.0);
+        }
+
+        let timeout_layer = Self(layer);
+        let class = PyClassInitializer::from(Layer(Box::new(timeout_layer.clone())))
+            .add_subclass(timeout_layer);
+
+        Ok(class)
+    }
+}
+
+/// Cap the number of operations in flight against the service.
+///
+/// Operations beyond `limit` wait until a permit is released.
 #[pyclass(module = "opendal.layers", extends=Layer)]
 #[derive(Clone)]
 pub struct ConcurrentLimitLayer(ocore::layers::ConcurrentLimitLayer);
@@ -95,8 +194,12 @@ impl PythonLayer for ConcurrentLimitLayer {
 impl ConcurrentLimitLayer {
     #[new]
     #[pyo3(signature = (limit))]
-    fn new(limit: usize) -> PyResult<PyClassInitializer<Self>> {
-        let concurrent_limit = Self(ocore::layers::ConcurrentLimitLayer::new(limit));
+    fn new(limit: isize) -> PyResult<PyClassInitializer<Self>> {
+        if limit <= 0 {
+            return Err(PyValueError::new_err("limit must be a positive integer"));
+        }
+
+        let concurrent_limit = Self(ocore::layers::ConcurrentLimitLayer::new(limit as usize));
         let class = PyClassInitializer::from(Layer(Box::new(concurrent_limit.clone())))
             .add_subclass(concurrent_limit);
 
@@ -104,6 +207,12 @@ impl ConcurrentLimitLayer {
     }
 }
 
+/// Fill in `content_type` from the path's extension, like `application/pdf`
+/// for `report.pdf`.
+///
+/// Applies to writes and to metadata from `stat` and `list`, but only when no
+/// `content_type` was given or returned by the service, so an explicit
+/// `content_type` always wins. Unknown extensions are left unset.
 #[pyclass(module = "opendal.layers", extends=Layer)]
 #[derive(Clone)]
 pub struct MimeGuessLayer(ocore::layers::MimeGuessLayer);
@@ -125,3 +234,1455 @@ impl MimeGuessLayer {
         Ok(class)
     }
 }
+
+/// Cap the bytes per second read from and written to the service.
+///
+/// Transfers draw from a token bucket that refills at `bandwidth` bytes per
+/// second and holds up to `burst` bytes, so short bursts above `bandwidth` pass
+/// without waiting. A transfer larger than the tokens available waits until
+/// they're paid back.
+///
+/// The bucket is shared by every read and write of the operator the layer is
+/// applied to, but not across operators: each `op.layer(...)` gets its own.
+#[pyclass(module = "opendal.layers", extends=Layer)]
+#[derive(Clone)]
+pub struct ThrottleLayer {
+    bandwidth: u64,
+    burst: u64,
+}
+
+impl PythonLayer for ThrottleLayer {
+    fn layer(&self, op: Operator) -> Operator {
+        op.layer(self.clone())
+    }
+}
+
+#[pymethods]
+impl ThrottleLayer {
+    #[new]
+    #[pyo3(signature = (bandwidth, burst))]
+    fn new(bandwidth: u64, burst: u64) -> PyResult<PyClassInitializer<Self>> {
+        if bandwidth == 0 {
+            return Err(PyValueError::new_err("bandwidth must be positive"));
+        }
+        if burst == 0 {
+            return Err(PyValueError::new_err("burst must be positive"));
+        }
+
+        let throttle = Self { bandwidth, burst };
+        let class =
+            PyClassInitializer::from(Layer(Box::new(throttle.clone()))).add_subclass(throttle);
+        Ok(class)
+    }
+}
+
+impl<A: Access> ocore::raw::Layer<A> for ThrottleLayer {
+    type LayeredAccess = ThrottleAccessor<A>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccess {
+        ThrottleAccessor {
+            inner,
+            bucket: Arc::new(TokenBucket::new(self.bandwidth, self.burst)),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct TokenBucket {
+    rate: f64,
+    burst: f64,
+    /// Tokens left and the time they were counted, going negative while a
+    /// large transfer is being paid back.
+    state: Mutex<(f64, Instant)>,
+}
+
+impl TokenBucket {
+    fn new(bandwidth: u64, burst: u64) -> Self {
+        Self {
+            rate: bandwidth as f64,
+            burst: burst as f64,
+            state: Mutex::new((burst as f64, Instant::now())),
+        }
+    }
+
+    /// Take `size` bytes worth of tokens and wait until the bucket is no
+    /// longer in debt.
+    async fn acquire(&self, size: usize) {
+        let wait = {
+            let mut state = self.state.lock().expect("lock must not be poisoned");
+            let (tokens, counted_at) = &mut *state;
+            let now = Instant::now();
+            let refill = now.duration_since(*counted_at).as_secs_f64() * self.rate;
+            *tokens = (*tokens + refill).min(self.burst) - size as f64;
+            *counted_at = now;
+            Duration::from_secs_f64((-*tokens).max(0.0) / self.rate)
+        };
+        if !wait.is_zero() {
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ThrottleAccessor<A: Access> {
+    inner: A,
+    bucket: Arc<TokenBucket>,
+}
+
+impl<A: Access> LayeredAccess for ThrottleAccessor<A> {
+    type Inner = A;
+    type Reader = ThrottleWrapper<A::Reader>;
+    type Writer = ThrottleWrapper<A::Writer>;
+    type Lister = A::Lister;
+    type Deleter = A::Deleter;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> ocore::Result<(RpRead, Self::Reader)> {
+        let (rp, r) = self.inner.read(path, args).await?;
+        Ok((rp, ThrottleWrapper::new(r, self.bucket.clone())))
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> ocore::Result<(RpWrite, Self::Writer)> {
+        let (rp, w) = self.inner.write(path, args).await?;
+        Ok((rp, ThrottleWrapper::new(w, self.bucket.clone())))
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> ocore::Result<(RpList, Self::Lister)> {
+        self.inner.list(path, args).await
+    }
+
+    async fn delete(&self) -> ocore::Result<(RpDelete, Self::Deleter)> {
+        self.inner.delete().await
+    }
+}
+
+pub struct ThrottleWrapper<R> {
+    inner: R,
+    bucket: Arc<TokenBucket>,
+}
+
+impl<R> ThrottleWrapper<R> {
+    fn new(inner: R, bucket: Arc<TokenBucket>) -> Self {
+        Self { inner, bucket }
+    }
+}
+
+impl<R: oio::Read> oio::Read for ThrottleWrapper<R> {
+    async fn read(&mut self) -> ocore::Result<ocore::Buffer> {
+        let bs = self.inner.read().await?;
+        self.bucket.acquire(bs.len()).await;
+        Ok(bs)
+    }
+}
+
+impl<R: oio::Write> oio::Write for ThrottleWrapper<R> {
+    async fn write(&mut self, bs: ocore::Buffer) -> ocore::Result<()> {
+        self.bucket.acquire(bs.len()).await;
+        self.inner.write(bs).await
+    }
+
+    async fn abort(&mut self) -> ocore::Result<()> {
+        self.inner.abort().await
+    }
+
+    async fn close(&mut self) -> ocore::Result<ocore::Metadata> {
+        self.inner.close().await
+    }
+}
+
+/// Serve repeated reads of a path from memory until `ttl` has passed since
+/// it was read.
+///
+/// Whole reads of a path fill the cache, and every read of a cached path,
+/// ranged or not, is served from it. `ttl` accepts seconds or a
+/// `datetime.timedelta`. Once `max_entries` paths are cached, the one read
+/// longest ago is evicted. Reads with a `version` or a condition like
+/// `if_match` always go to the service, and objects larger than
+/// `max_entry_size` bytes, 8 MiB by default, are streamed without being
+/// cached.
+///
+/// Writes, deletes, copies and renames through the operator drop the paths
+/// they change from the cache, but changes made by other clients are only
+/// seen once the entry expires.
+///
+/// The cache is shared by every read of the operator the layer is applied
+/// to, but not across operators: each `op.layer(...)` gets its own.
+#[pyclass(module = "opendal.layers", extends=Layer)]
+#[derive(Clone)]
+pub struct CacheLayer {
+    max_entries: usize,
+    max_entry_size: usize,
+    ttl: Duration,
+}
+
+impl PythonLayer for CacheLayer {
+    fn layer(&self, op: Operator) -> Operator {
+        op.layer(self.clone())
+    }
+}
+
+#[pymethods]
+impl CacheLayer {
+    #[new]
+    #[pyo3(signature = (max_entries, ttl, max_entry_size = CACHE_MAX_ENTRY_SIZE))]
+    fn new(
+        max_entries: usize,
+        ttl: PyDuration,
+        max_entry_size: usize,
+    ) -> PyResult<PyClassInitializer<Self>> {
+        if max_entries == 0 {
+            return Err(PyValueError::new_err("max_entries must be positive"));
+        }
+        if max_entry_size == 0 {
+            return Err(PyValueError::new_err("max_entry_size must be positive"));
+        }
+        if ttl.0.is_zero() {
+            return Err(PyValueError::new_err("ttl must be positive"));
+        }
+
+        let cache = Self {
+            max_entries,
+            max_entry_size,
+            ttl: ttl.0,
+        };
+        let class = PyClassInitializer::from(Layer(Box::new(cache.clone()))).add_subclass(cache);
+        Ok(class)
+    }
+}
+
+impl<A: Access> ocore::raw::Layer<A> for CacheLayer {
+    type LayeredAccess = CacheAccessor<A>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccess {
+        CacheAccessor {
+            inner,
+            cache: Arc::new(ReadCache::new(
+                self.max_entries,
+                self.max_entry_size,
+                self.ttl,
+            )),
+        }
+    }
+}
+
+/// Largest object `CacheLayer` caches by default.
+const CACHE_MAX_ENTRY_SIZE: usize = 8 * 1024 * 1024;
+
+#[derive(Debug, Default)]
+struct CacheState {
+    /// The content of each cached path and when it was read.
+    entries: HashMap<String, (Instant, ocore::Buffer)>,
+    /// Bumped by every invalidation, so a read that raced a write doesn't
+    /// cache the content the write replaced.
+    generation: u64,
+}
+
+#[derive(Debug)]
+struct ReadCache {
+    max_entries: usize,
+    max_entry_size: usize,
+    ttl: Duration,
+    state: Mutex<CacheState>,
+}
+
+impl ReadCache {
+    fn new(max_entries: usize, max_entry_size: usize, ttl: Duration) -> Self {
+        Self {
+            max_entries,
+            max_entry_size,
+            ttl,
+            state: Mutex::default(),
+        }
+    }
+
+    fn get(&self, path: &str) -> Option<ocore::Buffer> {
+        let mut state = self.state.lock().expect("lock must not be poisoned");
+        match state.entries.get(path) {
+            Some((read_at, bs)) if read_at.elapsed() < self.ttl => Some(bs.clone()),
+            Some(_) => {
+                state.entries.remove(path);
+                None
+            }
+            None => None,
+        }
+    }
+
+    fn generation(&self) -> u64 {
+        self.state
+            .lock()
+            .expect("lock must not be poisoned")
+            .generation
+    }
+
+    /// Cache the content of `path` read since `generation`, unless the path
+    /// may have changed in the meantime.
+    fn insert(&self, path: &str, generation: u64, bs: ocore::Buffer) {
+        let mut state = self.state.lock().expect("lock must not be poisoned");
+        if state.generation != generation {
+            return;
+        }
+        if !state.entries.contains_key(path) && state.entries.len() >= self.max_entries {
+            let oldest = state
+                .entries
+                .iter()
+                .min_by_key(|(_, (read_at, _))| *read_at)
+                .map(|(path, _)| path.clone());
+            if let Some(oldest) = oldest {
+                state.entries.remove(&oldest);
+            }
+        }
+        state.entries.insert(path.to_string(), (Instant::now(), bs));
+    }
+
+    fn invalidate(&self, path: &str) {
+        let mut state = self.state.lock().expect("lock must not be poisoned");
+        state.entries.remove(path);
+        state.generation += 1;
+    }
+}
+
+/// Whether the content of a read only depends on its path and range.
+fn is_cacheable(args: &OpRead) -> bool {
+    args.version().is_none()
+        && args.if_match().is_none()
+        && args.if_none_match().is_none()
+        && args.if_modified_since().is_none()
+        && args.if_unmodified_since().is_none()
+}
+
+fn slice_range(bs: ocore::Buffer, range: BytesRange) -> ocore::Buffer {
+    let start = (range.offset() as usize).min(bs.len());
+    let end = range.size().map_or(bs.len(), |size| {
+        start.saturating_add(size as usize).min(bs.len())
+    });
+    bs.slice(start..end)
+}
+
+#[derive(Debug)]
+pub struct CacheAccessor<A: Access> {
+    inner: A,
+    cache: Arc<ReadCache>,
+}
+
+impl<A: Access> LayeredAccess for CacheAccessor<A> {
+    type Inner = A;
+    type Reader = CacheReader<A::Reader>;
+    type Writer = CacheWriter<A::Writer>;
+    type Lister = A::Lister;
+    type Deleter = CacheDeleter<A::Deleter>;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> ocore::Result<(RpRead, Self::Reader)> {
+        if !is_cacheable(&args) {
+            let (rp, r) = self.inner.read(path, args).await?;
+            return Ok((rp, CacheReader::Inner(r)));
+        }
+        if let Some(bs) = self.cache.get(path) {
+            let bs = slice_range(bs, args.range());
+            return Ok((RpRead::new(), CacheReader::Cached(bs)));
+        }
+        if !args.range().is_full() {
+            let (rp, r) = self.inner.read(path, args).await?;
+            return Ok((rp, CacheReader::Inner(r)));
+        }
+
+        let generation = self.cache.generation();
+        let (rp, mut r) = self.inner.read(path, args).await?;
+        let limit = self.cache.max_entry_size;
+        if rp.size().is_some_and(|size| size > limit as u64) {
+            return Ok((rp, CacheReader::Inner(r)));
+        }
+        // The size is often unknown, so buffer up to the limit and stream
+        // the rest of a larger object instead.
+        let mut chunks = Vec::new();
+        let mut read = 0;
+        loop {
+            let bs = oio::Read::read(&mut r).await?;
+            if bs.is_empty() {
+                break;
+            }
+            read += bs.len();
+            chunks.push(bs);
+            if read > limit {
+                let head = chunks.into_iter().flatten().collect();
+                return Ok((rp, CacheReader::Uncached(head, r)));
+            }
+        }
+        let bs: ocore::Buffer = chunks.into_iter().flatten().collect();
+        self.cache.insert(path, generation, bs.clone());
+        Ok((rp, CacheReader::Cached(bs)))
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> ocore::Result<(RpWrite, Self::Writer)> {
+        let (rp, w) = self.inner.write(path, args).await?;
+        let w = CacheWriter {
+            inner: w,
+            path: path.to_string(),
+            cache: self.cache.clone(),
+        };
+        Ok((rp, w))
+    }
+
+    async fn copy(&self, from: &str, to: &str, args: OpCopy) -> ocore::Result<RpCopy> {
+        let res = self.inner.copy(from, to, args).await;
+        self.cache.invalidate(to);
+        res
+    }
+
+    async fn rename(&self, from: &str, to: &str, args: OpRename) -> ocore::Result<RpRename> {
+        let res = self.inner.rename(from, to, args).await;
+        self.cache.invalidate(from);
+        self.cache.invalidate(to);
+        res
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> ocore::Result<(RpList, Self::Lister)> {
+        self.inner.list(path, args).await
+    }
+
+    async fn delete(&self) -> ocore::Result<(RpDelete, Self::Deleter)> {
+        let (rp, d) = self.inner.delete().await?;
+        let d = CacheDeleter {
+            inner: d,
+            paths: Vec::new(),
+            cache: self.cache.clone(),
+        };
+        Ok((rp, d))
+    }
+}
+
+pub enum CacheReader<R> {
+    Cached(ocore::Buffer),
+    Inner(R),
+    /// What was read of an object too large to cache, then the rest of it.
+    Uncached(ocore::Buffer, R),
+}
+
+impl<R: oio::Read> oio::Read for CacheReader<R> {
+    async fn read(&mut self) -> ocore::Result<ocore::Buffer> {
+        match self {
+            Self::Cached(bs) => bs.read().await,
+            Self::Inner(r) => r.read().await,
+            Self::Uncached(head, _) if !head.is_empty() => head.read().await,
+            Self::Uncached(_, r) => r.read().await,
+        }
+    }
+}
+
+/// Drop the written path from the cache once the write is finished or
+/// aborted, so a read racing the write can't keep the old content.
+pub struct CacheWriter<W> {
+    inner: W,
+    path: String,
+    cache: Arc<ReadCache>,
+}
+
+impl<W: oio::Write> oio::Write for CacheWriter<W> {
+    async fn write(&mut self, bs: ocore::Buffer) -> ocore::Result<()> {
+        self.inner.write(bs).await
+    }
+
+    async fn abort(&mut self) -> ocore::Result<()> {
+        let res = self.inner.abort().await;
+        self.cache.invalidate(&self.path);
+        res
+    }
+
+    async fn close(&mut self) -> ocore::Result<ocore::Metadata> {
+        let res = self.inner.close().await;
+        self.cache.invalidate(&self.path);
+        res
+    }
+}
+
+/// Drop the deleted paths from the cache once they're flushed.
+pub struct CacheDeleter<D> {
+    inner: D,
+    paths: Vec<String>,
+    cache: Arc<ReadCache>,
+}
+
+impl<D: oio::Delete> oio::Delete for CacheDeleter<D> {
+    fn delete(&mut self, path: &str, args: OpDelete) -> ocore::Result<()> {
+        self.inner.delete(path, args)?;
+        self.paths.push(path.to_string());
+        Ok(())
+    }
+
+    async fn flush(&mut self) -> ocore::Result<usize> {
+        let res = self.inner.flush().await;
+        for path in self.paths.drain(..) {
+            self.cache.invalidate(&path);
+        }
+        res
+    }
+}
+
+/// Report every read, write, stat, list and delete to `callback`.
+///
+/// `callback` is called as `callback(operation, scheme, duration_seconds,
+/// outcome)`, where `outcome` is `"ok"`, `"aborted"` for an aborted write, or
+/// the kind of the error raised, like `"NotFound"`. Reads, writes and lists
+/// are timed from opening them until they're finished, failed or dropped;
+/// deletes once for each batch sent to the service.
+///
+/// Callbacks run in order on a background thread, so a slow callback never
+/// holds up IO. Exceptions raised by `callback` are reported as unraisable.
+#[pyclass(module = "opendal.layers", extends=Layer)]
+#[derive(Clone)]
+pub struct MetricsLayer {
+    callback: Arc<PyObject>,
+}
+
+impl PythonLayer for MetricsLayer {
+    fn layer(&self, op: Operator) -> Operator {
+        op.layer(self.clone())
+    }
+}
+
+#[pymethods]
+impl MetricsLayer {
+    #[new]
+    #[pyo3(signature = (callback))]
+    fn new(py: Python, callback: PyObject) -> PyResult<PyClassInitializer<Self>> {
+        if !callback.bind(py).is_callable() {
+            return Err(PyTypeError::new_err("callback must be callable"));
+        }
+
+        let metrics = Self {
+            callback: Arc::new(callback),
+        };
+        let class =
+            PyClassInitializer::from(Layer(Box::new(metrics.clone()))).add_subclass(metrics);
+        Ok(class)
+    }
+}
+
+impl<A: Access> ocore::raw::Layer<A> for MetricsLayer {
+    type LayeredAccess = MetricsAccessor<A>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccess {
+        let scheme = inner.info().scheme().into_static();
+        MetricsAccessor {
+            inner,
+            callback: self.callback.clone(),
+            scheme,
+        }
+    }
+}
+
+/// Times one operation and reports it once: when it's finished, when it fails
+/// or, failing both, when it's dropped.
+struct Observation {
+    callback: Arc<PyObject>,
+    operation: &'static str,
+    scheme: &'static str,
+    start: Instant,
+    reported: bool,
+}
+
+impl Observation {
+    fn new(callback: Arc<PyObject>, operation: &'static str, scheme: &'static str) -> Self {
+        Self {
+            callback,
+            operation,
+            scheme,
+            start: Instant::now(),
+            reported: false,
+        }
+    }
+
+    /// Report the error if `res` failed.
+    fn check<T>(&mut self, res: ocore::Result<T>) -> ocore::Result<T> {
+        if let Err(err) = &res {
+            self.report(err.kind().into_static());
+        }
+        res
+    }
+
+    fn report(&mut self, outcome: &'static str) {
+        if self.reported {
+            return;
+        }
+        self.reported = true;
+
+        let callback = self.callback.clone();
+        let args = (
+            self.operation,
+            self.scheme,
+            self.start.elapsed().as_secs_f64(),
+            outcome,
+        );
+        dispatch(move |py| {
+            if let Err(err) = callback.call1(py, args) {
+                err.write_unraisable(py, Some(callback.bind(py)));
+            }
+        });
+    }
+}
+
+impl Drop for Observation {
+    fn drop(&mut self) {
+        self.report("ok");
+    }
+}
+
+#[derive(Debug)]
+pub struct MetricsAccessor<A: Access> {
+    inner: A,
+    callback: Arc<PyObject>,
+    scheme: &'static str,
+}
+
+impl<A: Access> MetricsAccessor<A> {
+    fn observe(&self, operation: &'static str) -> Observation {
+        Observation::new(self.callback.clone(), operation, self.scheme)
+    }
+}
+
+impl<A: Access> LayeredAccess for MetricsAccessor<A> {
+    type Inner = A;
+    type Reader = MetricsWrapper<A::Reader>;
+    type Writer = MetricsWrapper<A::Writer>;
+    type Lister = MetricsWrapper<A::Lister>;
+    type Deleter = MetricsDeleter<A::Deleter>;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> ocore::Result<(RpRead, Self::Reader)> {
+        let mut obs = self.observe("read");
+        let (rp, r) = obs.check(self.inner.read(path, args).await)?;
+        Ok((rp, MetricsWrapper { inner: r, obs }))
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> ocore::Result<(RpWrite, Self::Writer)> {
+        let mut obs = self.observe("write");
+        let (rp, w) = obs.check(self.inner.write(path, args).await)?;
+        Ok((rp, MetricsWrapper { inner: w, obs }))
+    }
+
+    async fn stat(&self, path: &str, args: OpStat) -> ocore::Result<RpStat> {
+        self.observe("stat")
+            .check(self.inner.stat(path, args).await)
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> ocore::Result<(RpList, Self::Lister)> {
+        let mut obs = self.observe("list");
+        let (rp, l) = obs.check(self.inner.list(path, args).await)?;
+        Ok((rp, MetricsWrapper { inner: l, obs }))
+    }
+
+    async fn delete(&self) -> ocore::Result<(RpDelete, Self::Deleter)> {
+        let mut obs = self.observe("delete");
+        let (rp, d) = obs.check(self.inner.delete().await)?;
+        // Deletes are reported per flushed batch, not for opening the deleter.
+        obs.reported = true;
+        Ok((
+            rp,
+            MetricsDeleter {
+                inner: d,
+                callback: self.callback.clone(),
+                scheme: self.scheme,
+            },
+        ))
+    }
+}
+
+pub struct MetricsWrapper<R> {
+    inner: R,
+    obs: Observation,
+}
+
+impl<R: oio::Read> oio::Read for MetricsWrapper<R> {
+    async fn read(&mut self) -> ocore::Result<ocore::Buffer> {
+        let bs = self.obs.check(self.inner.read().await)?;
+        if bs.is_empty() {
+            self.obs.report("ok");
+        }
+        Ok(bs)
+    }
+}
+
+impl<R: oio::Write> oio::Write for MetricsWrapper<R> {
+    async fn write(&mut self, bs: ocore::Buffer) -> ocore::Result<()> {
+        self.obs.check(self.inner.write(bs).await)
+    }
+
+    async fn abort(&mut self) -> ocore::Result<()> {
+        self.obs.check(self.inner.abort().await)?;
+        self.obs.report("aborted");
+        Ok(())
+    }
+
+    async fn close(&mut self) -> ocore::Result<ocore::Metadata> {
+        let meta = self.obs.check(self.inner.close().await)?;
+        self.obs.report("ok");
+        Ok(meta)
+    }
+}
+
+impl<R: oio::List> oio::List for MetricsWrapper<R> {
+    async fn next(&mut self) -> ocore::Result<Option<oio::Entry>> {
+        let entry = self.obs.check(self.inner.next().await)?;
+        if entry.is_none() {
+            self.obs.report("ok");
+        }
+        Ok(entry)
+    }
+}
+
+pub struct MetricsDeleter<D> {
+    inner: D,
+    callback: Arc<PyObject>,
+    scheme: &'static str,
+}
+
+impl<D: oio::Delete> oio::Delete for MetricsDeleter<D> {
+    fn delete(&mut self, path: &str, args: OpDelete) -> ocore::Result<()> {
+        self.inner.delete(path, args)
+    }
+
+    async fn flush(&mut self) -> ocore::Result<usize> {
+        let mut obs = Observation::new(self.callback.clone(), "delete", self.scheme);
+        let n = obs.check(self.inner.flush().await)?;
+        // Nothing was queued, so nothing was sent.
+        if n == 0 {
+            obs.reported = true;
+        }
+        Ok(n)
+    }
+}
+
+/// Bound a single call by a deadline, for the `timeout` argument of the
+/// operator methods.
+///
+/// Unlike `TimeoutLayer`, which limits each request on its own, everything
+/// done through the layered operator must finish before `timeout` elapses
+/// from the layer's creation. The pending request is cancelled once it does.
+#[derive(Clone)]
+pub struct DeadlineLayer {
+    timeout: Duration,
+    deadline: tokio::time::Instant,
+}
+
+impl DeadlineLayer {
+    pub fn new(timeout: Duration) -> Self {
+        Self {
+            timeout,
+            deadline: tokio::time::Instant::now() + timeout,
+        }
+    }
+
+    async fn run<F: Future<Output = ocore::Result<T>>, T>(
+        &self,
+        op: ocore::raw::Operation,
+        fut: F,
+    ) -> ocore::Result<T> {
+        tokio::time::timeout_at(self.deadline, fut)
+            .await
+            .map_err(|_| {
+                // Worded like `TimeoutLayer` so both are handled alike.
+                ocore::Error::new(ocore::ErrorKind::Unexpected, "operation timeout reached")
+                    .with_operation(op)
+                    .with_context("timeout", self.timeout.as_secs_f64().to_string())
+                    .set_temporary()
+            })?
+    }
+}
+
+impl<A: Access> ocore::raw::Layer<A> for DeadlineLayer {
+    type LayeredAccess = DeadlineAccessor<A>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccess {
+        DeadlineAccessor {
+            inner,
+            deadline: self.clone(),
+        }
+    }
+}
+
+pub struct DeadlineAccessor<A: Access> {
+    inner: A,
+    deadline: DeadlineLayer,
+}
+
+impl<A: Access> std::fmt::Debug for DeadlineAccessor<A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DeadlineAccessor")
+            .field("inner", &self.inner)
+            .field("timeout", &self.deadline.timeout)
+            .finish()
+    }
+}
+
+impl<A: Access> LayeredAccess for DeadlineAccessor<A> {
+    type Inner = A;
+    type Reader = DeadlineWrapper<A::Reader>;
+    type Writer = DeadlineWrapper<A::Writer>;
+    type Lister = DeadlineWrapper<A::Lister>;
+    type Deleter = A::Deleter;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> ocore::Result<(RpRead, Self::Reader)> {
+        let (rp, r) = self
+            .deadline
+            .run(Operation::Read, self.inner.read(path, args))
+            .await?;
+        Ok((rp, DeadlineWrapper::new(r, &self.deadline)))
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> ocore::Result<(RpWrite, Self::Writer)> {
+        let (rp, w) = self
+            .deadline
+            .run(Operation::Write, self.inner.write(path, args))
+            .await?;
+        Ok((rp, DeadlineWrapper::new(w, &self.deadline)))
+    }
+
+    async fn stat(&self, path: &str, args: OpStat) -> ocore::Result<RpStat> {
+        self.deadline
+            .run(Operation::Stat, self.inner.stat(path, args))
+            .await
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> ocore::Result<(RpList, Self::Lister)> {
+        let (rp, l) = self
+            .deadline
+            .run(Operation::List, self.inner.list(path, args))
+            .await?;
+        Ok((rp, DeadlineWrapper::new(l, &self.deadline)))
+    }
+
+    async fn delete(&self) -> ocore::Result<(RpDelete, Self::Deleter)> {
+        self.inner.delete().await
+    }
+}
+
+pub struct DeadlineWrapper<R> {
+    inner: R,
+    deadline: DeadlineLayer,
+}
+
+impl<R> DeadlineWrapper<R> {
+    fn new(inner: R, deadline: &DeadlineLayer) -> Self {
+        Self {
+            inner,
+            deadline: deadline.clone(),
+        }
+    }
+}
+
+impl<R: oio::Read> oio::Read for DeadlineWrapper<R> {
+    async fn read(&mut self) -> ocore::Result<ocore::Buffer> {
+        self.deadline.run(Operation::Read, self.inner.read()).await
+    }
+}
+
+impl<R: oio::Write> oio::Write for DeadlineWrapper<R> {
+    async fn write(&mut self, bs: ocore::Buffer) -> ocore::Result<()> {
+        self.deadline
+            .run(Operation::Write, self.inner.write(bs))
+            .await
+    }
+
+    async fn abort(&mut self) -> ocore::Result<()> {
+        self.deadline
+            .run(Operation::Write, self.inner.abort())
+            .await
+    }
+
+    async fn close(&mut self) -> ocore::Result<ocore::Metadata> {
+        self.deadline
+            .run(Operation::Write, self.inner.close())
+            .await
+    }
+}
+
+impl<R: oio::List> oio::List for DeadlineWrapper<R> {
+    async fn next(&mut self) -> ocore::Result<Option<oio::Entry>> {
+        self.deadline.run(Operation::List, self.inner.next()).await
+    }
+}
+
+/// Add a deadline of `timeout` to `op`, for a call given a `timeout`.
+pub fn with_deadline(op: Operator, timeout: Option<PyDuration>) -> Operator {
+    match timeout {
+        Some(timeout) => op.layer(DeadlineLayer::new(timeout.0)),
+        None => op,
+    }
+}
+
+/// Route operation logs into Python's `logging` module.
+///
+/// Records go to the logger called `name`, honoring its level. Each record
+/// carries `service`, `operation`, `outcome` and the operation's context like
+/// `path` in its `extra`. Progress is logged at `DEBUG`, failures at
+/// `WARNING`, or `ERROR` for unexpected errors.
+#[pyclass(module = "opendal.layers", extends=Layer)]
+#[derive(Clone)]
+pub struct LoggingLayer(PythonLoggingInterceptor);
+
+impl PythonLayer for LoggingLayer {
+    fn layer(&self, op: Operator) -> Operator {
+        op.layer(ocore::layers::LoggingLayer::new(self.0.clone()))
+    }
+}
+
+#[pymethods]
+impl LoggingLayer {
+    #[new]
+    #[pyo3(signature = (name = "opendal"))]
+    fn new(py: Python, name: &str) -> PyResult<PyClassInitializer<Self>> {
+        let logger = py
+            .import("logging")?
+            .call_method1("getLogger", (name,))?
+            .unbind();
+
+        let logging_layer = Self(PythonLoggingInterceptor {
+            logger: Arc::new(logger),
+        });
+        let class = PyClassInitializer::from(Layer(Box::new(logging_layer.clone())))
+            .add_subclass(logging_layer);
+        Ok(class)
+    }
+}
+
+/// Levels of Python's `logging` module.
+const LOG_DEBUG: i32 = 10;
+const LOG_WARNING: i32 = 30;
+const LOG_ERROR: i32 = 40;
+
+#[derive(Clone, Debug)]
+pub struct PythonLoggingInterceptor {
+    logger: Arc<PyObject>,
+}
+
+impl ocore::layers::LoggingInterceptor for PythonLoggingInterceptor {
+    fn log(
+        &self,
+        info: &ocore::raw::AccessorInfo,
+        operation: ocore::raw::Operation,
+        context: &[(&str, &str)],
+        message: &str,
+        err: Option<&ocore::Error>,
+    ) {
+        let level = match err {
+            Some(err) if err.kind() == ocore::ErrorKind::Unexpected => LOG_ERROR,
+            Some(_) => LOG_WARNING,
+            None => LOG_DEBUG,
+        };
+
+        let mut msg = format!("service={} name={}", info.scheme(), info.name());
+        for (k, v) in context {
+            msg.push_str(&format!(" {k}={v}"));
+        }
+        msg.push_str(&format!(": {operation} {message}"));
+        if let Some(err) = err {
+            msg.push_str(&format!(" {err}"));
+        }
+
+        let mut extra = vec![
+            ("service".to_string(), info.scheme().to_string()),
+            ("operation".to_string(), operation.to_string()),
+            ("outcome".to_string(), message.to_string()),
+        ];
+        extra.extend(context.iter().map(|(k, v)| (k.to_string(), v.to_string())));
+
+        let record = LogRecord {
+            logger: self.logger.clone(),
+            level,
+            msg,
+            extra,
+        };
+        with_gil_or_dispatch(move |py| record.emit(py));
+    }
+}
+
+struct LogRecord {
+    logger: Arc<PyObject>,
+    level: i32,
+    msg: String,
+    extra: Vec<(String, String)>,
+}
+
+impl LogRecord {
+    fn emit(self, py: Python) {
+        let logger = self.logger.bind(py);
+        let res = (|| -> PyResult<()> {
+            if !logger
+                .call_method1("isEnabledFor", (self.level,))?
+                .is_truthy()?
+            {
+                return Ok(());
+            }
+            let extra = PyDict::new(py);
+            for (k, v) in self.extra {
+                extra.set_item(k, v)?;
+            }
+            let kwargs = PyDict::new(py);
+            kwargs.set_item("extra", extra)?;
+            logger.call_method("log", (self.level, self.msg), Some(&kwargs))?;
+            Ok(())
+        })();
+        if let Err(err) = res {
+            err.write_unraisable(py, Some(logger));
+        }
+    }
+}
+
+/// Record a span per operation with OpenTelemetry.
+///
+/// Spans are named `opendal.<operation>`, of kind `CLIENT`, and carry the
+/// `opendal.scheme`, `opendal.operation` and `opendal.path` attributes.
+/// Reads, writes and lists span from opening them until they're finished,
+/// failed or dropped; deletes get one span per batch sent to the service.
+/// Failed spans get an error status and the kind of the error as
+/// `error.type`.
+///
+/// Spans nest under `parent` if given. Otherwise they nest under the span
+/// active in the caller: blocking calls look it up in the calling thread,
+/// while async calls capture it when they're made, since they run away from
+/// the caller's context.
+///
+/// Spans are recorded with their timestamps on a background thread once the
+/// operation completes, so exporting never holds up IO.
+#[pyclass(module = "opendal.layers", extends=Layer)]
+#[derive(Clone)]
+pub struct TracingLayer(Arc<Tracer>);
+
+impl PythonLayer for TracingLayer {
+    fn layer(&self, op: Operator) -> Operator {
+        op.layer(self.clone())
+    }
+}
+
+#[pymethods]
+impl TracingLayer {
+    #[new]
+    #[pyo3(signature = (tracer = None, *, parent = None))]
+    fn new(
+        py: Python,
+        tracer: Option<PyObject>,
+        parent: Option<&Bound<PyAny>>,
+    ) -> PyResult<PyClassInitializer<Self>> {
+        let trace = py.import("opentelemetry.trace")?;
+        let context = py.import("opentelemetry.context")?;
+        let get_current = context.getattr("get_current")?.unbind();
+        let _ = GET_CURRENT.set(get_current);
+        let tracer = match tracer {
+            Some(tracer) => tracer,
+            None => trace.call_method1("get_tracer", ("opendal",))?.unbind(),
+        };
+        let parent = match parent {
+            None => None,
+            Some(parent) if parent.is_instance(&context.getattr("Context")?)? => {
+                Some(parent.clone().unbind())
+            }
+            Some(parent) => {
+                let Ok((trace_id, span_id)) = parent.extract::<(u128, u64)>() else {
+                    return Err(PyTypeError::new_err(
+                        "parent must be a (trace_id, span_id) pair or a Context",
+                    ));
+                };
+                if trace_id == 0 || span_id == 0 {
+                    return Err(PyValueError::new_err(
+                        "trace_id and span_id of parent must not be zero",
+                    ));
+                }
+                let flags = trace.getattr("TraceFlags")?;
+                let flags = flags.call1((flags.getattr("SAMPLED")?,))?;
+                let span_context = trace
+                    .getattr("SpanContext")?
+                    .call1((trace_id, span_id, true, flags))?;
+                let span = trace.getattr("NonRecordingSpan")?.call1((span_context,))?;
+                Some(trace.call_method1("set_span_in_context", (span,))?.unbind())
+            }
+        };
+
+        let tracing = Self(Arc::new(Tracer {
+            tracer,
+            context: context.unbind().into_any(),
+            parent,
+            kind: trace.getattr("SpanKind")?.getattr("CLIENT")?.unbind(),
+            status: trace.getattr("Status")?.unbind(),
+            error: trace.getattr("StatusCode")?.getattr("ERROR")?.unbind(),
+        }));
+        let class =
+            PyClassInitializer::from(Layer(Box::new(tracing.clone()))).add_subclass(tracing);
+        Ok(class)
+    }
+}
+
+/// `opentelemetry.context.get_current`, once a `TracingLayer` was built.
+static GET_CURRENT: OnceLock<PyObject> = OnceLock::new();
+
+/// Run `fut` on the runtime like `pyo3_async_runtimes::tokio::future_into_py`,
+/// with the OpenTelemetry context active in the caller, so the spans of
+/// `TracingLayer` nest under it.
+pub fn future_into_py<F, T>(py: Python, fut: F) -> PyResult<Bound<PyAny>>
+where
+    F: Future<Output = PyResult<T>> + Send + 'static,
+    T: for<'py> IntoPyObject<'py> + Send + 'static,
+{
+    let context = GET_CURRENT
+        .get()
+        .and_then(|get_current| get_current.call0(py).ok())
+        .map(Arc::new);
+    pyo3_async_runtimes::tokio::future_into_py(py, with_trace_context(context, fut))
+}
+
+struct Tracer {
+    tracer: PyObject,
+    /// `opentelemetry.context`, to look up the span active in the caller.
+    context: PyObject,
+    parent: Option<PyObject>,
+    /// `opentelemetry.trace.SpanKind.CLIENT`.
+    kind: PyObject,
+    /// `opentelemetry.trace.Status` and `StatusCode.ERROR`.
+    status: PyObject,
+    error: PyObject,
+}
+
+impl<A: Access> ocore::raw::Layer<A> for TracingLayer {
+    type LayeredAccess = TracingAccessor<A>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccess {
+        let scheme = inner.info().scheme().into_static();
+        TracingAccessor {
+            inner,
+            tracer: self.0.clone(),
+            scheme,
+        }
+    }
+}
+
+/// One operation's span, recorded once: when it's finished, when it fails
+/// or, failing both, when it's dropped.
+struct Span {
+    tracer: Arc<Tracer>,
+    operation: &'static str,
+    scheme: &'static str,
+    path: String,
+    /// The context active in the caller, if known.
+    context: Option<TraceContext>,
+    start: SystemTime,
+    reported: bool,
+}
+
+impl Span {
+    fn new(tracer: Arc<Tracer>, operation: &'static str, scheme: &'static str, path: &str) -> Self {
+        // Async calls capture the caller's context up front, blocking calls
+        // run on the caller's thread, which holds the GIL.
+        let context = if tracer.parent.is_some() {
+            None
+        } else if let Some(context) = trace_context() {
+            Some(context)
+        } else if unsafe { pyo3::ffi::PyGILState_Check() } == 1 {
+            Python::with_gil(|py| tracer.context.call_method0(py, "get_current").ok())
+                .map(Arc::new)
+        } else {
+            None
+        };
+        Self {
+            tracer,
+            operation,
+            scheme,
+            path: path.to_string(),
+            context,
+            start: SystemTime::now(),
+            reported: false,
+        }
+    }
+
+    /// Record the error if `res` failed.
+    fn check<T>(&mut self, res: ocore::Result<T>) -> ocore::Result<T> {
+        if let Err(err) = &res {
+            self.report(Some(err));
+        }
+        res
+    }
+
+    fn report(&mut self, err: Option<&ocore::Error>) {
+        if self.reported {
+            return;
+        }
+        self.reported = true;
+
+        let record = SpanRecord {
+            tracer: self.tracer.clone(),
+            operation: self.operation,
+            scheme: self.scheme,
+            path: std::mem::take(&mut self.path),
+            context: self.context.take(),
+            start: self.start,
+            end: SystemTime::now(),
+            error: err.map(|err| (err.kind().into_static(), err.to_string())),
+        };
+        dispatch(move |py| {
+            if let Err(err) = record.emit(py) {
+                err.write_unraisable(py, Some(record.tracer.tracer.bind(py)));
+            }
+        });
+    }
+}
+
+impl Drop for Span {
+    fn drop(&mut self) {
+        self.report(None);
+    }
+}
+
+struct SpanRecord {
+    tracer: Arc<Tracer>,
+    operation: &'static str,
+    scheme: &'static str,
+    path: String,
+    context: Option<TraceContext>,
+    start: SystemTime,
+    end: SystemTime,
+    error: Option<(&'static str, String)>,
+}
+
+impl SpanRecord {
+    fn emit(&self, py: Python) -> PyResult<()> {
+        let tracer = &self.tracer;
+        let attributes = PyDict::new(py);
+        attributes.set_item("opendal.scheme", self.scheme)?;
+        attributes.set_item("opendal.operation", self.operation)?;
+        if !self.path.is_empty() {
+            attributes.set_item("opendal.path", &self.path)?;
+        }
+        if let Some((kind, _)) = &self.error {
+            attributes.set_item("error.type", kind)?;
+        }
+
+        let kwargs = PyDict::new(py);
+        kwargs.set_item("context", self.context.as_deref().or(tracer.parent.as_ref()))?;
+        kwargs.set_item("kind", &tracer.kind)?;
+        kwargs.set_item("attributes", attributes)?;
+        kwargs.set_item("start_time", unix_nanos(self.start))?;
+        let span = tracer.tracer.bind(py).call_method(
+            "start_span",
+            (format!("opendal.{}", self.operation),),
+            Some(&kwargs),
+        )?;
+        if let Some((_, message)) = &self.error {
+            let status = tracer.status.call1(py, (&tracer.error, message))?;
+            span.call_method1("set_status", (status,))?;
+        }
+        let kwargs = PyDict::new(py);
+        kwargs.set_item("end_time", unix_nanos(self.end))?;
+        span.call_method("end", (), Some(&kwargs))?;
+        Ok(())
+    }
+}
+
+/// Timestamps of OpenTelemetry spans are nanoseconds since the epoch.
+fn unix_nanos(time: SystemTime) -> u128 {
+    time.duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos()
+}
+
+#[derive(Debug)]
+pub struct TracingAccessor<A: Access> {
+    inner: A,
+    tracer: Arc<Tracer>,
+    scheme: &'static str,
+}
+
+impl std::fmt::Debug for Tracer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Tracer").finish_non_exhaustive()
+    }
+}
+
+impl<A: Access> TracingAccessor<A> {
+    fn span(&self, operation: &'static str, path: &str) -> Span {
+        Span::new(self.tracer.clone(), operation, self.scheme, path)
+    }
+}
+
+impl<A: Access> LayeredAccess for TracingAccessor<A> {
+    type Inner = A;
+    type Reader = TracingWrapper<A::Reader>;
+    type Writer = TracingWrapper<A::Writer>;
+    type Lister = TracingWrapper<A::Lister>;
+    type Deleter = TracingDeleter<A::Deleter>;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    async fn create_dir(&self, path: &str, args: OpCreateDir) -> ocore::Result<RpCreateDir> {
+        self.span("create_dir", path)
+            .check(self.inner.create_dir(path, args).await)
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> ocore::Result<(RpRead, Self::Reader)> {
+        let mut span = self.span("read", path);
+        let (rp, r) = span.check(self.inner.read(path, args).await)?;
+        Ok((rp, TracingWrapper { inner: r, span }))
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> ocore::Result<(RpWrite, Self::Writer)> {
+        let mut span = self.span("write", path);
+        let (rp, w) = span.check(self.inner.write(path, args).await)?;
+        Ok((rp, TracingWrapper { inner: w, span }))
+    }
+
+    async fn copy(&self, from: &str, to: &str, args: OpCopy) -> ocore::Result<RpCopy> {
+        self.span("copy", from)
+            .check(self.inner.copy(from, to, args).await)
+    }
+
+    async fn rename(&self, from: &str, to: &str, args: OpRename) -> ocore::Result<RpRename> {
+        self.span("rename", from)
+            .check(self.inner.rename(from, to, args).await)
+    }
+
+    async fn stat(&self, path: &str, args: OpStat) -> ocore::Result<RpStat> {
+        self.span("stat", path)
+            .check(self.inner.stat(path, args).await)
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> ocore::Result<(RpList, Self::Lister)> {
+        let mut span = self.span("list", path);
+        let (rp, l) = span.check(self.inner.list(path, args).await)?;
+        Ok((rp, TracingWrapper { inner: l, span }))
+    }
+
+    async fn delete(&self) -> ocore::Result<(RpDelete, Self::Deleter)> {
+        let (rp, d) = self.inner.delete().await?;
+        Ok((
+            rp,
+            TracingDeleter {
+                inner: d,
+                tracer: self.tracer.clone(),
+                scheme: self.scheme,
+                path: None,
+            },
+        ))
+    }
+}
+
+pub struct TracingWrapper<R> {
+    inner: R,
+    span: Span,
+}
+
+impl<R: oio::Read> oio::Read for TracingWrapper<R> {
+    async fn read(&mut self) -> ocore::Result<ocore::Buffer> {
+        let bs = self.span.check(self.inner.read().await)?;
+        if bs.is_empty() {
+            self.span.report(None);
+        }
+        Ok(bs)
+    }
+}
+
+impl<R: oio::Write> oio::Write for TracingWrapper<R> {
+    async fn write(&mut self, bs: ocore::Buffer) -> ocore::Result<()> {
+        self.span.check(self.inner.write(bs).await)
+    }
+
+    async fn abort(&mut self) -> ocore::Result<()> {
+        self.span.check(self.inner.abort().await)?;
+        self.span.report(None);
+        Ok(())
+    }
+
+    async fn close(&mut self) -> ocore::Result<ocore::Metadata> {
+        let meta = self.span.check(self.inner.close().await)?;
+        self.span.report(None);
+        Ok(meta)
+    }
+}
+
+impl<R: oio::List> oio::List for TracingWrapper<R> {
+    async fn next(&mut self) -> ocore::Result<Option<oio::Entry>> {
+        let entry = self.span.check(self.inner.next().await)?;
+        if entry.is_none() {
+            self.span.report(None);
+        }
+        Ok(entry)
+    }
+}
+
+pub struct TracingDeleter<D> {
+    inner: D,
+    tracer: Arc<Tracer>,
+    scheme: &'static str,
+    /// The path of a batch of one, recorded on its span.
+    path: Option<String>,
+}
+
+impl<D: oio::Delete> oio::Delete for TracingDeleter<D> {
+    fn delete(&mut self, path: &str, args: OpDelete) -> ocore::Result<()> {
+        self.inner.delete(path, args)?;
+        self.path = match self.path {
+            None => Some(path.to_string()),
+            Some(_) => Some(String::new()),
+        };
+        Ok(())
+    }
+
+    async fn flush(&mut self) -> ocore::Result<usize> {
+        let path = self.path.take().unwrap_or_default();
+        let mut span = Span::new(self.tracer.clone(), "delete", self.scheme, &path);
+        let n = span.check(self.inner.flush().await)?;
+        // Nothing was queued, so nothing was sent.
+        if n == 0 {
+            span.reported = true;
+        }
+        Ok(n)
+    }
+}
+
+type Callback = Box<dyn FnOnce(Python) + Send>;
+
+/// Run a Python callback from inside an operation.
+///
+/// Run it right away if this thread holds the GIL. Otherwise hand it to a
+/// dedicated thread: waiting for the GIL from a runtime worker could deadlock
+/// against a blocking call holding it.
+fn with_gil_or_dispatch(f: impl FnOnce(Python) + Send + 'static) {
+    if unsafe { pyo3::ffi::PyGILState_Check() } == 1 {
+        Python::with_gil(f);
+    } else {
+        dispatch(f);
+    }
+}
+
+/// Hand a Python callback to the dedicated thread, so the operation doesn't
+/// wait for it even when this thread holds the GIL.
+fn dispatch(f: impl FnOnce(Python) + Send + 'static) {
+    let _ = callback_sender().send(Box::new(f));
+}
+
+fn callback_sender() -> &'static mpsc::Sender<Callback> {
+    static SENDER: OnceLock<mpsc::Sender<Callback>> = OnceLock::new();
+    SENDER.get_or_init(|| {
+        let (tx, rx) = mpsc::channel::<Callback>();
+        std::thread::Builder::new()
+            .name("opendal-callbacks".to_string())
+            .spawn(move || {
+                for callback in rx {
+                    Python::with_gil(callback);
+                }
+            })
+            .expect("spawn callback thread must succeed");
+        tx
+    })
+}
@@ -18,11 +18,19 @@
 // expose the opendal rust core as `core`.
 // We will use `ocore::Xxx` to represents all types from opendal rust core.
 pub use ::opendal as ocore;
-use pyo3::prelude::*;
 
 pub mod capability;
 pub use capability::*;
 
+pub mod credential;
+pub use credential::*;
+
+pub mod glob;
+pub use glob::*;
+
+pub mod headers;
+pub use headers::*;
+
 pub mod layers;
 pub use layers::*;
 
@@ -15,23 +15,71 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use futures::TryStreamExt;
 use pyo3::exceptions::PyStopAsyncIteration;
+use pyo3::exceptions::PyValueError;
+use pyo3::types::PyDict;
 use pyo3::{prelude::*, IntoPyObjectExt};
-use pyo3_async_runtimes::tokio::future_into_py;
 use tokio::sync::Mutex;
 
 use crate::*;
 
+/// The entries of a listing a lister yields.
+pub enum ListFilter {
+    /// The entries matching a glob pattern.
+    Glob(Glob),
+    /// The directories in `dir`, leaving out `dir` itself.
+    Dirs(String),
+}
+
+impl ListFilter {
+    fn matches(&self, entry: &ocore::Entry) -> bool {
+        match self {
+            Self::Glob(glob) => glob.is_match(entry.path()),
+            // Services list the directory itself too, as `/` for the root.
+            Self::Dirs(dir) => {
+                entry.metadata().is_dir() && entry.path().trim_matches('/') != dir.trim_matches('/')
+            }
+        }
+    }
+}
+
+/// An iterator over the entries of a blocking listing.
+///
+/// Entries are fetched from the service on demand while iterating.
 #[pyclass(unsendable, module = "opendal")]
-pub struct BlockingLister(ocore::blocking::Lister);
+pub struct BlockingLister {
+    inner: ocore::blocking::Lister,
+    remaining: Option<usize>,
+    filter: Option<ListFilter>,
+}
 
 impl BlockingLister {
-    /// Create a new blocking lister.
-    pub fn new(inner: ocore::blocking::Lister) -> Self {
-        Self(inner)
+    /// Create a new blocking lister that yields at most `limit` entries.
+    pub fn new(inner: ocore::blocking::Lister, limit: Option<usize>) -> Self {
+        Self {
+            inner,
+            remaining: limit,
+            filter: None,
+        }
+    }
+
+    /// Only yield the entries passing `filter`.
+    pub fn with_filter(mut self, filter: ListFilter) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    fn next_entry(&mut self) -> ocore::Result<Option<ocore::Entry>> {
+        while let Some(entry) = self.inner.next().transpose()? {
+            if self.filter.as_ref().is_none_or(|f| f.matches(&entry)) {
+                return Ok(Some(entry));
+            }
+        }
+        Ok(None)
     }
 }
 
@@ -41,48 +89,216 @@ impl BlockingLister {
         slf
     }
     fn __next__(mut slf: PyRefMut<'_, Self>) -> PyResult<Option<PyObject>> {
-        match slf.0.next() {
-            Some(Ok(entry)) => Ok(Some(Entry::new(entry).into_py_any(slf.py())?)),
-            Some(Err(err)) => {
+        if slf.remaining == Some(0) {
+            return Ok(None);
+        }
+        if let Some(remaining) = slf.remaining.as_mut() {
+            *remaining -= 1;
+        }
+        match slf.next_entry() {
+            Ok(Some(entry)) => Ok(Some(Entry::new(entry).into_py_any(slf.py())?)),
+            Err(err) => {
                 let pyerr = format_pyerr(err);
                 Err(pyerr)
             }
-            None => Ok(None),
+            Ok(None) => Ok(None),
         }
     }
 }
 
+/// An async iterator over the entries of a listing.
+///
+/// Each `__anext__` pulls the next entry from the underlying stream, so huge
+/// directories never get materialized in memory.
 #[pyclass(module = "opendal")]
-pub struct AsyncLister(Arc<Mutex<ocore::Lister>>);
+pub struct AsyncLister(
+    Arc<Mutex<(ocore::Lister, Option<usize>)>>,
+    Option<Arc<ListFilter>>,
+);
 
 impl AsyncLister {
-    pub fn new(lister: ocore::Lister) -> Self {
-        Self(Arc::new(Mutex::new(lister)))
+    /// Create a new async lister that yields at most `limit` entries.
+    pub fn new(lister: ocore::Lister, limit: Option<usize>) -> Self {
+        Self(Arc::new(Mutex::new((lister, limit))), None)
+    }
+
+    /// Only yield the entries passing `filter`.
+    pub fn with_filter(mut self, filter: ListFilter) -> Self {
+        self.1 = Some(Arc::new(filter));
+        self
     }
 }
 
 #[pymethods]
 impl AsyncLister {
-    fn __aiter__(slf: PyRef<'_, Self>) -> PyRef<Self> {
+    fn __aiter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
         slf
     }
-    fn __anext__(slf: PyRefMut<'_, Self>) -> PyResult<Option<PyObject>> {
+    fn __anext__<'p>(slf: PyRefMut<'p, Self>, py: Python<'p>) -> PyResult<Bound<'p, PyAny>> {
         let lister = slf.0.clone();
-        let fut = future_into_py(slf.py(), async move {
-            let mut lister = lister.lock().await;
-            let entry = lister.try_next().await.map_err(format_pyerr)?;
+        let filter = slf.1.clone();
+        future_into_py(py, async move {
+            let mut guard = lister.lock().await;
+            let (lister, remaining) = &mut *guard;
+            if *remaining == Some(0) {
+                return Err(PyStopAsyncIteration::new_err("stream exhausted"));
+            }
+            if let Some(remaining) = remaining.as_mut() {
+                *remaining -= 1;
+            }
+            let entry = match filter {
+                Some(filter) => lister
+                    .try_filter(|entry| std::future::ready(filter.matches(entry)))
+                    .try_next()
+                    .await
+                    .map_err(format_pyerr)?,
+                None => lister.try_next().await.map_err(format_pyerr)?,
+            };
             match entry {
-                Some(entry) => Python::with_gil(|py| {
-                    let py_obj = Entry::new(entry).into_py_any(py)?;
-                    Ok(Some(py_obj))
-                }),
+                Some(entry) => Python::with_gil(|py| Entry::new(entry).into_py_any(py)),
                 None => Err(PyStopAsyncIteration::new_err("stream exhausted")),
             }
-        });
+        })
+    }
+}
+
+/// The entries below a directory, nested by their path, for `tree`.
+///
+/// Directories missing from the listing, like the parents of objects on
+/// services without real directories, are filled in.
+pub struct Tree {
+    root: TreeNode,
+    max_depth: Option<usize>,
+}
+
+struct TreeNode {
+    name: String,
+    path: String,
+    metadata: Option<ocore::Metadata>,
+    /// The entries of a directory, or `None` for files and for directories
+    /// at the depth cap, which weren't listed.
+    children: Option<BTreeMap<String, TreeNode>>,
+}
+
+impl TreeNode {
+    fn dir(name: &str, path: String, listed: bool) -> Self {
+        Self {
+            name: name.to_string(),
+            path,
+            metadata: None,
+            children: listed.then(BTreeMap::new),
+        }
+    }
+
+    fn into_py(self, py: Python) -> PyResult<PyObject> {
+        let node = PyDict::new(py);
+        node.set_item("name", self.name)?;
+        node.set_item("path", self.path)?;
+        match self.children {
+            Some(children) => {
+                let children = children
+                    .into_values()
+                    .map(|child| child.into_py(py))
+                    .collect::<PyResult<Vec<_>>>()?;
+                node.set_item("children", children)?;
+            }
+            None => match self.metadata {
+                Some(meta) if !meta.is_dir() => node.set_item("metadata", Metadata::new(meta))?,
+                _ => node.set_item("children", py.None())?,
+            },
+        }
+        node.into_py_any(py)
+    }
+}
 
-        match fut {
-            Ok(fut) => Ok(Some(fut.into())),
-            Err(e) => Err(e),
+impl Tree {
+    /// Create an empty tree below the directory `path`, nesting entries at
+    /// most `max_depth` levels deep.
+    pub fn new(path: &str, max_depth: Option<usize>) -> PyResult<Self> {
+        if max_depth == Some(0) {
+            return Err(PyValueError::new_err(
+                "max_depth must be a positive integer",
+            ));
         }
+        let path = path.trim_start_matches('/');
+        let path = match path {
+            "" => String::new(),
+            path if path.ends_with('/') => path.to_string(),
+            path => format!("{path}/"),
+        };
+        let name = path
+            .trim_end_matches('/')
+            .rsplit('/')
+            .next()
+            .unwrap_or_default();
+        let name = if path.is_empty() {
+            "/".to_string()
+        } else {
+            format!("{name}/")
+        };
+        Ok(Self {
+            root: TreeNode::dir(&name, path, true),
+            max_depth,
+        })
+    }
+
+    /// The directory the tree is below.
+    pub fn root(&self) -> &str {
+        match self.root.path.as_str() {
+            "" => "/",
+            path => path,
+        }
+    }
+
+    /// Add a listed entry to the tree, returning whether it's a directory
+    /// that wasn't already present and whose entries should be listed too.
+    pub fn insert(&mut self, entry: ocore::Entry) -> bool {
+        let (path, meta) = entry.into_parts();
+        let Some(rel) = path.strip_prefix(self.root.path.as_str()) else {
+            return false;
+        };
+        let is_dir = meta.is_dir();
+        let parts: Vec<&str> = rel.trim_end_matches('/').split('/').collect();
+        // Services list the directory itself too, as `/` for the root.
+        if rel.trim_end_matches('/').is_empty()
+            || self.max_depth.is_some_and(|max| parts.len() > max)
+        {
+            return false;
+        }
+
+        let mut node = &mut self.root;
+        let mut prefix = node.path.clone();
+        for part in &parts[..parts.len() - 1] {
+            prefix.push_str(part);
+            prefix.push('/');
+            let Some(children) = node.children.as_mut() else {
+                return false;
+            };
+            node = children
+                .entry(format!("{part}/"))
+                .or_insert_with(|| TreeNode::dir(&format!("{part}/"), prefix.clone(), true));
+        }
+        let Some(children) = node.children.as_mut() else {
+            return false;
+        };
+        let last = parts[parts.len() - 1];
+        let name = if is_dir {
+            format!("{last}/")
+        } else {
+            last.to_string()
+        };
+        if let Some(child) = children.get_mut(&name) {
+            child.metadata.get_or_insert(meta);
+            return false;
+        }
+        let listed = is_dir && self.max_depth.is_none_or(|max| parts.len() < max);
+        let mut child = TreeNode::dir(&name, path, listed);
+        child.metadata = Some(meta);
+        children.insert(name, child);
+        listed
+    }
+
+    pub fn into_py(self, py: Python) -> PyResult<PyObject> {
+        self.root.into_py(py)
     }
 }
@@ -17,6 +17,7 @@
 
 use chrono::prelude::*;
 use pyo3::prelude::*;
+use pyo3::types::PyDict;
 use std::collections::HashMap;
 
 use crate::*;
@@ -44,6 +45,14 @@ impl Entry {
         Metadata::new(self.0.metadata().clone())
     }
 
+    /// Return the path and the fields of `metadata` as a dict of plain
+    /// Python types, which `json.dumps` accepts as is.
+    pub fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = self.metadata().to_dict(py)?;
+        dict.set_item("path", self.path())?;
+        Ok(dict)
+    }
+
     fn __str__(&self) -> &str {
         self.0.path()
     }
@@ -66,11 +75,23 @@ impl Metadata {
     }
 }
 
+/// Treat empty strings returned by some services as missing.
+fn non_empty(v: Option<&str>) -> Option<&str> {
+    v.filter(|v| !v.is_empty())
+}
+
 #[pymethods]
 impl Metadata {
+    /// Cache Control of this entry.
+    #[getter]
+    pub fn cache_control(&self) -> Option<&str> {
+        non_empty(self.0.cache_control())
+    }
+
+    /// Content Disposition of this entry.
     #[getter]
     pub fn content_disposition(&self) -> Option<&str> {
-        self.0.content_disposition()
+        non_empty(self.0.content_disposition())
     }
 
     /// Content length of this entry.
@@ -82,25 +103,25 @@ impl Metadata {
     /// Content MD5 of this entry.
     #[getter]
     pub fn content_md5(&self) -> Option<&str> {
-        self.0.content_md5()
+        non_empty(self.0.content_md5())
     }
 
     /// Content Type of this entry.
     #[getter]
     pub fn content_type(&self) -> Option<&str> {
-        self.0.content_type()
+        non_empty(self.0.content_type())
     }
 
-    /// Content Type of this entry.
+    /// Content Encoding of this entry.
     #[getter]
     pub fn content_encoding(&self) -> Option<&str> {
-        self.0.content_encoding()
+        non_empty(self.0.content_encoding())
     }
 
     /// ETag of this entry.
     #[getter]
     pub fn etag(&self) -> Option<&str> {
-        self.0.etag()
+        non_empty(self.0.etag())
     }
 
     /// mode represents this entry's mode.
@@ -110,6 +131,9 @@ impl Metadata {
     }
 
     /// Returns `true` if this metadata is for a file.
+    ///
+    /// Both `is_file` and `is_dir` are `false` if the service can't tell the
+    /// mode, which `mode` reports as `EntryMode.UNKNOWN`.
     #[getter]
     pub fn is_file(&self) -> bool {
         self.mode().is_file()
@@ -121,7 +145,7 @@ impl Metadata {
         self.mode().is_dir()
     }
 
-    /// Last modified time
+    /// Last modified time of this entry, as a timezone-aware UTC datetime.
     #[getter]
     pub fn last_modified(&self) -> Option<DateTime<Utc>> {
         self.0.last_modified()
@@ -130,31 +154,82 @@ impl Metadata {
     /// Version of this entry, if available.
     #[getter]
     pub fn version(&self) -> Option<&str> {
-        self.0.version()
+        non_empty(self.0.version())
     }
 
-    /// User defined metadata of this entry
+    /// Whether this is the latest version of the entry, or `None` if the
+    /// service doesn't tell.
     #[getter]
-    pub fn user_metadata(&self) -> Option<&HashMap<String, String>> {
-        self.0.user_metadata()
+    pub fn is_current(&self) -> Option<bool> {
+        self.0.is_current()
+    }
+
+    /// Whether this version of the entry has been deleted, like a delete
+    /// marker listed with `deleted=True`.
+    #[getter]
+    pub fn is_deleted(&self) -> bool {
+        self.0.is_deleted()
+    }
+
+    /// User defined metadata of this entry.
+    ///
+    /// Keys are lowercased and stripped of the service's prefix like
+    /// `x-amz-meta-`, so they read back the same from every service no matter
+    /// how the service stores them.
+    #[getter]
+    pub fn user_metadata(&self) -> Option<HashMap<String, String>> {
+        self.0.user_metadata().map(|meta| {
+            meta.iter()
+                .map(|(k, v)| (k.to_lowercase(), v.clone()))
+                .collect()
+        })
+    }
+
+    /// Return the fields as a dict of plain Python types, which `json.dumps`
+    /// accepts as is.
+    ///
+    /// `mode` is `"file"`, `"dir"` or `"unknown"` and `last_modified` is an
+    /// ISO 8601 string with microseconds, which `datetime.fromisoformat`
+    /// parses on every supported Python.
+    pub fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new(py);
+        dict.set_item("mode", self.0.mode().to_string())?;
+        dict.set_item("cache_control", self.cache_control())?;
+        dict.set_item("content_disposition", self.content_disposition())?;
+        dict.set_item("content_length", self.content_length())?;
+        dict.set_item("content_md5", self.content_md5())?;
+        dict.set_item("content_type", self.content_type())?;
+        dict.set_item("content_encoding", self.content_encoding())?;
+        dict.set_item("etag", self.etag())?;
+        dict.set_item(
+            "last_modified",
+            self.last_modified()
+                .map(|v| v.to_rfc3339_opts(SecondsFormat::Micros, false)),
+        )?;
+        dict.set_item("version", self.version())?;
+        dict.set_item("is_current", self.is_current())?;
+        dict.set_item("is_deleted", self.is_deleted())?;
+        dict.set_item("user_metadata", self.user_metadata())?;
+        Ok(dict)
     }
 
     pub fn __repr__(&self) -> String {
         let mut parts = vec![];
 
         parts.push(format!("mode={}", self.0.mode()));
+        parts.push(format!("cache_control={:?}", self.cache_control()));
         parts.push(format!(
             "content_disposition={:?}",
-            self.0.content_disposition()
+            self.content_disposition()
         ));
         parts.push(format!("content_length={}", self.0.content_length()));
-        parts.push(format!("content_md5={:?}", self.0.content_md5()));
-        parts.push(format!("content_type={:?}", self.0.content_type()));
-        parts.push(format!("content_encoding={:?}", self.0.content_encoding()));
-        parts.push(format!("etag={:?}", self.0.etag()));
+        parts.push(format!("content_md5={:?}", self.content_md5()));
+        parts.push(format!("content_type={:?}", self.content_type()));
+        parts.push(format!("content_encoding={:?}", self.content_encoding()));
+        parts.push(format!("etag={:?}", self.etag()));
         parts.push(format!("last_modified={:?}", self.0.last_modified()));
-        parts.push(format!("version={:?}", self.0.version()));
-        parts.push(format!("user_metadata={:?}", self.0.user_metadata()));
+        parts.push(format!("version={:?}", self.version()));
+        parts.push(format!("user_metadata={:?}", self.user_metadata()));
 
         format!("Metadata({})", parts.join(", "))
     }
@@ -16,32 +16,211 @@
 // under the License.
 
 use std::collections::HashMap;
+use std::future::Future;
 use std::path::PathBuf;
 use std::str::FromStr;
-use std::time::Duration;
-
+use std::sync::Arc;
+
+use bytes::Bytes;
+use bytes::BytesMut;
+use chrono::DateTime;
+use chrono::FixedOffset;
+use chrono::Utc;
+use futures::StreamExt;
+use futures::TryStreamExt;
+use opendal::raw::BytesRange;
+use percent_encoding::percent_decode_str;
+use pyo3::exceptions::PyBaseException;
+use pyo3::exceptions::PyRuntimeError;
+use pyo3::exceptions::PyTypeError;
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
-use pyo3::types::PyBytes;
+use pyo3::sync::GILOnceCell;
+use pyo3::types::IntoPyDict;
 use pyo3::types::PyDict;
+use pyo3::types::PyString;
 use pyo3::types::PyTuple;
 use pyo3::IntoPyObjectExt;
-use pyo3_async_runtimes::tokio::future_into_py;
+use tokio::io::AsyncReadExt;
+use tokio::io::AsyncWriteExt;
 
 use crate::*;
 
 fn build_operator(
     scheme: ocore::Scheme,
     map: HashMap<String, String>,
+    http: Option<&HttpOptions>,
+    credential: Option<&Arc<CredentialProvider>>,
 ) -> PyResult<ocore::Operator> {
-    let op = ocore::Operator::via_iter(scheme, map).map_err(format_pyerr)?;
+    // Services are split across packages, so name the ones that are here
+    // instead of the core's generic "scheme is not enabled".
+    if !ocore::Scheme::enabled().contains(&scheme) {
+        return Err(Unsupported::new_err(format!(
+            "service {scheme} is not enabled in this package, enabled services: {}",
+            enabled_services().join(", ")
+        )));
+    }
+    let mut op = match credential {
+        Some(provider) => build_with_credential(scheme, map, provider)?,
+        None => ocore::Operator::via_iter(scheme, map).map_err(format_pyerr)?,
+    };
+    if let Some(http) = http {
+        op = op.layer(ocore::layers::HttpClientLayer::new(http.build()?));
+    }
+    install_extra_headers(&op);
     Ok(op)
 }
 
+/// Return the schemes of the services compiled into this package, sorted.
+#[pyfunction]
+pub fn enabled_services() -> Vec<String> {
+    let mut schemes: Vec<String> = ocore::Scheme::enabled()
+        .into_iter()
+        .map(|scheme| scheme.to_string())
+        .collect();
+    schemes.sort();
+    schemes
+}
+
+/// The config key that the host of a URI fills for `scheme`, like the
+/// bucket of `s3://bucket/prefix`.
+fn uri_host_key(scheme: &str) -> Option<&'static str> {
+    match scheme {
+        "s3" | "gcs" | "oss" | "cos" | "obs" | "b2" | "upyun" => Some("bucket"),
+        "azblob" | "swift" => Some("container"),
+        "azdls" => Some("filesystem"),
+        "azfile" => Some("share_name"),
+        _ => None,
+    }
+}
+
+/// Split a URI like `s3://bucket/prefix?region=us-east-1` into its scheme
+/// and the service config.
+///
+/// The host fills the service's bucket or container, the path becomes
+/// `root` and each query parameter maps to the config key of the same name.
+fn parse_uri(uri: &str) -> PyResult<(String, HashMap<String, String>)> {
+    // Don't echo the URI, its query may hold credentials.
+    let url =
+        url::Url::parse(uri).map_err(|err| PyValueError::new_err(format!("invalid URI: {err}")))?;
+    let scheme = url.scheme().replace('-', "_");
+    if !url.username().is_empty() || url.password().is_some() {
+        return Err(PyValueError::new_err(
+            "credentials in the URI aren't supported, pass them as keyword arguments",
+        ));
+    }
+
+    let mut map = HashMap::new();
+    if let Some(host) = url.host_str().filter(|v| !v.is_empty()) {
+        let Some(key) = uri_host_key(&scheme) else {
+            return Err(PyValueError::new_err(format!(
+                "service {scheme} doesn't take a host in the URI, \
+                 pass its endpoint as a query parameter instead"
+            )));
+        };
+        if url.port().is_some() {
+            return Err(PyValueError::new_err(format!(
+                "the {key} in the URI can't have a port"
+            )));
+        }
+        map.insert(key.to_string(), host.to_string());
+    }
+    let root = percent_decode_str(url.path())
+        .decode_utf8()
+        .map_err(|err| PyValueError::new_err(format!("invalid URI path: {err}")))?;
+    if !root.is_empty() && root != "/" {
+        map.insert("root".to_string(), root.into_owned());
+    }
+    map.extend(
+        url.query_pairs()
+            .map(|(k, v)| (k.into_owned(), v.into_owned())),
+    );
+    Ok((scheme, map))
+}
+
+/// The start of the environment variables holding the config of `scheme`,
+/// like `OPENDAL_S3_` under the `OPENDAL` prefix.
+fn env_var_prefix(scheme: &str, prefix: &str) -> String {
+    let scheme = scheme.to_uppercase().replace('-', "_");
+    match prefix {
+        "" => format!("{scheme}_"),
+        prefix => format!("{}_{scheme}_", prefix.to_uppercase()),
+    }
+}
+
+/// Collect the service config of `scheme` from the environment variables
+/// named `{PREFIX}_{SCHEME}_{KEY}`, keyed by the lowercased `KEY`.
+///
+/// Variables that aren't valid unicode are skipped.
+fn parse_env(scheme: &str, prefix: &str) -> HashMap<String, String> {
+    let var_prefix = env_var_prefix(scheme, prefix);
+    std::env::vars_os()
+        .filter_map(|(k, v)| Some((k.into_string().ok()?, v.into_string().ok()?)))
+        .filter_map(|(k, v)| {
+            let key = k.strip_prefix(&var_prefix).filter(|key| !key.is_empty())?;
+            Some((key.to_lowercase(), v))
+        })
+        .collect()
+}
+
+/// Raise `ConfigInvalid` naming the variable of the first config key that
+/// `scheme` can't be built without, like the bucket of `s3`, if it's unset.
+///
+/// Only the keys from `uri_host_key` and the `root` of `fs` are known here,
+/// other keys are checked by the service itself, see `env_config_error`.
+fn check_env_config(scheme: &str, prefix: &str, config: &Bound<PyDict>) -> PyResult<()> {
+    let required = uri_host_key(scheme)
+        .into_iter()
+        .chain((scheme == "fs").then_some("root"));
+    for key in required {
+        if !config.contains(key)? {
+            return Err(ConfigInvalid::new_err(format!(
+                "config key {key:?} of service {scheme} is missing, set {}{}",
+                env_var_prefix(scheme, prefix),
+                key.to_uppercase()
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Point a `ConfigInvalid` raised while building `scheme` at the variables
+/// `from_env` reads, since the service only names its config key.
+fn env_config_error(py: Python, err: PyErr, scheme: &str, prefix: &str) -> PyErr {
+    if !err.is_instance_of::<ConfigInvalid>(py) {
+        return err;
+    }
+    let new = ConfigInvalid::new_err(format!(
+        "{}\n\nThe config of service {scheme} is read from the variables named {}<KEY>.",
+        err.value(py).to_string().trim_end(),
+        env_var_prefix(scheme, prefix)
+    ));
+    new.set_cause(py, Some(err));
+    new
+}
+
+/// The blocking operator to run one call with, bounded by `timeout` if given.
+fn blocking_with_deadline(
+    op: &ocore::blocking::Operator,
+    timeout: Option<PyDuration>,
+) -> PyResult<ocore::blocking::Operator> {
+    if timeout.is_none() {
+        return Ok(op.clone());
+    }
+    let op = with_deadline(op.clone().into(), timeout);
+
+    let runtime = pyo3_async_runtimes::tokio::get_runtime();
+    let _guard = runtime.enter();
+    ocore::blocking::Operator::new(op).map_err(format_pyerr)
+}
+
 fn build_blocking_operator(
     scheme: ocore::Scheme,
     map: HashMap<String, String>,
+    http: Option<&HttpOptions>,
+    credential: Option<&Arc<CredentialProvider>>,
 ) -> PyResult<ocore::blocking::Operator> {
-    let op = ocore::Operator::via_iter(scheme, map).map_err(format_pyerr)?;
+    let op = build_operator(scheme, map, http, credential)?;
 
     let runtime = pyo3_async_runtimes::tokio::get_runtime();
     let _guard = runtime.enter();
@@ -49,21 +228,1380 @@ fn build_blocking_operator(
     Ok(op)
 }
 
+/// Reject checksum verification for range reads, which can't be checked
+/// against the digest of the whole object.
+fn check_verify_range(opts: &ReadOptions) -> PyResult<()> {
+    if opts.has_range() {
+        return Err(PyValueError::new_err(
+            "verify can't be used with offset or size",
+        ));
+    }
+    Ok(())
+}
+
+/// Prepare to verify a read of `path` against the checksum in `meta`.
+///
+/// The read is pinned to the stat'ed object with `if_match` where the
+/// service supports it, so a concurrent overwrite raises `ConditionNotMatch`
+/// instead of looking like corruption.
+fn checksum_verifier(
+    info: &ocore::OperatorInfo,
+    path: &str,
+    meta: &ocore::Metadata,
+    opts: &mut ReadOptions,
+    required: bool,
+) -> PyResult<Option<ChecksumVerifier>> {
+    let verifier = ChecksumVerifier::new(path, meta, required)?;
+    if verifier.is_some() && opts.if_match.is_none() && info.full_capability().read_with_if_match {
+        opts.if_match = meta.etag().map(|v| v.to_string());
+    }
+    Ok(verifier)
+}
+
+/// The read of `read_if_modified`, sent only if the object changed after
+/// `since`. Services that can't send it conditionally compare the last
+/// modified time of `meta` themselves, to the second like HTTP dates.
+fn if_modified_read(
+    info: &ocore::OperatorInfo,
+    meta: &ocore::Metadata,
+    since: DateTime<Utc>,
+) -> Option<ocore::options::ReadOptions> {
+    if info.full_capability().read_with_if_modified_since {
+        return Some(ocore::options::ReadOptions {
+            if_modified_since: Some(since),
+            ..Default::default()
+        });
+    }
+    match meta.last_modified() {
+        Some(modified) if modified.timestamp() <= since.timestamp() => None,
+        _ => Some(Default::default()),
+    }
+}
+
+/// Turn the `304 Not Modified` of a conditional read into `None`.
+fn modified_content(res: ocore::Result<ocore::Buffer>) -> PyResult<Option<ocore::Buffer>> {
+    match res {
+        Ok(buffer) => Ok(Some(buffer)),
+        Err(err) if err.kind() == ocore::ErrorKind::ConditionNotMatch => Ok(None),
+        Err(err) => Err(format_pyerr(err)),
+    }
+}
+
+/// Validate the options of `read_with_metadata`, which reads the whole
+/// object so the metadata describes exactly the content returned.
+fn check_whole_read(kwargs: &mut ReadOptions) -> PyResult<()> {
+    kwargs.check()?;
+    if kwargs.has_range() {
+        return Err(PyValueError::new_err(
+            "read_with_metadata reads the whole object, range, offset and size aren't supported",
+        ));
+    }
+    Ok(())
+}
+
+/// How many of the ranges of `read_ranges` are fetched at once by default.
+const READ_RANGES_CONCURRENT: usize = 16;
+
+/// Validate the options of `read_ranges`, which can't take a range of
+/// their own.
+fn check_read_ranges(kwargs: &mut ReadOptions) -> PyResult<()> {
+    kwargs.check()?;
+    if kwargs.has_range() {
+        return Err(PyValueError::new_err(
+            "read_ranges takes its ranges as a list, range, offset and size aren't supported",
+        ));
+    }
+    kwargs.concurrent = kwargs.concurrent.or(Some(READ_RANGES_CONCURRENT));
+    Ok(())
+}
+
+/// Clamp the `(offset, length)` pairs of `read_ranges` to `content_length`,
+/// following HTTP range semantics like `read` does.
+fn clamp_ranges(ranges: &[(u64, u64)], content_length: u64) -> Vec<std::ops::Range<u64>> {
+    ranges
+        .iter()
+        .map(|(offset, len)| {
+            let start = (*offset).min(content_length);
+            start..offset.saturating_add(*len).min(content_length)
+        })
+        .collect()
+}
+
+/// Clamp `range` to `content_length` like `ReadOptions::clamp_range` does,
+/// returning `None` if nothing is left to read.
+fn clamp_range(range: BytesRange, content_length: u64) -> Option<BytesRange> {
+    let offset = range.offset().min(content_length);
+    let remaining = content_length - offset;
+    let size = range.size().map_or(remaining, |v| v.min(remaining));
+    (size > 0).then(|| BytesRange::new(offset, Some(size)))
+}
+
+/// Append the class name of `layer` to the names of the layers applied.
+fn with_layer(layers: &[String], layer: &Bound<layers::Layer>) -> PyResult<Vec<String>> {
+    let mut layers = layers.to_vec();
+    layers.push(layer.get_type().name()?.to_string());
+    Ok(layers)
+}
+
+/// Describe an operator by its scheme, root, name and layers, leaving out
+/// the rest of its options since they may hold credentials.
+fn operator_repr(class: &str, info: &ocore::OperatorInfo, layers: &[String]) -> String {
+    let mut repr = format!("{class}(\"{}\", root=\"{}\"", info.scheme(), info.root());
+    let name = info.name();
+    if !name.is_empty() {
+        repr.push_str(&format!(", name=\"{name}\""));
+    }
+    if !layers.is_empty() {
+        repr.push_str(&format!(", layers=[{}]", layers.join(", ")));
+    }
+    repr.push(')');
+    repr
+}
+
+/// Limits of S3 object tagging, the service tags are modeled after.
+const MAX_TAGS: usize = 10;
+const MAX_TAG_KEY_LEN: usize = 128;
+const MAX_TAG_VALUE_LEN: usize = 256;
+/// Characters allowed in tags besides letters, digits and whitespace.
+const TAG_PUNCTUATION: &str = "+-=._:/@";
+
+fn check_tagging(info: &ocore::OperatorInfo) -> PyResult<()> {
+    if !info.full_capability().tagging {
+        return Err(Unsupported::new_err(format!(
+            "service {} doesn't support tagging",
+            info.scheme()
+        )));
+    }
+    Ok(())
+}
+
+/// Reject tags the service would refuse before sending them, so the error
+/// names the offending tag.
+fn check_tags(info: &ocore::OperatorInfo, tags: &HashMap<String, String>) -> PyResult<()> {
+    check_tagging(info)?;
+    if tags.len() > MAX_TAGS {
+        return Err(PyValueError::new_err(format!(
+            "at most {MAX_TAGS} tags are allowed, got {}",
+            tags.len()
+        )));
+    }
+    for (key, value) in tags {
+        if key.is_empty() || key.chars().count() > MAX_TAG_KEY_LEN {
+            return Err(PyValueError::new_err(format!(
+                "tag key {key:?} must be 1 to {MAX_TAG_KEY_LEN} characters long"
+            )));
+        }
+        if value.chars().count() > MAX_TAG_VALUE_LEN {
+            return Err(PyValueError::new_err(format!(
+                "tag value of {key:?} must be at most {MAX_TAG_VALUE_LEN} characters long"
+            )));
+        }
+        if key.starts_with("aws:") {
+            return Err(PyValueError::new_err(format!(
+                "tag key {key:?} uses the reserved prefix aws:"
+            )));
+        }
+        let invalid = key
+            .chars()
+            .chain(value.chars())
+            .find(|c| !(c.is_alphanumeric() || c.is_whitespace() || TAG_PUNCTUATION.contains(*c)));
+        if let Some(c) = invalid {
+            return Err(PyValueError::new_err(format!(
+                "tag {key:?} contains {c:?}, only letters, digits, whitespace and {TAG_PUNCTUATION} are allowed"
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Decide whether `rename` should be emulated with copy and delete.
+///
+/// Emulation is only used when requested and the service can't rename
+/// natively. It needs copy, stat and delete to verify the copy and remove
+/// the source, so `Unsupported` is raised up front if any is missing.
+fn rename_emulated(info: &ocore::OperatorInfo, emulate: bool) -> PyResult<bool> {
+    let cap = info.full_capability();
+    if !emulate || cap.rename {
+        return Ok(false);
+    }
+    if !(cap.copy && cap.stat && cap.delete) {
+        return Err(Unsupported::new_err(format!(
+            "service {} can't rename, and emulating it needs copy, stat and delete",
+            info.scheme()
+        )));
+    }
+    Ok(true)
+}
+
+/// Check that the copy made by an emulated rename matches the source
+/// before the source gets deleted.
+fn verify_copy(
+    source: &str,
+    target: &str,
+    src: &ocore::Metadata,
+    dst: &ocore::Metadata,
+) -> PyResult<()> {
+    let md5_differs = matches!(
+        (src.content_md5(), dst.content_md5()),
+        (Some(a), Some(b)) if a != b
+    );
+    if src.content_length() != dst.content_length() || md5_differs {
+        let err = ocore::Error::new(
+            ocore::ErrorKind::Unexpected,
+            "copy doesn't match the source, source is kept",
+        )
+        .with_operation("rename")
+        .with_context("source", source)
+        .with_context("target", target);
+        return Err(format_pyerr(err));
+    }
+    Ok(())
+}
+
+/// How `copy_from` copies on the service itself, naming the operator that
+/// runs the copy and the other path relative to its root.
+enum ServerCopy {
+    /// Copy with the target operator, from the source path under its root.
+    OnTarget(String),
+    /// Copy with the source operator, to the target path under its root.
+    OnSource(String),
+}
+
+/// Decide whether `copy_from` can copy `source` of one operator to `target`
+/// of another without moving the bytes through this process.
+///
+/// That needs both operators to be built with the same scheme and options
+/// apart from `root`, so they reach the same bucket or container, and the
+/// service to support copy. One operator's root must also contain both
+/// paths, since the copy runs on a single operator.
+fn server_copy(
+    src: (ocore::Scheme, &HashMap<String, String>, ocore::OperatorInfo),
+    source: &str,
+    dst: (ocore::Scheme, &HashMap<String, String>, ocore::OperatorInfo),
+    target: &str,
+) -> Option<ServerCopy> {
+    let config = |map: &HashMap<String, String>| {
+        map.iter()
+            .filter(|(k, _)| k.as_str() != "root")
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect::<HashMap<_, _>>()
+    };
+    if src.0 != dst.0 || config(src.1) != config(dst.1) || !dst.2.full_capability().copy {
+        return None;
+    }
+    // Some services keep the root as given, so end both with a slash.
+    let src_root = format!("{}/", src.2.root().trim_end_matches('/'));
+    let dst_root = format!("{}/", dst.2.root().trim_end_matches('/'));
+    let source = format!("{src_root}{}", source.trim_start_matches('/'));
+    let target = format!("{dst_root}{}", target.trim_start_matches('/'));
+    if let Some(source) = source.strip_prefix(&dst_root) {
+        return Some(ServerCopy::OnTarget(source.to_string()));
+    }
+    target
+        .strip_prefix(&src_root)
+        .map(|target| ServerCopy::OnSource(target.to_string()))
+}
+
+/// Copy `source` of `src` to `target` of `dst` by streaming it through,
+/// aborting the write if anything fails.
+fn blocking_copy_between(
+    src: &ocore::blocking::Operator,
+    source: &str,
+    dst: &ocore::blocking::Operator,
+    target: &str,
+) -> PyResult<()> {
+    let mut bufs = src
+        .reader(source)
+        .and_then(|r| r.into_iterator(..))
+        .map_err(format_pyerr)?;
+    // Read the first piece before opening the target, so a missing source
+    // doesn't leave an empty target behind.
+    let first = bufs.next().transpose().map_err(format_pyerr)?;
+    let mut writer = dst.writer(target).map_err(format_pyerr)?;
+    for buf in first.map(Ok).into_iter().chain(bufs) {
+        let res = buf.and_then(|buf| writer.write(buf));
+        if let Err(err) = res {
+            let _ = writer.abort();
+            return Err(format_pyerr(err));
+        }
+    }
+    writer.close().map(|_| ()).map_err(format_pyerr)
+}
+
+/// Copy `source` of `src` to `target` of `dst` by streaming it through,
+/// aborting the write if anything fails.
+async fn copy_between(
+    src: ocore::Operator,
+    source: String,
+    dst: ocore::Operator,
+    target: String,
+) -> PyResult<()> {
+    let mut bufs = src
+        .reader(&source)
+        .await
+        .map_err(format_pyerr)?
+        .into_stream(..)
+        .await
+        .map_err(format_pyerr)?;
+    // Read the first piece before opening the target, so a missing source
+    // doesn't leave an empty target behind.
+    let first = bufs.try_next().await.map_err(format_pyerr)?;
+    let mut bufs = futures::stream::iter(first.map(Ok)).chain(bufs);
+    let mut writer = dst.writer(&target).await.map_err(format_pyerr)?;
+    loop {
+        let res = match bufs.next().await {
+            None => break,
+            Some(Ok(buf)) => writer.write(buf).await.map_err(format_pyerr),
+            Some(Err(err)) => Err(format_pyerr(err)),
+        };
+        if let Err(err) = res {
+            let _ = writer.abort().await;
+            return Err(err);
+        }
+    }
+    writer.close().await.map(|_| ()).map_err(format_pyerr)
+}
+
+/// Size of the pieces `copy_from` reads from an operator of another package.
+const FOREIGN_COPY_CHUNK: usize = 4 * 1024 * 1024;
+
+/// Copy the bytes-like `chunk` read from an operator of another package.
+fn foreign_chunk(chunk: &Bound<PyAny>) -> PyResult<Bytes> {
+    let view = BytesView::get(chunk)?;
+    Ok(Bytes::copy_from_slice(view.as_slice()))
+}
+
+/// Refuse a `source_op` for `copy_from` that isn't an operator.
+///
+/// Each OpenDAL package builds its own `Operator` class, so one from another
+/// package, like `opendal_cloud` for `dropbox`, is read through its `open` API.
+fn check_foreign_operator(source_op: &Bound<PyAny>, kind: &str) -> PyResult<()> {
+    if !source_op.hasattr("open")? {
+        return Err(PyTypeError::new_err(format!(
+            "source_op must be an OpenDAL {kind}, got {}",
+            source_op.get_type().name()?
+        )));
+    }
+    Ok(())
+}
+
+/// Copy `source` of an operator from another package to `target` of `dst`,
+/// streaming it through the file returned by its `open`.
+fn blocking_copy_from_foreign(
+    src: &Bound<PyAny>,
+    source: &str,
+    dst: &ocore::blocking::Operator,
+    target: &str,
+) -> PyResult<()> {
+    let file = src.call_method1("open", (source, "rb"))?;
+    if file.hasattr("__await__")? {
+        let _ = file.call_method0("close");
+        return Err(PyTypeError::new_err(
+            "source_op must be a blocking Operator, use AsyncOperator.copy_from \
+             to copy from an AsyncOperator",
+        ));
+    }
+    let read = || foreign_chunk(&file.call_method1("read", (FOREIGN_COPY_CHUNK,))?);
+    let res = (|| {
+        // Read the first piece before opening the target, so a missing
+        // source doesn't leave an empty target behind.
+        let mut buf = read()?;
+        let mut writer = dst.writer(target).map_err(format_pyerr)?;
+        while !buf.is_empty() {
+            match writer
+                .write(buf)
+                .map_err(format_pyerr)
+                .and_then(|()| read())
+            {
+                Ok(next) => buf = next,
+                Err(err) => {
+                    let _ = writer.abort();
+                    return Err(err);
+                }
+            }
+        }
+        writer.close().map(|_| ()).map_err(format_pyerr)
+    })();
+    let closed = file.call_method0("close");
+    res.and(closed.map(|_| ()))
+}
+
+/// Turn the awaitable returned by an async operator of another package,
+/// like the one of its `open`, into a future.
+fn foreign_future(
+    awaitable: Bound<PyAny>,
+) -> PyResult<impl Future<Output = PyResult<PyObject>> + Send> {
+    if !awaitable.hasattr("__await__")? {
+        return Err(PyTypeError::new_err(
+            "source_op must be an AsyncOperator, use Operator.copy_from \
+             to copy from a blocking Operator",
+        ));
+    }
+    pyo3_async_runtimes::tokio::into_future(awaitable)
+}
+
+/// Await the awaitable returned by `f` on a file of another package.
+async fn await_foreign(
+    f: impl for<'py> FnOnce(Python<'py>) -> PyResult<Bound<'py, PyAny>>,
+) -> PyResult<PyObject> {
+    Python::with_gil(|py| foreign_future(f(py)?))?.await
+}
+
+/// Copy the source opened by `open`, the `open` of an async operator from
+/// another package, to `target` of `dst`, streaming it through the file.
+async fn copy_from_foreign(
+    open: impl Future<Output = PyResult<PyObject>>,
+    dst: ocore::Operator,
+    target: String,
+) -> PyResult<()> {
+    let file = open.await?;
+    let read = || async {
+        let chunk =
+            await_foreign(|py| file.bind(py).call_method1("read", (FOREIGN_COPY_CHUNK,))).await?;
+        Python::with_gil(|py| foreign_chunk(chunk.bind(py)))
+    };
+    let res = async {
+        // Read the first piece before opening the target, so a missing
+        // source doesn't leave an empty target behind.
+        let mut buf = read().await?;
+        let mut writer = dst.writer(&target).await.map_err(format_pyerr)?;
+        while !buf.is_empty() {
+            let res = match writer.write(buf).await {
+                Ok(()) => read().await,
+                Err(err) => Err(format_pyerr(err)),
+            };
+            match res {
+                Ok(next) => buf = next,
+                Err(err) => {
+                    let _ = writer.abort().await;
+                    return Err(err);
+                }
+            }
+        }
+        writer.close().await.map(|_| ()).map_err(format_pyerr)
+    }
+    .await;
+    let closed = await_foreign(|py| file.bind(py).call_method0("close")).await;
+    res.and(closed.map(|_| ()))
+}
+
+/// Stat options that look up `version` of an object, or its current one.
+fn version_stat_options(version: &Option<String>) -> ocore::options::StatOptions {
+    ocore::options::StatOptions {
+        version: version.clone(),
+        ..Default::default()
+    }
+}
+
+/// Whether a delete with `missing_ok` false has to stat the path first.
+///
+/// Services that report a missing object raise `NotFound` from the delete
+/// itself, but most treat deleting it as success, so a stat beforehand is
+/// the only way to tell. It races with a concurrent delete of the path, one
+/// that lands between the stat and the delete makes the delete succeed.
+fn check_missing(info: &ocore::OperatorInfo, missing_ok: bool) -> bool {
+    !missing_ok && info.full_capability().stat
+}
+
+/// Map the stat made before a delete by [`check_missing`], raising only if
+/// the path doesn't exist, other errors are left to the delete to report.
+fn missing_result(res: ocore::Result<ocore::Metadata>) -> PyResult<()> {
+    match res {
+        Err(err) if err.kind() == ocore::ErrorKind::NotFound => Err(format_pyerr(err)),
+        _ => Ok(()),
+    }
+}
+
+/// Map the result of a delete, treating `NotFound` as success if
+/// `missing_ok`, since some services raise it for a missing object.
+fn delete_result(res: ocore::Result<()>, missing_ok: bool) -> PyResult<()> {
+    match res {
+        Err(err) if missing_ok && err.kind() == ocore::ErrorKind::NotFound => Ok(()),
+        res => res.map_err(format_pyerr),
+    }
+}
+
+/// Refuse blocking calls on a thread that drives the async runtime, like
+/// from a layer callback, where `block_on` would panic. Callbacks run
+/// through `outside_runtime`, like progress callbacks, may make them.
+///
+/// Refuse them on a thread running an asyncio event loop too, which they
+/// would stall until done.
+fn check_blocking() -> PyResult<()> {
+    if tokio::runtime::Handle::try_current().is_ok() && !is_outside_runtime() {
+        return Err(PyRuntimeError::new_err(
+            "the blocking Operator can't be used inside OpenDAL's async runtime, \
+             e.g. from a layer callback, use AsyncOperator instead",
+        ));
+    }
+    static GET_RUNNING_LOOP: GILOnceCell<PyObject> = GILOnceCell::new();
+    Python::with_gil(|py| {
+        let get_running_loop = GET_RUNNING_LOOP.import(py, "asyncio", "_get_running_loop")?;
+        if !get_running_loop.call0()?.is_none() {
+            return Err(PyRuntimeError::new_err(
+                "the blocking Operator can't be used inside a running asyncio event loop, \
+                 which it would stall, use AsyncOperator instead",
+            ));
+        }
+        Ok(())
+    })
+}
+
+/// How many bytes to write at a time when streaming if `chunk` isn't set.
+const WRITE_STEP: usize = 1024 * 1024;
+
+/// Read the range of `opts`, clamped to the size of the object the way an
+/// HTTP range is: a range starting past the end reads nothing and one
+/// running past it stops there.
+///
+/// The range is sent as is, the object is only stat-ed to clamp it once the
+/// service refuses it.
+fn blocking_read_clamped(
+    op: &ocore::blocking::Operator,
+    path: &str,
+    opts: ReadOptions,
+) -> ocore::Result<ocore::Buffer> {
+    let ranged = opts.has_range();
+    let stat = opts.stat_options();
+    let mut args: ocore::options::ReadOptions = opts.into();
+    match op.read_options(path, args.clone()) {
+        Err(err) if ranged && err.kind() == ocore::ErrorKind::RangeNotSatisfied => {
+            let meta = op.stat_options(path, stat)?;
+            let Some(range) = clamp_range(args.range, meta.content_length()) else {
+                return Ok(ocore::Buffer::new());
+            };
+            args.range = range;
+            op.read_options(path, args)
+        }
+        res => res,
+    }
+}
+
+/// Read `path` in chunks so that `callback` can follow the progress.
+///
+/// The total is the size of the range asked for, or what's left of the
+/// object after `offset` if no size is given. A range running past the end
+/// of the object is clamped like `blocking_read_clamped` does, and the read
+/// resumes where the service stopped.
+///
+/// Chunks are fetched one `block_on` at a time, so the callback never runs
+/// inside the runtime and may use the blocking operator itself.
+fn blocking_read_with_progress(
+    op: &ocore::blocking::Operator,
+    path: &str,
+    opts: ReadOptions,
+    callback: PyObject,
+) -> PyResult<Vec<u8>> {
+    let ranged = opts.has_range();
+    let stat = opts.stat_options();
+    let range = opts.make_range();
+    let args: ocore::options::ReaderOptions = opts.into();
+    // Only the total of a range without a size needs a stat, which isn't
+    // used to clamp the range.
+    let total = match range.size() {
+        Some(size) => size,
+        None => op
+            .stat_options(path, stat.clone())
+            .map_err(format_pyerr)?
+            .content_length()
+            .saturating_sub(range.offset()),
+    };
+    let mut progress = Progress::new(callback, Some(total));
+    progress.report()?;
+
+    let mut buffer = Vec::new();
+    let reader = op
+        .reader_options(path, args.clone())
+        .map_err(format_pyerr)?;
+    let Some(err) = blocking_read_into(reader, range, &mut buffer, &mut progress)? else {
+        return Ok(buffer);
+    };
+    if !ranged {
+        return Err(format_pyerr(err));
+    }
+
+    // The range ran past the end of the object, read the rest of what's there.
+    let meta = op.stat_options(path, stat).map_err(format_pyerr)?;
+    let clamped = clamp_range(range, meta.content_length());
+    progress.set_total(Some(clamped.and_then(|v| v.size()).unwrap_or_default()));
+    let Some(rest) = clamped.and_then(|v| resume_range(v, buffer.len())) else {
+        progress.report()?;
+        return Ok(buffer);
+    };
+    let reader = op.reader_options(path, args).map_err(format_pyerr)?;
+    match blocking_read_into(reader, rest, &mut buffer, &mut progress)? {
+        Some(err) => Err(format_pyerr(err)),
+        None => Ok(buffer),
+    }
+}
+
+/// The part of `range` left after its first `read` bytes, if any.
+fn resume_range(range: BytesRange, read: usize) -> Option<BytesRange> {
+    let size = range.size()?.checked_sub(read as u64).filter(|v| *v > 0)?;
+    Some(BytesRange::new(range.offset() + read as u64, Some(size)))
+}
+
+/// Append `range` of `reader` to `buffer`, advancing `progress`.
+///
+/// Return the error if the service refused the range.
+fn blocking_read_into(
+    reader: ocore::blocking::Reader,
+    range: BytesRange,
+    buffer: &mut Vec<u8>,
+    progress: &mut Progress,
+) -> PyResult<Option<ocore::Error>> {
+    let chunks = reader
+        .into_iterator(range.to_range())
+        .map_err(format_pyerr)?;
+    for bs in chunks {
+        let bs = match bs {
+            Ok(bs) => bs,
+            Err(err) if err.kind() == ocore::ErrorKind::RangeNotSatisfied => return Ok(Some(err)),
+            Err(err) => return Err(format_pyerr(err)),
+        };
+        let n = bs.len();
+        for chunk in bs {
+            buffer.extend_from_slice(&chunk);
+        }
+        progress.advance(n)?;
+    }
+    Ok(None)
+}
+
+/// Write `bs` piece by piece, copying one piece at a time into the writer.
+///
+/// If `callback` is given, it follows the progress. The write is aborted if
+/// it fails or the callback raises.
+fn blocking_write_chunks(
+    op: &ocore::blocking::Operator,
+    path: &str,
+    bs: &[u8],
+    mut opts: WriteOptions,
+    callback: Option<PyObject>,
+) -> PyResult<Metadata> {
+    let step = match opts.plan(&op.info(), Some(bs.len())) {
+        true => bs.len().max(1),
+        false => opts.chunk.unwrap_or(WRITE_STEP),
+    };
+    let mut progress = callback.map(|cb| Progress::new(cb, Some(bs.len() as u64)));
+    if let Some(progress) = progress.as_mut() {
+        progress.report()?;
+    }
+
+    let mut writer = op.writer_options(path, opts.into()).map_err(format_pyerr)?;
+    for chunk in bs.chunks(step) {
+        let res = match writer.write(Bytes::copy_from_slice(chunk)) {
+            Ok(()) => progress
+                .as_mut()
+                .map_or(Ok(()), |progress| progress.advance(chunk.len())),
+            Err(err) => Err(format_pyerr(err)),
+        };
+        // Don't leave uploaded parts behind when the write stops midway.
+        if let Err(err) = res {
+            let _ = writer.abort();
+            return Err(err);
+        }
+    }
+    writer.close().map(Metadata::new).map_err(format_pyerr)
+}
+
+/// Stream the chunks of an iterable into `path` as they're produced.
+fn blocking_write_iter(
+    py: Python,
+    op: &ocore::blocking::Operator,
+    path: &str,
+    chunks: Chunks,
+    mut opts: WriteOptions,
+    callback: Option<PyObject>,
+) -> PyResult<Metadata> {
+    let mut progress = callback.map(|cb| Progress::new(cb, None));
+    if let Some(progress) = progress.as_mut() {
+        progress.report()?;
+    }
+
+    // Content sent in a single request is held until the iterable ends.
+    let one_shot = opts.plan(&op.info(), None);
+    let mut held = Vec::new();
+    let mut writer = op.writer_options(path, opts.into()).map_err(format_pyerr)?;
+    loop {
+        let res = match chunks.blocking_next(py) {
+            Ok(Some(chunk)) if one_shot => {
+                let len = chunk.len();
+                held.push(chunk);
+                progress
+                    .as_mut()
+                    .map_or(Ok(()), |progress| progress.advance(len))
+            }
+            Ok(Some(chunk)) => {
+                let len = chunk.len();
+                match writer.write(chunk) {
+                    Ok(()) => progress
+                        .as_mut()
+                        .map_or(Ok(()), |progress| progress.advance(len)),
+                    Err(err) => Err(format_pyerr(err)),
+                }
+            }
+            Ok(None) => break,
+            Err(err) => Err(err),
+        };
+        // Don't leave a partial upload behind when the producer fails.
+        if let Err(err) = res {
+            let _ = writer.abort();
+            return Err(err);
+        }
+    }
+    if !held.is_empty() {
+        if let Err(err) = writer.write(held) {
+            let _ = writer.abort();
+            return Err(format_pyerr(err));
+        }
+    }
+    writer.close().map(Metadata::new).map_err(format_pyerr)
+}
+
+/// Apply the write strategy to a file opened for writing, whose length isn't
+/// known. Files pass what's written on, so they can't hold it all for a
+/// single request.
+fn plan_file_write(opts: &mut WriteOptions, info: &ocore::OperatorInfo) -> PyResult<()> {
+    if opts.strategy == Some(WriteStrategy::Single) {
+        return Err(PyValueError::new_err(
+            "open doesn't support strategy \"single\"",
+        ));
+    }
+    opts.plan(info, None);
+    Ok(())
+}
+
+/// Build the options to write `len` bytes at `offset`, rejecting services
+/// that can't patch in place and patches past their maximum size.
+fn write_at_options(
+    info: &ocore::OperatorInfo,
+    offset: u64,
+    len: usize,
+) -> PyResult<ocore::options::WriteOptions> {
+    let cap = info.full_capability();
+    if !cap.write_with_offset {
+        return Err(Unsupported::new_err(format!(
+            "service {} doesn't support write at offset",
+            info.scheme()
+        )));
+    }
+    if let Some(max) = cap.write_total_max_size {
+        if offset.saturating_add(len as u64) > max as u64 {
+            return Err(PyValueError::new_err(format!(
+                "writing {len} bytes at offset {offset} exceeds the maximum size {max} of service {}",
+                info.scheme()
+            )));
+        }
+    }
+
+    Ok(ocore::options::WriteOptions {
+        offset: Some(offset),
+        ..Default::default()
+    })
+}
+
+/// Read the range of `opts` like `blocking_read_clamped` does.
+async fn read_clamped(
+    op: &ocore::Operator,
+    path: &str,
+    opts: ReadOptions,
+) -> ocore::Result<ocore::Buffer> {
+    let ranged = opts.has_range();
+    let stat = opts.stat_options();
+    let mut args: ocore::options::ReadOptions = opts.into();
+    match op.read_options(path, args.clone()).await {
+        Err(err) if ranged && err.kind() == ocore::ErrorKind::RangeNotSatisfied => {
+            let meta = op.stat_options(path, stat).await?;
+            let Some(range) = clamp_range(args.range, meta.content_length()) else {
+                return Ok(ocore::Buffer::new());
+            };
+            args.range = range;
+            op.read_options(path, args).await
+        }
+        res => res,
+    }
+}
+
+/// Fetch `ranges` of `path` for `read_ranges`, clamping them like
+/// `read_clamped` does.
+async fn fetch_clamped(
+    op: &ocore::Operator,
+    path: &str,
+    opts: ReadOptions,
+    ranges: &[(u64, u64)],
+) -> ocore::Result<Vec<ocore::Buffer>> {
+    let stat = opts.stat_options();
+    let reader = op.reader_options(path, opts.into()).await?;
+    let requested = ranges
+        .iter()
+        .map(|(offset, len)| *offset..offset.saturating_add(*len))
+        .collect();
+    match reader.fetch(requested).await {
+        Err(err) if err.kind() == ocore::ErrorKind::RangeNotSatisfied => {
+            let meta = op.stat_options(path, stat).await?;
+            reader
+                .fetch(clamp_ranges(ranges, meta.content_length()))
+                .await
+        }
+        res => res,
+    }
+}
+
+/// Read `path` as a stream so that `callback` can follow the progress, like
+/// `blocking_read_with_progress` does.
+async fn read_with_progress(
+    op: ocore::Operator,
+    path: String,
+    opts: ReadOptions,
+    callback: PyObject,
+) -> PyResult<Vec<u8>> {
+    let ranged = opts.has_range();
+    let stat = opts.stat_options();
+    let range = opts.make_range();
+    let args: ocore::options::ReaderOptions = opts.into();
+    let total = match range.size() {
+        Some(size) => size,
+        None => op
+            .stat_options(&path, stat.clone())
+            .await
+            .map_err(format_pyerr)?
+            .content_length()
+            .saturating_sub(range.offset()),
+    };
+    let mut progress = Progress::new(callback, Some(total));
+    progress.report()?;
+
+    let mut buffer = Vec::new();
+    let reader = op
+        .reader_options(&path, args)
+        .await
+        .map_err(format_pyerr)?;
+    let Some(err) = read_into(&reader, range, &mut buffer, &mut progress).await? else {
+        return Ok(buffer);
+    };
+    if !ranged {
+        return Err(format_pyerr(err));
+    }
+
+    // The range ran past the end of the object, read the rest of what's there.
+    let meta = op
+        .stat_options(&path, stat)
+        .await
+        .map_err(format_pyerr)?;
+    let clamped = clamp_range(range, meta.content_length());
+    progress.set_total(Some(clamped.and_then(|v| v.size()).unwrap_or_default()));
+    let Some(rest) = clamped.and_then(|v| resume_range(v, buffer.len())) else {
+        progress.report()?;
+        return Ok(buffer);
+    };
+    match read_into(&reader, rest, &mut buffer, &mut progress).await? {
+        Some(err) => Err(format_pyerr(err)),
+        None => Ok(buffer),
+    }
+}
+
+/// Append `range` of `reader` to `buffer` like `blocking_read_into` does.
+async fn read_into(
+    reader: &ocore::Reader,
+    range: BytesRange,
+    buffer: &mut Vec<u8>,
+    progress: &mut Progress,
+) -> PyResult<Option<ocore::Error>> {
+    let mut stream = reader
+        .clone()
+        .into_stream(range.to_range())
+        .await
+        .map_err(format_pyerr)?;
+    while let Some(bs) = stream.next().await {
+        let bs = match bs {
+            Ok(bs) => bs,
+            Err(err) if err.kind() == ocore::ErrorKind::RangeNotSatisfied => return Ok(Some(err)),
+            Err(err) => return Err(format_pyerr(err)),
+        };
+        let n = bs.len();
+        for chunk in bs {
+            buffer.extend_from_slice(&chunk);
+        }
+        progress.advance(n)?;
+    }
+    Ok(None)
+}
+
+/// Write `bs` piece by piece, copying one piece at a time into the writer.
+///
+/// If `callback` is given, it follows the progress. The write is aborted if
+/// it fails or the callback raises.
+async fn write_chunks(
+    op: ocore::Operator,
+    path: String,
+    bs: BytesView,
+    mut opts: WriteOptions,
+    callback: Option<PyObject>,
+) -> PyResult<Metadata> {
+    let bs = bs.as_slice();
+    let step = match opts.plan(&op.info(), Some(bs.len())) {
+        true => bs.len().max(1),
+        false => opts.chunk.unwrap_or(WRITE_STEP),
+    };
+    let mut progress = callback.map(|cb| Progress::new(cb, Some(bs.len() as u64)));
+    if let Some(progress) = progress.as_mut() {
+        progress.report()?;
+    }
+
+    let mut writer = op
+        .writer_options(&path, opts.into())
+        .await
+        .map_err(format_pyerr)?;
+    for chunk in bs.chunks(step) {
+        let res = match writer.write(Bytes::copy_from_slice(chunk)).await {
+            Ok(()) => progress
+                .as_mut()
+                .map_or(Ok(()), |progress| progress.advance(chunk.len())),
+            Err(err) => Err(format_pyerr(err)),
+        };
+        if let Err(err) = res {
+            let _ = writer.abort().await;
+            return Err(err);
+        }
+    }
+    writer
+        .close()
+        .await
+        .map(Metadata::new)
+        .map_err(format_pyerr)
+}
+
+/// Stream the chunks of an iterable, or async iterable, into `path` as
+/// they're produced.
+async fn write_iter(
+    op: ocore::Operator,
+    path: String,
+    chunks: Chunks,
+    mut opts: WriteOptions,
+    callback: Option<PyObject>,
+) -> PyResult<Metadata> {
+    let mut progress = callback.map(|cb| Progress::new(cb, None));
+    if let Some(progress) = progress.as_mut() {
+        progress.report()?;
+    }
+
+    // Content sent in a single request is held until the iterable ends.
+    let one_shot = opts.plan(&op.info(), None);
+    let mut held = Vec::new();
+    let mut writer = op
+        .writer_options(&path, opts.into())
+        .await
+        .map_err(format_pyerr)?;
+    loop {
+        let res = match chunks.next().await {
+            Ok(Some(chunk)) if one_shot => {
+                let len = chunk.len();
+                held.push(chunk);
+                progress
+                    .as_mut()
+                    .map_or(Ok(()), |progress| progress.advance(len))
+            }
+            Ok(Some(chunk)) => {
+                let len = chunk.len();
+                match writer.write(chunk).await {
+                    Ok(()) => progress
+                        .as_mut()
+                        .map_or(Ok(()), |progress| progress.advance(len)),
+                    Err(err) => Err(format_pyerr(err)),
+                }
+            }
+            Ok(None) => break,
+            Err(err) => Err(err),
+        };
+        if let Err(err) = res {
+            let _ = writer.abort().await;
+            return Err(err);
+        }
+    }
+    if !held.is_empty() {
+        if let Err(err) = writer.write(held).await {
+            let _ = writer.abort().await;
+            return Err(format_pyerr(err));
+        }
+    }
+    writer
+        .close()
+        .await
+        .map(Metadata::new)
+        .map_err(format_pyerr)
+}
+
+/// Stream `path` into the local file `local_path`.
+///
+/// The local file is only created once the object can be read, and it's
+/// removed again if the transfer fails midway.
+async fn read_to_file(
+    op: ocore::Operator,
+    path: String,
+    local_path: PathBuf,
+    opts: ReadOptions,
+    mut verifier: Option<ChecksumVerifier>,
+) -> PyResult<()> {
+    let mut stream = op
+        .reader_options(&path, opts.into())
+        .await
+        .map_err(format_pyerr)?
+        .into_stream(..)
+        .await
+        .map_err(format_pyerr)?;
+    let mut file = tokio::fs::File::create(&local_path).await?;
+    let res: PyResult<()> = async {
+        while let Some(bs) = stream.try_next().await.map_err(format_pyerr)? {
+            for chunk in bs {
+                if let Some(verifier) = verifier.as_mut() {
+                    verifier.update(&chunk);
+                }
+                file.write_all(&chunk).await?;
+            }
+        }
+        if let Some(verifier) = verifier {
+            verifier.finish()?;
+        }
+        file.sync_all().await?;
+        Ok(())
+    }
+    .await;
+    if res.is_err() {
+        drop(file);
+        let _ = tokio::fs::remove_file(&local_path).await;
+    }
+    res
+}
+
+/// Stream the local file `local_path` into `path`, aborting the write on failure.
+async fn write_from_file(
+    op: ocore::Operator,
+    path: String,
+    local_path: PathBuf,
+    mut opts: WriteOptions,
+) -> PyResult<Metadata> {
+    let mut file = tokio::fs::File::open(&local_path).await?;
+    let len = file.metadata().await?.len() as usize;
+    let one_shot = opts.plan(&op.info(), Some(len));
+    let step = opts.chunk.unwrap_or(WRITE_STEP);
+    let mut writer = op
+        .writer_options(&path, opts.into())
+        .await
+        .map_err(format_pyerr)?;
+    let mut buf = match one_shot {
+        true => BytesMut::with_capacity(len),
+        false => BytesMut::new(),
+    };
+    loop {
+        buf.reserve(step);
+        let res = match file.read_buf(&mut buf).await {
+            Ok(0) => break,
+            // Keep reading the whole file for a single request.
+            Ok(_) if one_shot => Ok(()),
+            Ok(_) => writer
+                .write(buf.split().freeze())
+                .await
+                .map_err(format_pyerr),
+            Err(err) => Err(err.into()),
+        };
+        if let Err(err) = res {
+            let _ = writer.abort().await;
+            return Err(err);
+        }
+    }
+    if !buf.is_empty() {
+        if let Err(err) = writer.write(buf.freeze()).await {
+            let _ = writer.abort().await;
+            return Err(format_pyerr(err));
+        }
+    }
+    writer
+        .close()
+        .await
+        .map(Metadata::new)
+        .map_err(format_pyerr)
+}
+
+/// Collect the paths of an iterable passed to `delete_iter`.
+fn extract_paths(paths: &Bound<PyAny>) -> PyResult<Vec<String>> {
+    if paths.is_instance_of::<PyString>() {
+        return Err(PyTypeError::new_err(
+            "paths must be an iterable of paths, not a single str",
+        ));
+    }
+    paths
+        .try_iter()?
+        .map(|p| Ok(p?.extract::<PyPath>()?.0))
+        .collect()
+}
+
+/// The paths `remove_all` goes through, gathered into batches of the
+/// service's `delete_max_size`.
+///
+/// With `dry_run` nothing is batched, the paths are kept to be returned.
+struct RemoveAll {
+    dry_run: bool,
+    on_delete: Option<PyObject>,
+    batch_size: usize,
+    batch: Vec<String>,
+    planned: Vec<String>,
+    removed: usize,
+}
+
+impl RemoveAll {
+    fn new(
+        info: &ocore::OperatorInfo,
+        dry_run: bool,
+        on_delete: Option<PyObject>,
+    ) -> PyResult<Self> {
+        if let Some(on_delete) = &on_delete {
+            if !Python::with_gil(|py| on_delete.bind(py).is_callable()) {
+                return Err(PyTypeError::new_err("on_delete must be callable"));
+            }
+        }
+        Ok(Self {
+            dry_run,
+            on_delete,
+            batch_size: info.full_capability().delete_max_size.unwrap_or(1).max(1),
+            batch: Vec::new(),
+            planned: Vec::new(),
+            removed: 0,
+        })
+    }
+
+    /// Whether the path passed to `remove_all` must be deleted on its own,
+    /// which is the case for a file. A missing path may still be a prefix
+    /// with objects under it.
+    fn is_file(stat: ocore::Result<ocore::Metadata>) -> PyResult<bool> {
+        match stat {
+            Ok(meta) => Ok(!meta.is_dir()),
+            Err(err) if err.kind() == ocore::ErrorKind::NotFound => Ok(false),
+            Err(err) => Err(format_pyerr(err)),
+        }
+    }
+
+    /// Queue `path`, returning the batch to delete once it's full.
+    fn push(&mut self, path: &str) -> Option<Vec<String>> {
+        if self.dry_run {
+            self.planned.push(path.to_string());
+            return None;
+        }
+        self.batch.push(path.to_string());
+        (self.batch.len() >= self.batch_size).then(|| std::mem::take(&mut self.batch))
+    }
+
+    /// Return the last batch to delete, if any.
+    fn rest(&mut self) -> Option<Vec<String>> {
+        (!self.batch.is_empty()).then(|| std::mem::take(&mut self.batch))
+    }
+
+    /// Delete `batch`, then count it and pass it to `on_delete`.
+    fn blocking_delete(
+        &mut self,
+        op: &ocore::blocking::Operator,
+        batch: Vec<String>,
+    ) -> PyResult<()> {
+        op.delete_iter(batch.iter().map(String::as_str))
+            .map_err(format_pyerr)?;
+        self.deleted(batch)
+    }
+
+    /// Delete `batch`, then count it and pass it to `on_delete`.
+    async fn delete(&mut self, op: &ocore::Operator, batch: Vec<String>) -> PyResult<()> {
+        op.delete_iter(batch.iter().map(String::as_str))
+            .await
+            .map_err(format_pyerr)?;
+        self.deleted(batch)
+    }
+
+    fn deleted(&mut self, batch: Vec<String>) -> PyResult<()> {
+        self.removed += batch.len();
+        match &self.on_delete {
+            Some(on_delete) => Python::with_gil(|py| on_delete.call1(py, (batch,)).map(|_| ())),
+            None => Ok(()),
+        }
+    }
+
+    /// Return the paths of a dry run, or how many were deleted.
+    fn finish(self) -> PyResult<PyObject> {
+        Python::with_gil(|py| {
+            if self.dry_run {
+                self.planned.into_py_any(py)
+            } else {
+                self.removed.into_py_any(py)
+            }
+        })
+    }
+}
+
+/// `remove_all` for the blocking operator, deleting each batch as soon as
+/// it's full so `on_delete` follows along.
+///
+/// Batches are deleted one `block_on` at a time, so `on_delete` never runs
+/// inside the runtime and may use the blocking operator itself.
+fn blocking_remove_all(
+    op: &ocore::blocking::Operator,
+    path: &str,
+    mut state: RemoveAll,
+) -> PyResult<PyObject> {
+    let is_file = RemoveAll::is_file(op.stat(path))?;
+    if is_file {
+        if let Some(batch) = state.push(path) {
+            state.blocking_delete(op, batch)?;
+        }
+    }
+    let opts = ocore::options::ListOptions {
+        recursive: true,
+        ..Default::default()
+    };
+    for entry in op.lister_options(path, opts).map_err(format_pyerr)? {
+        let entry = entry.map_err(format_pyerr)?;
+        // Object stores list a file under its own path as well.
+        if is_file && entry.path() == path {
+            continue;
+        }
+        if let Some(batch) = state.push(entry.path()) {
+            state.blocking_delete(op, batch)?;
+        }
+    }
+    if let Some(batch) = state.rest() {
+        state.blocking_delete(op, batch)?;
+    }
+    state.finish()
+}
+
+/// `remove_all` for the async operator, like `blocking_remove_all`.
+async fn remove_all(op: ocore::Operator, path: String, mut state: RemoveAll) -> PyResult<PyObject> {
+    let is_file = RemoveAll::is_file(op.stat(&path).await)?;
+    if is_file {
+        if let Some(batch) = state.push(&path) {
+            state.delete(&op, batch).await?;
+        }
+    }
+    let mut lister = op
+        .lister_with(&path)
+        .recursive(true)
+        .await
+        .map_err(format_pyerr)?;
+    while let Some(entry) = lister.try_next().await.map_err(format_pyerr)? {
+        // Object stores list a file under its own path as well.
+        if is_file && entry.path() == path {
+            continue;
+        }
+        if let Some(batch) = state.push(entry.path()) {
+            state.delete(&op, batch).await?;
+        }
+    }
+    if let Some(batch) = state.rest() {
+        state.delete(&op, batch).await?;
+    }
+    state.finish()
+}
+
+/// Delete `paths` in batches of the service's `delete_max_size`.
+///
+/// If a batch fails, its paths are retried one by one so that every failure
+/// is reported against the path that caused it.
+async fn delete_paths(op: ocore::Operator, paths: Vec<String>) -> PyResult<DeleteResult> {
+    let batch_size = op
+        .info()
+        .full_capability()
+        .delete_max_size
+        .unwrap_or(1)
+        .max(1);
+
+    let mut deleted = Vec::with_capacity(paths.len());
+    let mut failed = Vec::new();
+    for batch in paths.chunks(batch_size) {
+        if batch.len() > 1 {
+            let mut deleter = op.deleter().await.map_err(format_pyerr)?;
+            let res = match deleter.delete_iter(batch.iter().map(String::as_str)).await {
+                Ok(()) => deleter.close().await,
+                Err(err) => Err(err),
+            };
+            if res.is_ok() {
+                deleted.extend_from_slice(batch);
+                continue;
+            }
+        }
+        for path in batch {
+            match op.delete(path).await {
+                Ok(()) => deleted.push(path.clone()),
+                Err(err) => failed.push((path.clone(), format_pyerr(err))),
+            }
+        }
+    }
+
+    Python::with_gil(|py| {
+        let failed = failed
+            .into_iter()
+            .map(|(path, err)| (path, err.into_value(py)))
+            .collect();
+        Ok(DeleteResult { deleted, failed })
+    })
+}
+
+/// Pickle an operator as a call to the router class `opendal.<class>` with its
+/// scheme and options.
+fn reduce_operator<'py>(
+    py: Python<'py>,
+    class: &str,
+    scheme: ocore::Scheme,
+    map: &HashMap<String, String>,
+    http: Option<&HttpOptions>,
+    credential: Option<&Arc<CredentialProvider>>,
+) -> PyResult<Bound<'py, PyTuple>> {
+    let class = py.import("opendal")?.getattr(class)?;
+    let kwargs = map.into_py_dict(py)?;
+    if let Some(http) = http {
+        kwargs.set_item("http_options", http.to_dict(py)?)?;
+    }
+    if let Some(provider) = credential {
+        kwargs.set_item("credential_provider", provider.callback(py))?;
+    }
+    let factory = py
+        .import("functools")?
+        .getattr("partial")?
+        .call((class, scheme.to_string()), Some(&kwargs))?;
+    PyTuple::new(py, [factory, PyTuple::empty(py).into_any()])
+}
+
 /// `Operator` is the entry for all public blocking APIs
 ///
 /// Create a new blocking `Operator` with the given `scheme` and options(`**kwargs`).
+///
+/// Pickling rebuilds the operator from its `scheme` and options, so it can be
+/// sent to other processes. The options, including any credentials, are
+/// stored in the pickle in plain text. Layers are not carried over. Forked
+/// children can't reuse the parent's runtime, so use the `spawn` start method.
+///
+/// Calls are bridged to the async core through the shared runtime, so every
+/// service works, but they can't be made from inside that runtime.
 #[pyclass(module = "opendal")]
 pub struct Operator {
     core: ocore::blocking::Operator,
     __scheme: ocore::Scheme,
     __map: HashMap<String, String>,
+    __http: Option<HttpOptions>,
+    __credential: Option<Arc<CredentialProvider>>,
+    __write_defaults: Arc<WriteOptions>,
+    /// The class names of the layers applied, innermost first, for `__repr__`.
+    __layers: Arc<Vec<String>>,
+    __read_chunk: Option<usize>,
 }
 
 #[pymethods]
 impl Operator {
     #[new]
-    #[pyo3(signature = (scheme, *, **map))]
-    pub fn new(scheme: &str, map: Option<&Bound<PyDict>>) -> PyResult<Self> {
+    #[pyo3(signature = (scheme, *, http_options = None, credential_provider = None, **map))]
+    pub fn new(
+        py: Python,
+        scheme: &str,
+        http_options: Option<HttpOptions>,
+        credential_provider: Option<PyObject>,
+        map: Option<&Bound<PyDict>>,
+    ) -> PyResult<Self> {
         let scheme = ocore::Scheme::from_str(scheme)
             .map_err(|err| {
                 ocore::Error::new(ocore::ErrorKind::Unexpected, "unsupported scheme")
@@ -76,17 +1614,89 @@ impl Operator {
                     .expect("must be valid hashmap")
             })
             .unwrap_or_default();
+        let credential = credential_provider
+            .map(|v| CredentialProvider::new(py, v))
+            .transpose()?;
 
         Ok(Operator {
-            core: build_blocking_operator(scheme, map.clone())?,
+            core: build_blocking_operator(
+                scheme,
+                map.clone(),
+                http_options.as_ref(),
+                credential.as_ref(),
+            )?,
             __scheme: scheme,
             __map: map,
+            __http: http_options,
+            __credential: credential,
+            __write_defaults: Arc::default(),
+            __layers: Arc::default(),
+            __read_chunk: None,
         })
     }
 
-    /// Add new layers upon the existing operator
-    pub fn layer(&self, layer: &layers::Layer) -> PyResult<Self> {
-        let op = layer.0.layer(self.core.clone().into());
+    /// Create a new `Operator` from a URI like
+    /// `s3://bucket/prefix?region=us-east-1`.
+    ///
+    /// The scheme picks the service. The host is the bucket or container of
+    /// services that have one, the path becomes `root`, and the query
+    /// parameters map to config keys. Keyword arguments, like credentials,
+    /// override the values parsed from the URI.
+    #[staticmethod]
+    #[pyo3(signature = (uri, *, http_options = None, credential_provider = None, **map))]
+    pub fn from_uri(
+        py: Python,
+        uri: &str,
+        http_options: Option<HttpOptions>,
+        credential_provider: Option<PyObject>,
+        map: Option<&Bound<PyDict>>,
+    ) -> PyResult<Self> {
+        let (scheme, config) = parse_uri(uri)?;
+        let config = config.into_py_dict(py)?;
+        if let Some(map) = map {
+            config.update(map.as_mapping())?;
+        }
+        Self::new(
+            py,
+            &scheme,
+            http_options,
+            credential_provider,
+            Some(&config),
+        )
+    }
+
+    /// Create a new `Operator` for `scheme` from the environment variables
+    /// named like `OPENDAL_S3_BUCKET`.
+    ///
+    /// The prefix and scheme are stripped and the rest is lowercased into
+    /// the config key, so `OPENDAL_S3_BUCKET` sets `bucket`. Keyword
+    /// arguments override the values read from the environment.
+    #[staticmethod]
+    #[pyo3(signature = (scheme, prefix = "OPENDAL", *, http_options = None, credential_provider = None, **map))]
+    pub fn from_env(
+        py: Python,
+        scheme: &str,
+        prefix: &str,
+        http_options: Option<HttpOptions>,
+        credential_provider: Option<PyObject>,
+        map: Option<&Bound<PyDict>>,
+    ) -> PyResult<Self> {
+        let config = parse_env(scheme, prefix).into_py_dict(py)?;
+        if let Some(map) = map {
+            config.update(map.as_mapping())?;
+        }
+        check_env_config(scheme, prefix, &config)?;
+        Self::new(py, scheme, http_options, credential_provider, Some(&config))
+            .map_err(|err| env_config_error(py, err, scheme, prefix))
+    }
+
+    /// Add new layers upon the existing operator.
+    ///
+    /// This returns a new operator and leaves the current one unchanged, so
+    /// several variants can be derived from the same base.
+    pub fn layer(&self, layer: &Bound<layers::Layer>) -> PyResult<Self> {
+        let layers = with_layer(&self.__layers, layer)?;
+        let op = layer.borrow().0.layer(self.core.clone().into());
 
         let runtime = pyo3_async_runtimes::tokio::get_runtime();
         let _guard = runtime.enter();
@@ -95,6 +1705,71 @@ impl Operator {
             core: op,
             __scheme: self.__scheme,
             __map: self.__map.clone(),
+            __http: self.__http.clone(),
+            __credential: self.__credential.clone(),
+            __write_defaults: self.__write_defaults.clone(),
+            __layers: Arc::new(layers),
+            __read_chunk: self.__read_chunk,
+        })
+    }
+
+    /// Return a new operator that applies the given write options, like
+    /// `content_type` or `cache_control`, to every write.
+    ///
+    /// Options passed to a single call take precedence over these defaults,
+    /// and `user_metadata` is merged key by key. Options the service can't
+    /// honor still raise when writing. Like layers, the defaults are not
+    /// carried over by pickling.
+    #[pyo3(signature = (**kwargs))]
+    pub fn with_default_write_options(&self, kwargs: Option<WriteOptions>) -> Self {
+        let defaults = kwargs.unwrap_or_default();
+        Self {
+            core: self.core.clone(),
+            __scheme: self.__scheme,
+            __map: self.__map.clone(),
+            __http: self.__http.clone(),
+            __credential: self.__credential.clone(),
+            __write_defaults: Arc::new(defaults.with_defaults(&self.__write_defaults)),
+            __layers: self.__layers.clone(),
+            __read_chunk: self.__read_chunk,
+        }
+    }
+
+    /// Return a new operator that reads and writes in chunks of the given
+    /// sizes, in bytes, unless a call passes its own `chunk`.
+    ///
+    /// By default reads fetch the whole range in one request, or in 8 MiB
+    /// chunks when `concurrent` is given, and writes are buffered up to the
+    /// service's minimum part size, if it has one. Larger chunks mean fewer
+    /// requests, which pays off on high-latency services.
+    ///
+    /// A `write_chunk` outside the part sizes the service accepts raises
+    /// `ValueError`.
+    #[pyo3(signature = (*, read_chunk = None, write_chunk = None))]
+    pub fn with_chunk_sizes(
+        &self,
+        read_chunk: Option<usize>,
+        write_chunk: Option<usize>,
+    ) -> PyResult<Self> {
+        if read_chunk == Some(0) {
+            return Err(PyValueError::new_err(
+                "read_chunk must be a positive integer",
+            ));
+        }
+        let defaults = WriteOptions {
+            chunk: write_chunk,
+            ..Default::default()
+        };
+        defaults.check(&self.core.info())?;
+        Ok(Self {
+            core: self.core.clone(),
+            __scheme: self.__scheme,
+            __map: self.__map.clone(),
+            __http: self.__http.clone(),
+            __credential: self.__credential.clone(),
+            __write_defaults: Arc::new(defaults.with_defaults(&self.__write_defaults)),
+            __layers: self.__layers.clone(),
+            __read_chunk: read_chunk.or(self.__read_chunk),
         })
     }
 
@@ -102,22 +1777,37 @@ impl Operator {
     #[pyo3(signature = (path, mode, *, **kwargs))]
     pub fn open(
         &self,
-        path: PathBuf,
+        path: PyPath,
         mode: String,
         kwargs: Option<&Bound<PyDict>>,
     ) -> PyResult<File> {
+        check_blocking()?;
         let this = self.core.clone();
-        let path = path.to_string_lossy().to_string();
+        let path = path.0;
 
-        let reader_opts = kwargs
+        let mut reader_opts = kwargs
             .map(|v| v.extract::<ReadOptions>())
             .transpose()?
-            .unwrap_or_default();
+            .unwrap_or_default()
+            .with_default_chunk(self.__read_chunk);
+        if mode == "rb" {
+            reader_opts.check()?;
+        }
 
-        let writer_opts = kwargs
+        let mut writer_opts = kwargs
             .map(|v| v.extract::<WriteOptions>())
             .transpose()?
-            .unwrap_or_default();
+            .unwrap_or_default()
+            .with_defaults(&self.__write_defaults);
+        // The file sends its requests after `open` returns.
+        let headers = match mode.as_str() {
+            "rb" => &reader_opts.extra_headers,
+            _ => &writer_opts.extra_headers,
+        };
+        reject_extra_headers(headers, "open")?;
+        if mode == "ab" {
+            writer_opts.append = Some(true);
+        }
 
         if mode == "rb" {
             let range = reader_opts.make_range();
@@ -129,7 +1819,9 @@ impl Operator {
                 .into_std_read(range.to_range())
                 .map_err(format_pyerr)?;
             Ok(File::new_reader(r))
-        } else if mode == "wb" {
+        } else if mode == "wb" || mode == "ab" {
+            writer_opts.check(&this.info())?;
+            plan_file_write(&mut writer_opts, &this.info())?;
             let writer = this
                 .writer_options(&path, writer_opts.into())
                 .map_err(format_pyerr)?;
@@ -142,67 +1834,451 @@ impl Operator {
     }
 
     /// Read the whole path into bytes.
+    ///
+    /// If `progress` is given, it's called with `(bytes_so_far, total)` as
+    /// the content arrives.
+    ///
+    /// If `buffer_protocol` is true, return a `Buffer` sharing the memory
+    /// that was read instead of copying it into `bytes`.
+    ///
+    /// If `verify` is true, check the content against the MD5 the service
+    /// reports and raise `ChecksumMismatch` if it differs. Objects without a
+    /// checksum are returned unchecked unless `require_checksum` is true.
+    ///
+    /// If `timeout` is given, as seconds or a `datetime.timedelta`, the call
+    /// raises `Unexpected` once it's exceeded, cancelling the request.
+    #[pyo3(signature = (
+        path,
+        *,
+        progress = None,
+        buffer_protocol = false,
+        verify = false,
+        require_checksum = false,
+        timeout = None,
+        **kwargs
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn read(
+        &self,
+        py: Python,
+        path: PyPath,
+        progress: Option<PyObject>,
+        buffer_protocol: bool,
+        verify: bool,
+        require_checksum: bool,
+        timeout: Option<PyDuration>,
+        kwargs: Option<ReadOptions>,
+    ) -> PyResult<Py<PyAny>> {
+        check_blocking()?;
+        let core = blocking_with_deadline(&self.core, timeout)?;
+        let path = path.0;
+        let mut kwargs = kwargs
+            .unwrap_or_default()
+            .with_default_chunk(self.__read_chunk);
+        kwargs.check()?;
+        let headers = parse_extra_headers(kwargs.extra_headers.take())?;
+        blocking_with_extra_headers(headers, || {
+            let mut verifier = None;
+            if verify || require_checksum {
+                check_verify_range(&kwargs)?;
+                let meta = core
+                    .stat_options(&path, kwargs.stat_options())
+                    .map_err(format_pyerr)?;
+                verifier =
+                    checksum_verifier(&core.info(), &path, &meta, &mut kwargs, require_checksum)?;
+            }
+            if let Some(callback) = progress {
+                let buffer = blocking_read_with_progress(&core, &path, kwargs, callback)?;
+                if let Some(verifier) = verifier {
+                    verifier.verify([&buffer])?;
+                }
+                return Buffer::new(buffer).into_bytes_or_buffer(py, buffer_protocol);
+            }
+            let buffer = blocking_read_clamped(&core, &path, kwargs).map_err(format_pyerr)?;
+            if let Some(verifier) = verifier {
+                verifier.verify(buffer.clone())?;
+            }
+
+            Buffer::from(buffer).into_bytes_or_buffer(py, buffer_protocol)
+        })
+    }
+
+    /// Read the whole path into memory and return it as a `BufferReader`,
+    /// a file-like object that can be passed to parsers without copying the
+    /// content into `io.BytesIO` first.
+    ///
+    /// Takes the same options as `read`.
     #[pyo3(signature = (path, **kwargs))]
-    pub fn read<'p>(
-        &'p self,
-        py: Python<'p>,
-        path: PathBuf,
+    pub fn read_stream(&self, path: PyPath, kwargs: Option<ReadOptions>) -> PyResult<BufferReader> {
+        check_blocking()?;
+        let path = path.0;
+        let mut kwargs = kwargs
+            .unwrap_or_default()
+            .with_default_chunk(self.__read_chunk);
+        kwargs.check()?;
+        let headers = parse_extra_headers(kwargs.extra_headers.take())?;
+        blocking_with_extra_headers(headers, || {
+            blocking_read_clamped(&self.core, &path, kwargs)
+                .map(BufferReader::new)
+                .map_err(format_pyerr)
+        })
+    }
+
+    /// Open a reader for the given path along with the number of bytes it
+    /// yields, for responses that need a `Content-Length` before streaming
+    /// the content.
+    ///
+    /// Return `(size, file)`, where `file` is the reader `open(path, "rb")`
+    /// returns and iterates in chunks. `size` is looked up with a stat and
+    /// accounts for `range`, `offset` and `size`, clamped like `read`, or is
+    /// `None` if the service can't stat.
+    ///
+    /// Takes the same options as `open`.
+    #[pyo3(signature = (path, **kwargs))]
+    pub fn reader_with_size(
+        &self,
+        path: PyPath,
         kwargs: Option<ReadOptions>,
-    ) -> PyResult<Bound<'p, PyAny>> {
-        let path = path.to_string_lossy().to_string();
-        let kwargs = kwargs.unwrap_or_default();
-        let buffer = self
+    ) -> PyResult<(Option<u64>, File)> {
+        check_blocking()?;
+        let path = path.0;
+        let mut kwargs = kwargs
+            .unwrap_or_default()
+            .with_default_chunk(self.__read_chunk);
+        kwargs.check()?;
+        reject_extra_headers(&kwargs.extra_headers, "reader_with_size")?;
+
+        let mut size = None;
+        if self.core.info().full_capability().stat {
+            let meta = self
+                .core
+                .stat_options(&path, kwargs.stat_options())
+                .map_err(format_pyerr)?;
+            kwargs.clamp_range(meta.content_length() as usize);
+            size = kwargs.size.map(|v| v as u64);
+        }
+        let range = kwargs.make_range();
+        let reader = self
             .core
-            .read_options(&path, kwargs.into())
-            .map_err(format_pyerr)?
-            .to_vec();
+            .reader_options(&path, kwargs.into())
+            .map_err(format_pyerr)?;
+        let r = reader
+            .into_std_read(range.to_range())
+            .map_err(format_pyerr)?;
+        Ok((size, File::new_reader(r)))
+    }
+
+    /// Read the path unless it hasn't changed since `since`, the way an HTTP
+    /// cache revalidates an entry.
+    ///
+    /// Return `(metadata, data)`, where `data` is `None` if the object wasn't
+    /// modified after `since`. Services supporting it are sent a conditional
+    /// read with `if_modified_since`, the rest compare the last modified time
+    /// of the object.
+    #[pyo3(signature = (path, since))]
+    pub fn read_if_modified(
+        &self,
+        py: Python,
+        path: PyPath,
+        since: DateTime<FixedOffset>,
+    ) -> PyResult<(Metadata, Option<Py<PyAny>>)> {
+        check_blocking()?;
+        let path = path.0;
+        let meta = self.core.stat(&path).map_err(format_pyerr)?;
+        let content = match if_modified_read(&self.core.info(), &meta, since.with_timezone(&Utc)) {
+            Some(opts) => modified_content(self.core.read_options(&path, opts))?,
+            None => None,
+        };
+        let content = content
+            .map(|buffer| Buffer::from(buffer).into_bytes_or_buffer(py, false))
+            .transpose()?;
+        Ok((Metadata::new(meta), content))
+    }
+
+    /// Read the whole path along with the metadata of the content read.
+    ///
+    /// Return `(metadata, data)`. For services speaking HTTP, the metadata,
+    /// like `etag`, `content_length`, `content_type` and `last_modified`,
+    /// comes from the headers of the same `GET` that returned `data`, so it
+    /// can't describe another version of the object. Other services, like
+    /// `fs`, stat the object after reading it.
+    #[pyo3(signature = (path, **kwargs))]
+    pub fn read_with_metadata(
+        &self,
+        py: Python,
+        path: PyPath,
+        kwargs: Option<ReadOptions>,
+    ) -> PyResult<(Metadata, Py<PyAny>)> {
+        check_blocking()?;
+        let path = path.0;
+        let mut kwargs = kwargs
+            .unwrap_or_default()
+            .with_default_chunk(self.__read_chunk);
+        check_whole_read(&mut kwargs)?;
+        let headers = parse_extra_headers(kwargs.extra_headers.take())?;
+        let (buffer, response) = blocking_with_extra_headers(headers, || {
+            blocking_with_response_headers(|| self.core.read_options(&path, kwargs.into()))
+        });
+        let buffer = buffer.map_err(format_pyerr)?;
+        let meta = match response {
+            Some(headers) => response_metadata(&path, &headers),
+            None => self.core.stat(&path),
+        }
+        .map_err(format_pyerr)?;
+        let content = Buffer::from(buffer).into_bytes_or_buffer(py, false)?;
+        Ok((Metadata::new(meta), content))
+    }
 
-        Buffer::new(buffer).into_bytes_ref(py)
+    /// Read several byte ranges of the path at once, like the footer and a
+    /// few column chunks of a Parquet file.
+    ///
+    /// `ranges` are `(offset, length)` pairs, and a list of `bytes` in the
+    /// same order is returned. Ranges that overlap or are less than `gap`
+    /// bytes apart, 1 MiB by default, are merged into one ranged read, and up
+    /// to `concurrent` reads, 16 by default, are sent at once. Like `read`,
+    /// the ranges are clamped to the size of the object.
+    #[pyo3(signature = (path, ranges, **kwargs))]
+    pub fn read_ranges(
+        &self,
+        py: Python,
+        path: PyPath,
+        ranges: Vec<(u64, u64)>,
+        kwargs: Option<ReadOptions>,
+    ) -> PyResult<Vec<Py<PyAny>>> {
+        check_blocking()?;
+        let this: ocore::Operator = self.core.clone().into();
+        let path = path.0;
+        let mut kwargs = kwargs
+            .unwrap_or_default()
+            .with_default_chunk(self.__read_chunk);
+        check_read_ranges(&mut kwargs)?;
+        if ranges.is_empty() {
+            return Ok(Vec::new());
+        }
+        let headers = parse_extra_headers(kwargs.extra_headers.take())?;
+        let buffers = blocking_with_extra_headers(headers, || {
+            pyo3_async_runtimes::tokio::get_runtime().block_on(async {
+                fetch_clamped(&this, &path, kwargs, &ranges).await
+            })
+        })
+        .map_err(format_pyerr)?;
+        buffers
+            .into_iter()
+            .map(|buffer| Buffer::from(buffer).into_bytes_or_buffer(py, false))
+            .collect()
     }
 
-    /// Write bytes into a given path.
-    #[pyo3(signature = (path, bs, **kwargs))]
-    pub fn write(&self, path: PathBuf, bs: Vec<u8>, kwargs: Option<WriteOptions>) -> PyResult<()> {
-        let path = path.to_string_lossy().to_string();
-        let kwargs = kwargs.unwrap_or_default();
+    /// Write a bytes-like object into the given path.
+    ///
+    /// `bs` may be anything implementing the buffer protocol, like `bytes`,
+    /// `bytearray`, `memoryview` or a contiguous one-dimensional numpy array.
+    /// It's streamed into the writer without copying it as a whole.
+    ///
+    /// `bs` may also be an iterable, like a generator, of bytes-like chunks.
+    /// Each chunk is written as it's produced, so the content is never held
+    /// in memory as a whole. If the iterable raises, the upload is aborted.
+    ///
+    /// `strategy` picks between a single request and a multipart upload.
+    /// The default, `auto`, sends content fitting in one part in a single
+    /// request and uploads the rest in parts where the service can. Content
+    /// sent in a single request is copied, or held for an iterable, first.
+    ///
+    /// If `progress` is given, it's called with `(bytes_so_far, total)` as
+    /// the content is written. `total` is `None` for an iterable.
+    ///
+    /// Return the `Metadata` of the written object as reported by the
+    /// service, like its etag or version.
+    ///
+    /// If `timeout` is given, as seconds or a `datetime.timedelta`, the call
+    /// raises `Unexpected` once it's exceeded, cancelling the request.
+    #[pyo3(signature = (path, bs, *, progress = None, timeout = None, **kwargs))]
+    pub fn write(
+        &self,
+        path: PyPath,
+        bs: &Bound<PyAny>,
+        progress: Option<PyObject>,
+        timeout: Option<PyDuration>,
+        kwargs: Option<WriteOptions>,
+    ) -> PyResult<Metadata> {
+        check_blocking()?;
+        let path = path.0;
+        let mut kwargs = kwargs
+            .unwrap_or_default()
+            .with_defaults(&self.__write_defaults);
+        kwargs.check(&self.core.info())?;
+        let headers = parse_extra_headers(kwargs.extra_headers.take())?;
+        let content = WriteContent::get(bs, false)?;
+        let core = blocking_with_deadline(&self.core, timeout)?;
+        blocking_with_extra_headers(headers, || match content {
+            WriteContent::Bytes(bs) => {
+                blocking_write_chunks(&core, &path, bs.as_slice(), kwargs, progress)
+            }
+            WriteContent::Chunks(chunks) => {
+                blocking_write_iter(bs.py(), &core, &path, chunks, kwargs, progress)
+            }
+        })
+    }
+
+    /// Write a bytes-like object into the given path starting at `offset`,
+    /// patching the existing file in place.
+    ///
+    /// The file must exist and `offset` must not be past its end. Content
+    /// outside the written range is kept. Only services with the
+    /// `write_with_offset` capability like `fs` support this.
+    pub fn write_at(&self, path: PyPath, offset: u64, bs: &Bound<PyAny>) -> PyResult<Metadata> {
+        check_blocking()?;
+        let bs = BytesView::get(bs)?;
+        let opts = write_at_options(&self.core.info(), offset, bs.as_slice().len())?;
         self.core
-            .write_options(&path, bs, kwargs.into())
-            .map(|_| ())
+            .write_options(&path.0, Bytes::copy_from_slice(bs.as_slice()), opts)
+            .map(Metadata::new)
             .map_err(format_pyerr)
     }
 
     /// Get metadata for the current path **without cache** directly.
-    #[pyo3(signature = (path, **kwargs))]
-    pub fn stat(&self, path: PathBuf, kwargs: Option<&Bound<PyDict>>) -> PyResult<Metadata> {
-        let path = path.to_string_lossy().to_string();
-        let kwargs = kwargs
+    ///
+    /// If `timeout` is given, as seconds or a `datetime.timedelta`, the call
+    /// raises `Unexpected` once it's exceeded, cancelling the request.
+    #[pyo3(signature = (path, *, timeout = None, **kwargs))]
+    pub fn stat(
+        &self,
+        path: PyPath,
+        timeout: Option<PyDuration>,
+        kwargs: Option<&Bound<PyDict>>,
+    ) -> PyResult<Metadata> {
+        check_blocking()?;
+        let path = path.0;
+        let mut kwargs = kwargs
             .map(|v| v.extract::<StatOptions>())
             .transpose()?
             .unwrap_or_default();
-        self.core
-            .stat_options(&path, kwargs.into())
-            .map_err(format_pyerr)
-            .map(Metadata::new)
+        let headers = parse_extra_headers(kwargs.extra_headers.take())?;
+        let core = blocking_with_deadline(&self.core, timeout)?;
+        blocking_with_extra_headers(headers, || {
+            core.stat_options(&path, kwargs.into())
+                .map_err(format_pyerr)
+                .map(Metadata::new)
+        })
+    }
+
+    /// Copy the source to the target.
+    ///
+    /// # Notes
+    ///
+    /// - `source` and `target` must be files.
+    /// - `target` will be overwritten if it exists.
+    pub fn copy(&self, source: PyPath, target: PyPath) -> PyResult<()> {
+        check_blocking()?;
+        let source = source.0;
+        let target = target.0;
+        self.core.copy(&source, &target).map_err(format_pyerr)
+    }
+
+    /// Copy `source` of `source_op` to `target` of this operator.
+    ///
+    /// # Notes
+    ///
+    /// - The service copies it itself when both operators are built with
+    ///   the same scheme and options apart from `root`, the service
+    ///   supports copy and one root contains both paths.
+    /// - Otherwise the content is streamed from `source_op` into the
+    ///   target, without being collected in memory.
+    /// - `source_op` may be the `Operator` of another OpenDAL package,
+    ///   which is read through its `open`.
+    pub fn copy_from(
+        &self,
+        source_op: &Bound<PyAny>,
+        source: PyPath,
+        target: PyPath,
+    ) -> PyResult<()> {
+        check_blocking()?;
+        let source = source.0;
+        let target = target.0;
+        let Ok(source_op) = source_op.downcast::<Operator>() else {
+            check_foreign_operator(source_op, "Operator")?;
+            return blocking_copy_from_foreign(source_op, &source, &self.core, &target);
+        };
+        let source_op = source_op.borrow();
+        match server_copy(
+            (source_op.__scheme, &source_op.__map, source_op.core.info()),
+            &source,
+            (self.__scheme, &self.__map, self.core.info()),
+            &target,
+        ) {
+            Some(ServerCopy::OnTarget(source)) => {
+                self.core.copy(&source, &target).map_err(format_pyerr)
+            }
+            Some(ServerCopy::OnSource(target)) => {
+                source_op.core.copy(&source, &target).map_err(format_pyerr)
+            }
+            None => blocking_copy_between(&source_op.core, &source, &self.core, &target),
+        }
+    }
+
+    /// Rename the source to the target.
+    ///
+    /// # Notes
+    ///
+    /// - `source` and `target` must be files.
+    /// - `target` will be overwritten if it exists.
+    /// - If the service can't rename, `emulate=True` copies to the target,
+    ///   checks the copy against the source and only then deletes the
+    ///   source. This is not atomic, and the source is kept if any step
+    ///   fails.
+    #[pyo3(signature = (source, target, *, emulate = false))]
+    pub fn rename(&self, source: PyPath, target: PyPath, emulate: bool) -> PyResult<()> {
+        check_blocking()?;
+        let source = source.0;
+        let target = target.0;
+        if rename_emulated(&self.core.info(), emulate)? {
+            let src = self.core.stat(&source).map_err(format_pyerr)?;
+            self.core.copy(&source, &target).map_err(format_pyerr)?;
+            let dst = self.core.stat(&target).map_err(format_pyerr)?;
+            verify_copy(&source, &target, &src, &dst)?;
+            return self.core.delete(&source).map_err(format_pyerr);
+        }
+        self.core.rename(&source, &target).map_err(format_pyerr)
     }
 
-    /// Copy the source to the target.
-    pub fn copy(&self, source: PathBuf, target: PathBuf) -> PyResult<()> {
-        let source = source.to_string_lossy().to_string();
-        let target = target.to_string_lossy().to_string();
-        self.core.copy(&source, &target).map_err(format_pyerr)
+    /// Replace the tags of the object at `path` with `tags`.
+    ///
+    /// Tags missing from `tags` are removed, so an empty dict clears them.
+    pub fn put_tags(&self, path: PyPath, tags: HashMap<String, String>) -> PyResult<()> {
+        check_blocking()?;
+        check_tags(&self.core.info(), &tags)?;
+        self.core.put_tags(&path.0, tags).map_err(format_pyerr)
     }
 
-    /// Rename filename.
-    pub fn rename(&self, source: PathBuf, target: PathBuf) -> PyResult<()> {
-        let source = source.to_string_lossy().to_string();
-        let target = target.to_string_lossy().to_string();
-        self.core.rename(&source, &target).map_err(format_pyerr)
+    /// Get the tags of the object at `path`, empty if it has none.
+    pub fn get_tags(&self, path: PyPath) -> PyResult<HashMap<String, String>> {
+        check_blocking()?;
+        check_tagging(&self.core.info())?;
+        self.core.get_tags(&path.0).map_err(format_pyerr)
     }
 
-    /// Remove all files
-    pub fn remove_all(&self, path: PathBuf) -> PyResult<()> {
-        let path = path.to_string_lossy().to_string();
-        self.core.remove_all(&path).map_err(format_pyerr)
+    /// Remove all files under the given path recursively.
+    ///
+    /// Return how many paths were deleted. With `dry_run`, nothing is
+    /// deleted and the paths that would be are returned instead.
+    ///
+    /// If `on_delete` is given, it's called with the list of paths of each
+    /// batch once it's deleted. An exception raised by it stops the removal
+    /// and propagates, leaving the remaining paths in place.
+    ///
+    /// # Notes
+    ///
+    /// - Batch delete is used if the service supports it.
+    /// - Removing a path that doesn't exist won't return errors.
+    #[pyo3(signature = (path, *, dry_run = false, on_delete = None))]
+    pub fn remove_all(
+        &self,
+        path: PyPath,
+        dry_run: bool,
+        on_delete: Option<PyObject>,
+    ) -> PyResult<PyObject> {
+        check_blocking()?;
+        let state = RemoveAll::new(&self.core.info(), dry_run, on_delete)?;
+        blocking_remove_all(&self.core, &path.0, state)
     }
 
     /// Create a dir at the given path.
@@ -210,15 +2286,18 @@ impl Operator {
     /// # Notes
     ///
     /// To indicate that a path is a directory, it is compulsory to include
-    /// a trailing / in the path. Failure to do so may result in
-    ///  a ` NotADirectory ` error being returned by OpenDAL.
+    /// a trailing / in the path, otherwise `NotADirectory` is raised. Pass a
+    /// `str`, since `pathlib.Path` drops the trailing /.
     ///
     /// # Behavior
     ///
     /// - Create on existing dir will succeed.
     /// - Create dir is always recursive, works like `mkdir -p`
-    pub fn create_dir(&self, path: PathBuf) -> PyResult<()> {
-        let path = path.to_string_lossy().to_string();
+    /// - On services where directories are virtual, like object stores, an
+    ///   empty object marks the directory so that it can be listed.
+    pub fn create_dir(&self, path: PyPath) -> PyResult<()> {
+        check_blocking()?;
+        let path = path.0;
         self.core.create_dir(&path).map_err(format_pyerr)
     }
 
@@ -226,10 +2305,39 @@ impl Operator {
     ///
     /// # Notes
     ///
-    /// - Delete not existing error won't return errors.
-    pub fn delete(&self, path: PathBuf) -> PyResult<()> {
-        let path = path.to_string_lossy().to_string();
-        self.core.delete(&path).map_err(format_pyerr)
+    /// - Deleting a path that doesn't exist succeeds, unless `missing_ok` is
+    ///   false, which raises `NotFound`. Services whose delete doesn't report
+    ///   a missing path stat it first, which can miss a concurrent delete.
+    /// - Pass `version` to delete that version instead of the current one, if
+    ///   the service supports versioning.
+    #[pyo3(signature = (path, *, version = None, missing_ok = true))]
+    pub fn delete(&self, path: PyPath, version: Option<String>, missing_ok: bool) -> PyResult<()> {
+        check_blocking()?;
+        let path = path.0;
+        if check_missing(&self.core.info(), missing_ok) {
+            missing_result(
+                self.core
+                    .stat_options(&path, version_stat_options(&version)),
+            )?;
+        }
+        let res = self
+            .core
+            .delete_options(&path, ocore::options::DeleteOptions { version });
+        delete_result(res, missing_ok)
+    }
+
+    /// Delete every path of the given iterable, batching requests if the
+    /// service supports it.
+    ///
+    /// # Notes
+    ///
+    /// - A failed path doesn't stop the others, it's reported in the
+    ///   returned `DeleteResult` instead.
+    pub fn delete_iter(&self, paths: &Bound<PyAny>) -> PyResult<DeleteResult> {
+        check_blocking()?;
+        let paths = extract_paths(paths)?;
+        let this: ocore::Operator = self.core.clone().into();
+        pyo3_async_runtimes::tokio::get_runtime().block_on(delete_paths(this, paths))
     }
 
     /// Checks if the given path exists.
@@ -237,26 +2345,47 @@ impl Operator {
     /// # Notes
     ///
     /// - Check not existing path won't return errors.
-    pub fn exists(&self, path: PathBuf) -> PyResult<bool> {
-        let path = path.to_string_lossy().to_string();
+    /// - Other errors, like `PermissionDenied`, are still raised.
+    pub fn exists(&self, path: PyPath) -> PyResult<bool> {
+        check_blocking()?;
+        let path = path.0;
         self.core.exists(&path).map_err(format_pyerr)
     }
 
     /// List current dir path.
-    #[pyo3(signature = (path, **kwargs))]
-    pub fn list(&self, path: PathBuf, kwargs: Option<&Bound<PyDict>>) -> PyResult<BlockingLister> {
-        let path = path.to_string_lossy().to_string();
+    ///
+    /// Entries carry the metadata the service returns while listing, which
+    /// differs per service. Use `stat` for anything that's missing.
+    ///
+    /// If `timeout` is given, as seconds or a `datetime.timedelta`, listing
+    /// raises `Unexpected` once it's exceeded, cancelling the request. The
+    /// deadline covers iterating the returned lister too.
+    #[pyo3(signature = (path, *, timeout = None, **kwargs))]
+    pub fn list(
+        &self,
+        path: PyPath,
+        timeout: Option<PyDuration>,
+        kwargs: Option<&Bound<PyDict>>,
+    ) -> PyResult<BlockingLister> {
+        check_blocking()?;
+        let path = path.0;
 
         let kwargs = kwargs
             .map(|v| v.extract::<ListOptions>())
             .transpose()?
             .unwrap_or_default();
+        kwargs.check(&self.core.info())?;
 
-        let l = self
-            .core
+        let limit = kwargs.limit;
+        let dirs_only = kwargs.dirs_only.unwrap_or_default();
+        let l = blocking_with_deadline(&self.core, timeout)?
             .lister_options(&path, kwargs.into())
             .map_err(format_pyerr)?;
-        Ok(BlockingLister::new(l))
+        let lister = BlockingLister::new(l, limit);
+        Ok(match dirs_only {
+            true => lister.with_filter(ListFilter::Dirs(path)),
+            false => lister,
+        })
     }
 
     /// List dir in a flat way.
@@ -264,14 +2393,142 @@ impl Operator {
     pub fn scan<'p>(
         &self,
         py: Python<'p>,
-        path: PathBuf,
+        path: PyPath,
         kwargs: Option<&Bound<PyDict>>,
     ) -> PyResult<BlockingLister> {
         let d = PyDict::new(py);
         let kwargs = kwargs.unwrap_or(&d);
         kwargs.set_item("recursive", true)?;
 
-        self.list(path, Some(kwargs))
+        self.list(path, None, Some(kwargs))
+    }
+
+    /// List the entries matching a glob `pattern`, like
+    /// `logs/2024-*/**.json`.
+    ///
+    /// Only the literal prefix before the first wildcard is listed,
+    /// recursively if the rest of the pattern spans directories, and the
+    /// entries are filtered while iterating. `*`, `?` and `[...]` match
+    /// within a directory, `**` crosses directory boundaries and `**/` also
+    /// matches no directory at all. Directories match without their trailing
+    /// `/`.
+    pub fn glob(&self, pattern: &str) -> PyResult<BlockingLister> {
+        check_blocking()?;
+        let glob = Glob::new(pattern)?;
+        let opts = ocore::options::ListOptions {
+            recursive: glob.recursive(),
+            ..Default::default()
+        };
+        let l = self
+            .core
+            .lister_options(glob.prefix(), opts)
+            .map_err(format_pyerr)?;
+        Ok(BlockingLister::new(l, None).with_filter(ListFilter::Glob(glob)))
+    }
+
+    /// Return the entries below the directory `path` as a tree.
+    ///
+    /// Each node is a dict with the `name` and `path` of the entry. Files
+    /// carry their `metadata`, directories a list of `children` sorted by
+    /// name. Without `max_depth` the whole tree is fetched with one recursive
+    /// listing. With it, directories are listed level by level, and the
+    /// directories `max_depth` levels deep get `None` for `children`.
+    #[pyo3(signature = (path, *, max_depth = None))]
+    pub fn tree(&self, py: Python, path: PyPath, max_depth: Option<usize>) -> PyResult<PyObject> {
+        check_blocking()?;
+        let mut tree = Tree::new(&path.0, max_depth)?;
+        let mut dirs = vec![tree.root().to_string()];
+        while let Some(dir) = dirs.pop() {
+            let opts = ocore::options::ListOptions {
+                recursive: max_depth.is_none(),
+                ..Default::default()
+            };
+            for entry in self.core.lister_options(&dir, opts).map_err(format_pyerr)? {
+                let entry = entry.map_err(format_pyerr)?;
+                let path = entry.path().to_string();
+                if tree.insert(entry) && max_depth.is_some() {
+                    dirs.push(path);
+                }
+            }
+        }
+        tree.into_py(py)
+    }
+
+    /// Presign an operation for stat(head) which expires after `expire_second`.
+    pub fn presign_stat(
+        &self,
+        path: PyPath,
+        expire_second: PyDuration,
+    ) -> PyResult<PresignedRequest> {
+        check_blocking()?;
+        let this: ocore::Operator = self.core.clone().into();
+        let path = path.0;
+        pyo3_async_runtimes::tokio::get_runtime()
+            .block_on(this.presign_stat(&path, expire_second.0))
+            .map_err(format_pyerr)
+            .map(PresignedRequest)
+    }
+
+    /// Presign an operation for read which expires after `expire_second`.
+    pub fn presign_read(
+        &self,
+        path: PyPath,
+        expire_second: PyDuration,
+    ) -> PyResult<PresignedRequest> {
+        check_blocking()?;
+        let this: ocore::Operator = self.core.clone().into();
+        let path = path.0;
+        pyo3_async_runtimes::tokio::get_runtime()
+            .block_on(this.presign_read(&path, expire_second.0))
+            .map_err(format_pyerr)
+            .map(PresignedRequest)
+    }
+
+    /// Presign an operation for write which expires after `expire_second`.
+    pub fn presign_write(
+        &self,
+        path: PyPath,
+        expire_second: PyDuration,
+    ) -> PyResult<PresignedRequest> {
+        check_blocking()?;
+        let this: ocore::Operator = self.core.clone().into();
+        let path = path.0;
+        pyo3_async_runtimes::tokio::get_runtime()
+            .block_on(this.presign_write(&path, expire_second.0))
+            .map_err(format_pyerr)
+            .map(PresignedRequest)
+    }
+
+    /// Presign an operation for delete which expires after `expire_second`.
+    pub fn presign_delete(
+        &self,
+        path: PyPath,
+        expire_second: PyDuration,
+    ) -> PyResult<PresignedRequest> {
+        check_blocking()?;
+        let this: ocore::Operator = self.core.clone().into();
+        let path = path.0;
+        pyo3_async_runtimes::tokio::get_runtime()
+            .block_on(this.presign_delete(&path, expire_second.0))
+            .map_err(format_pyerr)
+            .map(PresignedRequest)
+    }
+
+    /// Presign `operation`, one of `"read"`, `"write"`, `"stat"` or
+    /// `"delete"`, on the path for `expire`, as seconds or a
+    /// `datetime.timedelta`.
+    ///
+    /// Raise `Unsupported` unless the service can presign the operation.
+    pub fn presign(
+        &self,
+        path: PyPath,
+        operation: &str,
+        expire: PyDuration,
+    ) -> PyResult<PresignedRequest> {
+        check_blocking()?;
+        let operation = PresignOperation::new(&self.core.info(), operation)?;
+        let this: ocore::Operator = self.core.clone().into();
+        pyo3_async_runtimes::tokio::get_runtime().block_on(operation.sign(&this, &path.0, expire.0))
     }
 
     pub fn capability(&self) -> PyResult<capability::Capability> {
@@ -280,8 +2537,28 @@ impl Operator {
         ))
     }
 
+    /// Raise `Unsupported` unless the service supports every capability flag
+    /// given as `True`, like `op.require(read=True, presign=True)`.
+    ///
+    /// The error names each missing flag, so an operator can be validated
+    /// upfront instead of failing on its first unsupported call.
+    #[pyo3(signature = (**kwargs))]
+    pub fn require(&self, kwargs: Option<&Bound<PyDict>>) -> PyResult<()> {
+        capability::Capability::require(&self.core.info(), kwargs)
+    }
+
+    /// Get the information of this operator.
+    pub fn info(&self) -> OperatorInfo {
+        OperatorInfo(self.core.info())
+    }
+
     /// Check if this operator can work correctly.
+    ///
+    /// This lists the root with a limit of 1, so misconfigured endpoints or
+    /// credentials surface here instead of on the first real request. A
+    /// missing root isn't an error.
     pub fn check(&self) -> PyResult<()> {
+        check_blocking()?;
         self.core.check().map_err(format_pyerr)
     }
 
@@ -290,46 +2567,83 @@ impl Operator {
             core: self.core.clone().into(),
             __scheme: self.__scheme,
             __map: self.__map.clone(),
+            __http: self.__http.clone(),
+            __credential: self.__credential.clone(),
+            __write_defaults: self.__write_defaults.clone(),
+            __layers: self.__layers.clone(),
+            __read_chunk: self.__read_chunk,
         })
     }
 
     fn __repr__(&self) -> String {
-        let info = self.core.info();
-        let name = info.name();
-        if name.is_empty() {
-            format!("Operator(\"{}\", root=\"{}\")", info.scheme(), info.root())
-        } else {
-            format!(
-                "Operator(\"{}\", root=\"{}\", name=\"{name}\")",
-                info.scheme(),
-                info.root()
-            )
+        operator_repr("Operator", &self.core.info(), &self.__layers)
+    }
+
+    fn __copy__(&self) -> Self {
+        Self {
+            core: self.core.clone(),
+            __scheme: self.__scheme,
+            __map: self.__map.clone(),
+            __http: self.__http.clone(),
+            __credential: self.__credential.clone(),
+            __write_defaults: self.__write_defaults.clone(),
+            __layers: self.__layers.clone(),
+            __read_chunk: self.__read_chunk,
         }
     }
 
-    fn __getnewargs_ex__(&self, py: Python) -> PyResult<PyObject> {
-        let args = vec![self.__scheme.to_string()];
-        let args = PyTuple::new(py, args)?.into_py_any(py)?;
-        let kwargs = self.__map.clone().into_py_any(py)?;
-        PyTuple::new(py, [args, kwargs])?.into_py_any(py)
+    /// Operators are handles to a service, so a deep copy shares the same
+    /// service and layers just like a shallow one.
+    fn __deepcopy__(&self, _memo: &Bound<PyAny>) -> Self {
+        self.__copy__()
+    }
+
+    /// Rebuild through `opendal.Operator`, which routes the scheme to the
+    /// package providing it, since this class isn't reachable by name.
+    fn __reduce__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        reduce_operator(
+            py,
+            "Operator",
+            self.__scheme,
+            &self.__map,
+            self.__http.as_ref(),
+            self.__credential.as_ref(),
+        )
     }
 }
 
 /// `AsyncOperator` is the entry for all public async APIs
 ///
 /// Create a new `AsyncOperator` with the given `scheme` and options(`**kwargs`).
+///
+/// Pickling rebuilds the operator from its `scheme` and options, so it can be
+/// sent to other processes. The options, including any credentials, are
+/// stored in the pickle in plain text. Layers are not carried over. Forked
+/// children can't reuse the parent's runtime, so use the `spawn` start method.
 #[pyclass(module = "opendal")]
 pub struct AsyncOperator {
     core: ocore::Operator,
     __scheme: ocore::Scheme,
     __map: HashMap<String, String>,
+    __http: Option<HttpOptions>,
+    __credential: Option<Arc<CredentialProvider>>,
+    __write_defaults: Arc<WriteOptions>,
+    /// The class names of the layers applied, innermost first, for `__repr__`.
+    __layers: Arc<Vec<String>>,
+    __read_chunk: Option<usize>,
 }
 
 #[pymethods]
 impl AsyncOperator {
     #[new]
-    #[pyo3(signature = (scheme, *,  **map))]
-    pub fn new(scheme: &str, map: Option<&Bound<PyDict>>) -> PyResult<Self> {
+    #[pyo3(signature = (scheme, *, http_options = None, credential_provider = None, **map))]
+    pub fn new(
+        py: Python,
+        scheme: &str,
+        http_options: Option<HttpOptions>,
+        credential_provider: Option<PyObject>,
+        map: Option<&Bound<PyDict>>,
+    ) -> PyResult<Self> {
         let scheme = ocore::Scheme::from_str(scheme)
             .map_err(|err| {
                 ocore::Error::new(ocore::ErrorKind::Unexpected, "unsupported scheme")
@@ -342,202 +2656,896 @@ impl AsyncOperator {
                     .expect("must be valid hashmap")
             })
             .unwrap_or_default();
+        let credential = credential_provider
+            .map(|v| CredentialProvider::new(py, v))
+            .transpose()?;
 
         Ok(AsyncOperator {
-            core: build_operator(scheme, map.clone())?,
+            core: build_operator(
+                scheme,
+                map.clone(),
+                http_options.as_ref(),
+                credential.as_ref(),
+            )?,
             __scheme: scheme,
             __map: map,
+            __http: http_options,
+            __credential: credential,
+            __write_defaults: Arc::default(),
+            __layers: Arc::default(),
+            __read_chunk: None,
         })
     }
 
-    /// Add new layers upon the existing operator
-    pub fn layer(&self, layer: &layers::Layer) -> PyResult<Self> {
-        let op = layer.0.layer(self.core.clone());
+    /// Create a new `AsyncOperator` from a URI like
+    /// `s3://bucket/prefix?region=us-east-1`.
+    ///
+    /// The scheme picks the service. The host is the bucket or container of
+    /// services that have one, the path becomes `root`, and the query
+    /// parameters map to config keys. Keyword arguments, like credentials,
+    /// override the values parsed from the URI.
+    #[staticmethod]
+    #[pyo3(signature = (uri, *, http_options = None, credential_provider = None, **map))]
+    pub fn from_uri(
+        py: Python,
+        uri: &str,
+        http_options: Option<HttpOptions>,
+        credential_provider: Option<PyObject>,
+        map: Option<&Bound<PyDict>>,
+    ) -> PyResult<Self> {
+        let (scheme, config) = parse_uri(uri)?;
+        let config = config.into_py_dict(py)?;
+        if let Some(map) = map {
+            config.update(map.as_mapping())?;
+        }
+        Self::new(
+            py,
+            &scheme,
+            http_options,
+            credential_provider,
+            Some(&config),
+        )
+    }
+
+    /// Create a new `AsyncOperator` for `scheme` from the environment
+    /// variables named like `OPENDAL_S3_BUCKET`.
+    ///
+    /// The prefix and scheme are stripped and the rest is lowercased into
+    /// the config key, so `OPENDAL_S3_BUCKET` sets `bucket`. Keyword
+    /// arguments override the values read from the environment.
+    #[staticmethod]
+    #[pyo3(signature = (scheme, prefix = "OPENDAL", *, http_options = None, credential_provider = None, **map))]
+    pub fn from_env(
+        py: Python,
+        scheme: &str,
+        prefix: &str,
+        http_options: Option<HttpOptions>,
+        credential_provider: Option<PyObject>,
+        map: Option<&Bound<PyDict>>,
+    ) -> PyResult<Self> {
+        let config = parse_env(scheme, prefix).into_py_dict(py)?;
+        if let Some(map) = map {
+            config.update(map.as_mapping())?;
+        }
+        check_env_config(scheme, prefix, &config)?;
+        Self::new(py, scheme, http_options, credential_provider, Some(&config))
+            .map_err(|err| env_config_error(py, err, scheme, prefix))
+    }
+
+    /// Add new layers upon the existing operator.
+    ///
+    /// This returns a new operator and leaves the current one unchanged, so
+    /// several variants can be derived from the same base.
+    pub fn layer(&self, layer: &Bound<layers::Layer>) -> PyResult<Self> {
+        let layers = with_layer(&self.__layers, layer)?;
+        let op = layer.borrow().0.layer(self.core.clone());
         Ok(Self {
             core: op,
             __scheme: self.__scheme,
             __map: self.__map.clone(),
+            __http: self.__http.clone(),
+            __credential: self.__credential.clone(),
+            __write_defaults: self.__write_defaults.clone(),
+            __layers: Arc::new(layers),
+            __read_chunk: self.__read_chunk,
+        })
+    }
+
+    /// Return a new operator that applies the given write options, like
+    /// `content_type` or `cache_control`, to every write.
+    ///
+    /// Options passed to a single call take precedence over these defaults,
+    /// and `user_metadata` is merged key by key. Options the service can't
+    /// honor still raise when writing. Like layers, the defaults are not
+    /// carried over by pickling.
+    #[pyo3(signature = (**kwargs))]
+    pub fn with_default_write_options(&self, kwargs: Option<WriteOptions>) -> Self {
+        let defaults = kwargs.unwrap_or_default();
+        Self {
+            core: self.core.clone(),
+            __scheme: self.__scheme,
+            __map: self.__map.clone(),
+            __http: self.__http.clone(),
+            __credential: self.__credential.clone(),
+            __write_defaults: Arc::new(defaults.with_defaults(&self.__write_defaults)),
+            __layers: self.__layers.clone(),
+            __read_chunk: self.__read_chunk,
+        }
+    }
+
+    /// Return a new operator that reads and writes in chunks of the given
+    /// sizes, in bytes, unless a call passes its own `chunk`.
+    ///
+    /// By default reads fetch the whole range in one request, or in 8 MiB
+    /// chunks when `concurrent` is given, and writes are buffered up to the
+    /// service's minimum part size, if it has one. Larger chunks mean fewer
+    /// requests, which pays off on high-latency services.
+    ///
+    /// A `write_chunk` outside the part sizes the service accepts raises
+    /// `ValueError`.
+    #[pyo3(signature = (*, read_chunk = None, write_chunk = None))]
+    pub fn with_chunk_sizes(
+        &self,
+        read_chunk: Option<usize>,
+        write_chunk: Option<usize>,
+    ) -> PyResult<Self> {
+        if read_chunk == Some(0) {
+            return Err(PyValueError::new_err(
+                "read_chunk must be a positive integer",
+            ));
+        }
+        let defaults = WriteOptions {
+            chunk: write_chunk,
+            ..Default::default()
+        };
+        defaults.check(&self.core.info())?;
+        Ok(Self {
+            core: self.core.clone(),
+            __scheme: self.__scheme,
+            __map: self.__map.clone(),
+            __http: self.__http.clone(),
+            __credential: self.__credential.clone(),
+            __write_defaults: Arc::new(defaults.with_defaults(&self.__write_defaults)),
+            __layers: self.__layers.clone(),
+            __read_chunk: read_chunk.or(self.__read_chunk),
+        })
+    }
+
+    /// Open a file-like reader for the given path.
+    #[pyo3(signature = (path, mode, *, **kwargs))]
+    pub fn open<'p>(
+        &'p self,
+        py: Python<'p>,
+        path: PyPath,
+        mode: String,
+        kwargs: Option<&Bound<PyDict>>,
+    ) -> PyResult<Bound<'p, PyAny>> {
+        let this = self.core.clone();
+        let path = path.0;
+
+        let mut reader_opts = kwargs
+            .map(|v| v.extract::<ReadOptions>())
+            .transpose()?
+            .unwrap_or_default()
+            .with_default_chunk(self.__read_chunk);
+        if mode == "rb" {
+            reader_opts.check()?;
+        }
+
+        let mut writer_opts = kwargs
+            .map(|v| v.extract::<WriteOptions>())
+            .transpose()?
+            .unwrap_or_default()
+            .with_defaults(&self.__write_defaults);
+        // The file sends its requests after `open` returns.
+        let headers = match mode.as_str() {
+            "rb" => &reader_opts.extra_headers,
+            _ => &writer_opts.extra_headers,
+        };
+        reject_extra_headers(headers, "open")?;
+        if mode == "ab" {
+            writer_opts.append = Some(true);
+        }
+        if mode == "wb" || mode == "ab" {
+            writer_opts.check(&this.info())?;
+            plan_file_write(&mut writer_opts, &this.info())?;
+        }
+
+        future_into_py(py, async move {
+            if mode == "rb" {
+                let range = reader_opts.make_range();
+                let reader = this
+                    .reader_options(&path, reader_opts.into())
+                    .await
+                    .map_err(format_pyerr)?;
+
+                let r = reader
+                    .into_futures_async_read(range.to_range())
+                    .await
+                    .map_err(format_pyerr)?;
+                Ok(AsyncFile::new_reader(r))
+            } else if mode == "wb" || mode == "ab" {
+                let writer = this
+                    .writer_options(&path, writer_opts.into())
+                    .await
+                    .map_err(format_pyerr)?;
+                let w = writer.into_futures_async_write();
+                Ok(AsyncFile::new_writer(w))
+            } else {
+                Err(Unsupported::new_err(format!(
+                    "OpenDAL doesn't support mode: {mode}"
+                )))
+            }
+        })
+    }
+
+    /// Read the whole path into bytes.
+    ///
+    /// If `progress` is given, it's called with `(bytes_so_far, total)` as
+    /// the content arrives.
+    ///
+    /// If `buffer_protocol` is true, return a `Buffer` sharing the memory
+    /// that was read instead of copying it into `bytes`.
+    ///
+    /// If `verify` is true, check the content against the MD5 the service
+    /// reports and raise `ChecksumMismatch` if it differs. Objects without a
+    /// checksum are returned unchecked unless `require_checksum` is true.
+    ///
+    /// If `timeout` is given, as seconds or a `datetime.timedelta`, the call
+    /// raises `Unexpected` once it's exceeded, cancelling the request.
+    #[pyo3(signature = (
+        path,
+        *,
+        progress = None,
+        buffer_protocol = false,
+        verify = false,
+        require_checksum = false,
+        timeout = None,
+        **kwargs
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn read<'p>(
+        &'p self,
+        py: Python<'p>,
+        path: PyPath,
+        progress: Option<PyObject>,
+        buffer_protocol: bool,
+        verify: bool,
+        require_checksum: bool,
+        timeout: Option<PyDuration>,
+        kwargs: Option<ReadOptions>,
+    ) -> PyResult<Bound<'p, PyAny>> {
+        let this = with_deadline(self.core.clone(), timeout);
+        let path = path.0;
+        let mut kwargs = kwargs
+            .unwrap_or_default()
+            .with_default_chunk(self.__read_chunk);
+        kwargs.check()?;
+        let headers = parse_extra_headers(kwargs.extra_headers.take())?;
+        let verify = verify || require_checksum;
+        if verify {
+            check_verify_range(&kwargs)?;
+        }
+        future_into_py(
+            py,
+            with_extra_headers(headers, async move {
+                let mut verifier = None;
+                if verify {
+                    let meta = this
+                        .stat_options(&path, kwargs.stat_options())
+                        .await
+                        .map_err(format_pyerr)?;
+                    verifier = checksum_verifier(
+                        &this.info(),
+                        &path,
+                        &meta,
+                        &mut kwargs,
+                        require_checksum,
+                    )?;
+                }
+                if let Some(callback) = progress {
+                    let buffer = read_with_progress(this, path, kwargs, callback).await?;
+                    if let Some(verifier) = verifier {
+                        verifier.verify([&buffer])?;
+                    }
+                    return Python::with_gil(|py| {
+                        Buffer::new(buffer).into_bytes_or_buffer(py, buffer_protocol)
+                    });
+                }
+                let res = read_clamped(&this, &path, kwargs)
+                    .await
+                    .map_err(format_pyerr)?;
+                if let Some(verifier) = verifier {
+                    verifier.verify(res.clone())?;
+                }
+                Python::with_gil(|py| Buffer::from(res).into_bytes_or_buffer(py, buffer_protocol))
+            }),
+        )
+    }
+
+    /// Read the whole path into memory and return it as a `BufferReader`,
+    /// a file-like object that can be passed to parsers without copying the
+    /// content into `io.BytesIO` first.
+    ///
+    /// Takes the same options as `read`.
+    #[pyo3(signature = (path, **kwargs))]
+    pub fn read_stream<'p>(
+        &'p self,
+        py: Python<'p>,
+        path: PyPath,
+        kwargs: Option<ReadOptions>,
+    ) -> PyResult<Bound<'p, PyAny>> {
+        let this = self.core.clone();
+        let path = path.0;
+        let mut kwargs = kwargs
+            .unwrap_or_default()
+            .with_default_chunk(self.__read_chunk);
+        kwargs.check()?;
+        let headers = parse_extra_headers(kwargs.extra_headers.take())?;
+        future_into_py(
+            py,
+            with_extra_headers(headers, async move {
+                read_clamped(&this, &path, kwargs)
+                    .await
+                    .map(BufferReader::new)
+                    .map_err(format_pyerr)
+            }),
+        )
+    }
+
+    /// Open a reader for the given path along with the number of bytes it
+    /// yields, for responses that need a `Content-Length` before streaming
+    /// the content.
+    ///
+    /// Return `(size, file)`, where `file` is the reader `open(path, "rb")`
+    /// returns and iterates in chunks. `size` is looked up with a stat and
+    /// accounts for `range`, `offset` and `size`, clamped like `read`, or is
+    /// `None` if the service can't stat.
+    ///
+    /// Takes the same options as `open`.
+    #[pyo3(signature = (path, **kwargs))]
+    pub fn reader_with_size<'p>(
+        &'p self,
+        py: Python<'p>,
+        path: PyPath,
+        kwargs: Option<ReadOptions>,
+    ) -> PyResult<Bound<'p, PyAny>> {
+        let this = self.core.clone();
+        let path = path.0;
+        let mut kwargs = kwargs
+            .unwrap_or_default()
+            .with_default_chunk(self.__read_chunk);
+        kwargs.check()?;
+        reject_extra_headers(&kwargs.extra_headers, "reader_with_size")?;
+
+        future_into_py(py, async move {
+            let mut size = None;
+            if this.info().full_capability().stat {
+                let meta = this
+                    .stat_options(&path, kwargs.stat_options())
+                    .await
+                    .map_err(format_pyerr)?;
+                kwargs.clamp_range(meta.content_length() as usize);
+                size = kwargs.size.map(|v| v as u64);
+            }
+            let range = kwargs.make_range();
+            let reader = this
+                .reader_options(&path, kwargs.into())
+                .await
+                .map_err(format_pyerr)?;
+            let r = reader
+                .into_futures_async_read(range.to_range())
+                .await
+                .map_err(format_pyerr)?;
+            Ok((size, AsyncFile::new_reader(r)))
+        })
+    }
+
+    /// Read the path unless it hasn't changed since `since`, the way an HTTP
+    /// cache revalidates an entry.
+    ///
+    /// Return `(metadata, data)`, where `data` is `None` if the object wasn't
+    /// modified after `since`. Services supporting it are sent a conditional
+    /// read with `if_modified_since`, the rest compare the last modified time
+    /// of the object.
+    #[pyo3(signature = (path, since))]
+    pub fn read_if_modified<'p>(
+        &'p self,
+        py: Python<'p>,
+        path: PyPath,
+        since: DateTime<FixedOffset>,
+    ) -> PyResult<Bound<'p, PyAny>> {
+        let this = self.core.clone();
+        let path = path.0;
+        future_into_py(py, async move {
+            let meta = this.stat(&path).await.map_err(format_pyerr)?;
+            let content = match if_modified_read(&this.info(), &meta, since.with_timezone(&Utc)) {
+                Some(opts) => modified_content(this.read_options(&path, opts).await)?,
+                None => None,
+            };
+            Python::with_gil(|py| {
+                let content = content
+                    .map(|buffer| Buffer::from(buffer).into_bytes_or_buffer(py, false))
+                    .transpose()?;
+                Ok((Metadata::new(meta), content))
+            })
+        })
+    }
+
+    /// Read the whole path along with the metadata of the content read.
+    ///
+    /// Return `(metadata, data)`. For services speaking HTTP, the metadata,
+    /// like `etag`, `content_length`, `content_type` and `last_modified`,
+    /// comes from the headers of the same `GET` that returned `data`, so it
+    /// can't describe another version of the object. Other services, like
+    /// `fs`, stat the object after reading it.
+    #[pyo3(signature = (path, **kwargs))]
+    pub fn read_with_metadata<'p>(
+        &'p self,
+        py: Python<'p>,
+        path: PyPath,
+        kwargs: Option<ReadOptions>,
+    ) -> PyResult<Bound<'p, PyAny>> {
+        let this = self.core.clone();
+        let path = path.0;
+        let mut kwargs = kwargs
+            .unwrap_or_default()
+            .with_default_chunk(self.__read_chunk);
+        check_whole_read(&mut kwargs)?;
+        let headers = parse_extra_headers(kwargs.extra_headers.take())?;
+        future_into_py(py, async move {
+            let (buffer, response) = with_extra_headers(
+                headers,
+                with_response_headers(this.read_options(&path, kwargs.into())),
+            )
+            .await;
+            let buffer = buffer.map_err(format_pyerr)?;
+            let meta = match response {
+                Some(headers) => response_metadata(&path, &headers),
+                None => this.stat(&path).await,
+            }
+            .map_err(format_pyerr)?;
+            Python::with_gil(|py| {
+                let content = Buffer::from(buffer).into_bytes_or_buffer(py, false)?;
+                Ok((Metadata::new(meta), content))
+            })
+        })
+    }
+
+    /// Read several byte ranges of the path at once, like the footer and a
+    /// few column chunks of a Parquet file.
+    ///
+    /// `ranges` are `(offset, length)` pairs, and a list of `bytes` in the
+    /// same order is returned. Ranges that overlap or are less than `gap`
+    /// bytes apart, 1 MiB by default, are merged into one ranged read, and up
+    /// to `concurrent` reads, 16 by default, are sent at once. Like `read`,
+    /// the ranges are clamped to the size of the object.
+    #[pyo3(signature = (path, ranges, **kwargs))]
+    pub fn read_ranges<'p>(
+        &'p self,
+        py: Python<'p>,
+        path: PyPath,
+        ranges: Vec<(u64, u64)>,
+        kwargs: Option<ReadOptions>,
+    ) -> PyResult<Bound<'p, PyAny>> {
+        let this = self.core.clone();
+        let path = path.0;
+        let mut kwargs = kwargs
+            .unwrap_or_default()
+            .with_default_chunk(self.__read_chunk);
+        check_read_ranges(&mut kwargs)?;
+        let headers = parse_extra_headers(kwargs.extra_headers.take())?;
+        future_into_py(py, async move {
+            if ranges.is_empty() {
+                return Ok(Vec::new());
+            }
+            let buffers = with_extra_headers(headers, async {
+                fetch_clamped(&this, &path, kwargs, &ranges).await
+            })
+            .await
+            .map_err(format_pyerr)?;
+            Python::with_gil(|py| {
+                buffers
+                    .into_iter()
+                    .map(|buffer| Buffer::from(buffer).into_bytes_or_buffer(py, false))
+                    .collect::<PyResult<Vec<_>>>()
+            })
         })
     }
 
-    /// Open a file-like reader for the given path.
-    #[pyo3(signature = (path, mode, *, **kwargs))]
-    pub fn open<'p>(
+    /// Write a bytes-like object into the given path.
+    ///
+    /// `bs` may be anything implementing the buffer protocol, like `bytes`,
+    /// `bytearray`, `memoryview` or a contiguous one-dimensional numpy array.
+    /// It's streamed into the writer without copying it as a whole.
+    ///
+    /// `bs` may also be an async iterable, like an async generator, or an
+    /// iterable of bytes-like chunks. Each chunk is awaited only once the
+    /// previous one is written, so a slow service pauses the producer. If
+    /// the iterable raises, the upload is aborted.
+    ///
+    /// `strategy` picks between a single request and a multipart upload,
+    /// like for the blocking `write`.
+    ///
+    /// If `progress` is given, it's called with `(bytes_so_far, total)` as
+    /// the content is written. `total` is `None` for an iterable.
+    ///
+    /// Return the `Metadata` of the written object as reported by the
+    /// service, like its etag or version.
+    ///
+    /// If `timeout` is given, as seconds or a `datetime.timedelta`, the call
+    /// raises `Unexpected` once it's exceeded, cancelling the request.
+    #[pyo3(signature = (path, bs, *, progress = None, timeout = None, **kwargs))]
+    pub fn write<'p>(
+        &'p self,
+        py: Python<'p>,
+        path: PyPath,
+        bs: &Bound<PyAny>,
+        progress: Option<PyObject>,
+        timeout: Option<PyDuration>,
+        kwargs: Option<WriteOptions>,
+    ) -> PyResult<Bound<'p, PyAny>> {
+        let mut kwargs = kwargs
+            .unwrap_or_default()
+            .with_defaults(&self.__write_defaults);
+        let this = with_deadline(self.core.clone(), timeout);
+        kwargs.check(&this.info())?;
+        let headers = parse_extra_headers(kwargs.extra_headers.take())?;
+        let path = path.0;
+        match WriteContent::get(bs, true)? {
+            WriteContent::Bytes(bs) => future_into_py(
+                py,
+                with_extra_headers(headers, write_chunks(this, path, bs, kwargs, progress)),
+            ),
+            WriteContent::Chunks(chunks) => future_into_py(
+                py,
+                with_extra_headers(headers, write_iter(this, path, chunks, kwargs, progress)),
+            ),
+        }
+    }
+
+    /// Write a bytes-like object into the given path starting at `offset`,
+    /// patching the existing file in place.
+    ///
+    /// The file must exist and `offset` must not be past its end. Content
+    /// outside the written range is kept. Only services with the
+    /// `write_with_offset` capability like `fs` support this.
+    pub fn write_at<'p>(
         &'p self,
         py: Python<'p>,
-        path: PathBuf,
-        mode: String,
-        kwargs: Option<&Bound<PyDict>>,
+        path: PyPath,
+        offset: u64,
+        bs: &Bound<PyAny>,
     ) -> PyResult<Bound<'p, PyAny>> {
         let this = self.core.clone();
-        let path = path.to_string_lossy().to_string();
-
-        let reader_opts = kwargs
-            .map(|v| v.extract::<ReadOptions>())
-            .transpose()?
-            .unwrap_or_default();
-
-        let writer_opts = kwargs
-            .map(|v| v.extract::<WriteOptions>())
-            .transpose()?
-            .unwrap_or_default();
-
+        let bs = BytesView::get(bs)?;
+        let opts = write_at_options(&this.info(), offset, bs.as_slice().len())?;
+        let bs = Bytes::copy_from_slice(bs.as_slice());
+        let path = path.0;
         future_into_py(py, async move {
-            if mode == "rb" {
-                let range = reader_opts.make_range();
-                let reader = this
-                    .reader_options(&path, reader_opts.into())
-                    .await
-                    .map_err(format_pyerr)?;
-
-                let r = reader
-                    .into_futures_async_read(range.to_range())
-                    .await
-                    .map_err(format_pyerr)?;
-                Ok(AsyncFile::new_reader(r))
-            } else if mode == "wb" {
-                let writer = this
-                    .writer_options(&path, writer_opts.into())
-                    .await
-                    .map_err(format_pyerr)?;
-                let w = writer.into_futures_async_write();
-                Ok(AsyncFile::new_writer(w))
-            } else {
-                Err(Unsupported::new_err(format!(
-                    "OpenDAL doesn't support mode: {mode}"
-                )))
-            }
+            this.write_options(&path, bs, opts)
+                .await
+                .map(Metadata::new)
+                .map_err(format_pyerr)
         })
     }
 
-    /// Read the whole path into bytes.
-    #[pyo3(signature = (path, **kwargs))]
-    pub fn read<'p>(
+    /// Download the object at `path` into the local file `local_path`.
+    ///
+    /// The object is streamed to disk without being buffered in memory. If
+    /// the transfer fails, the partial local file is removed.
+    ///
+    /// `verify` and `require_checksum` check the content like `read` does,
+    /// removing the local file on a mismatch.
+    ///
+    /// `concurrent` and `chunk` download the object as concurrent ranged
+    /// reads, which are written to the file in order as they complete.
+    #[pyo3(signature = (
+        path,
+        local_path,
+        *,
+        verify = false,
+        require_checksum = false,
+        **kwargs
+    ))]
+    pub fn read_to_file<'p>(
         &'p self,
         py: Python<'p>,
-        path: PathBuf,
+        path: PyPath,
+        local_path: PathBuf,
+        verify: bool,
+        require_checksum: bool,
         kwargs: Option<ReadOptions>,
     ) -> PyResult<Bound<'p, PyAny>> {
         let this = self.core.clone();
-        let path = path.to_string_lossy().to_string();
-        let kwargs = kwargs.unwrap_or_default();
-        future_into_py(py, async move {
-            let range = kwargs.make_range();
-            let res = this
-                .reader_options(&path, kwargs.into())
-                .await
-                .map_err(format_pyerr)?
-                .read(range.to_range())
-                .await
-                .map_err(format_pyerr)?
-                .to_vec();
-            Python::with_gil(|py| Buffer::new(res).into_bytes(py))
-        })
+        let path = path.0;
+        let mut opts = kwargs
+            .unwrap_or_default()
+            .with_default_chunk(self.__read_chunk);
+        opts.check()?;
+        if opts.has_range() {
+            return Err(PyValueError::new_err(
+                "read_to_file downloads the whole object, use read for a range",
+            ));
+        }
+        let headers = parse_extra_headers(opts.extra_headers.take())?;
+        future_into_py(
+            py,
+            with_extra_headers(headers, async move {
+                let mut verifier = None;
+                if verify || require_checksum {
+                    let meta = this
+                        .stat_options(&path, opts.stat_options())
+                        .await
+                        .map_err(format_pyerr)?;
+                    verifier =
+                        checksum_verifier(&this.info(), &path, &meta, &mut opts, require_checksum)?;
+                }
+                read_to_file(this, path, local_path, opts, verifier).await
+            }),
+        )
     }
 
-    /// Write bytes into given path.
-    #[pyo3(signature = (path, bs, **kwargs))]
-    pub fn write<'p>(
+    /// Upload the local file `local_path` to `path`.
+    ///
+    /// The file is streamed in pieces of `chunk` bytes without being
+    /// buffered in memory, unless `strategy` sends it in a single request.
+    /// Write options such as `content_type` apply to the uploaded object.
+    #[pyo3(signature = (path, local_path, **kwargs))]
+    pub fn write_from_file<'p>(
         &'p self,
         py: Python<'p>,
-        path: PathBuf,
-        bs: &Bound<PyBytes>,
+        path: PyPath,
+        local_path: PathBuf,
         kwargs: Option<WriteOptions>,
     ) -> PyResult<Bound<'p, PyAny>> {
-        let mut kwargs = kwargs.unwrap_or_default();
+        let mut kwargs = kwargs
+            .unwrap_or_default()
+            .with_defaults(&self.__write_defaults);
         let this = self.core.clone();
-        let bs = bs.as_bytes().to_vec();
-        let path = path.to_string_lossy().to_string();
-        future_into_py(py, async move {
-            let mut write = this
-                .write_with(&path, bs)
-                .append(kwargs.append.unwrap_or(false));
-            if let Some(buffer) = kwargs.chunk {
-                write = write.chunk(buffer);
-            }
-            if let Some(content_type) = &kwargs.content_type {
-                write = write.content_type(content_type);
-            }
-            if let Some(content_disposition) = &kwargs.content_disposition {
-                write = write.content_disposition(content_disposition);
-            }
-            if let Some(cache_control) = &kwargs.cache_control {
-                write = write.cache_control(cache_control);
-            }
-            if let Some(user_metadata) = kwargs.user_metadata.take() {
-                write = write.user_metadata(user_metadata);
-            }
-
-            write.await.map(|_| ()).map_err(format_pyerr)
-        })
+        kwargs.check(&this.info())?;
+        let headers = parse_extra_headers(kwargs.extra_headers.take())?;
+        let path = path.0;
+        future_into_py(
+            py,
+            with_extra_headers(headers, write_from_file(this, path, local_path, kwargs)),
+        )
     }
 
     /// Get metadata for the current path **without cache** directly.
-    #[pyo3(signature = (path, **kwargs))]
+    ///
+    /// If `timeout` is given, as seconds or a `datetime.timedelta`, the call
+    /// raises `Unexpected` once it's exceeded, cancelling the request.
+    #[pyo3(signature = (path, *, timeout = None, **kwargs))]
     pub fn stat<'p>(
         &'p self,
         py: Python<'p>,
-        path: PathBuf,
+        path: PyPath,
+        timeout: Option<PyDuration>,
         kwargs: Option<&Bound<PyDict>>,
     ) -> PyResult<Bound<'p, PyAny>> {
-        let this = self.core.clone();
-        let path = path.to_string_lossy().to_string();
-        let kwargs = kwargs
+        let this = with_deadline(self.core.clone(), timeout);
+        let path = path.0;
+        let mut kwargs = kwargs
             .map(|v| v.extract::<StatOptions>())
             .transpose()?
             .unwrap_or_default();
+        let headers = parse_extra_headers(kwargs.extra_headers.take())?;
 
-        future_into_py(py, async move {
-            let res: Metadata = this
-                .stat_options(&path, kwargs.into())
-                .await
-                .map_err(format_pyerr)
-                .map(Metadata::new)?;
+        future_into_py(
+            py,
+            with_extra_headers(headers, async move {
+                let res: Metadata = this
+                    .stat_options(&path, kwargs.into())
+                    .await
+                    .map_err(format_pyerr)
+                    .map(Metadata::new)?;
 
-            Ok(res)
+                Ok(res)
+            }),
+        )
+    }
+
+    /// Get metadata for every path of the given iterable.
+    ///
+    /// Return a list aligned with `paths`, holding either the `Metadata` or
+    /// the exception of each path. At most `concurrency` stats are in flight
+    /// at once.
+    #[pyo3(signature = (paths, *, concurrency = 16))]
+    pub fn stat_many<'p>(
+        &'p self,
+        py: Python<'p>,
+        paths: &Bound<PyAny>,
+        concurrency: usize,
+    ) -> PyResult<Bound<'p, PyAny>> {
+        if concurrency == 0 {
+            return Err(PyValueError::new_err(
+                "concurrency must be a positive integer",
+            ));
+        }
+        let paths = extract_paths(paths)?;
+        let this = self.core.clone();
+        future_into_py(py, async move {
+            let results: Vec<_> = futures::stream::iter(paths)
+                .map(|path| {
+                    let this = this.clone();
+                    async move { this.stat(&path).await }
+                })
+                .buffered(concurrency)
+                .collect()
+                .await;
+            Python::with_gil(|py| {
+                results
+                    .into_iter()
+                    .map(|res| match res {
+                        Ok(meta) => Metadata::new(meta).into_py_any(py),
+                        Err(err) => format_pyerr(err).into_value(py).into_py_any(py),
+                    })
+                    .collect::<PyResult<Vec<_>>>()
+            })
         })
     }
 
-    /// Copy source to target.``
+    /// Copy the source to the target.
+    ///
+    /// # Notes
+    ///
+    /// - `source` and `target` must be files.
+    /// - `target` will be overwritten if it exists.
     pub fn copy<'p>(
         &'p self,
         py: Python<'p>,
-        source: PathBuf,
-        target: PathBuf,
+        source: PyPath,
+        target: PyPath,
     ) -> PyResult<Bound<'p, PyAny>> {
         let this = self.core.clone();
-        let source = source.to_string_lossy().to_string();
-        let target = target.to_string_lossy().to_string();
+        let source = source.0;
+        let target = target.0;
         future_into_py(py, async move {
             this.copy(&source, &target).await.map_err(format_pyerr)
         })
     }
 
-    /// Rename filename
+    /// Copy `source` of `source_op` to `target` of this operator.
+    ///
+    /// # Notes
+    ///
+    /// - The service copies it itself when both operators are built with
+    ///   the same scheme and options apart from `root`, the service
+    ///   supports copy and one root contains both paths.
+    /// - Otherwise the content is streamed from `source_op` into the
+    ///   target, without being collected in memory.
+    /// - `source_op` may be the `AsyncOperator` of another OpenDAL package,
+    ///   which is read through its `open`.
+    pub fn copy_from<'p>(
+        &'p self,
+        py: Python<'p>,
+        source_op: &Bound<'p, PyAny>,
+        source: PyPath,
+        target: PyPath,
+    ) -> PyResult<Bound<'p, PyAny>> {
+        let this = self.core.clone();
+        let source = source.0;
+        let target = target.0;
+        let Ok(source_op) = source_op.downcast::<AsyncOperator>() else {
+            check_foreign_operator(source_op, "AsyncOperator")?;
+            // Open it here, where the event loop of the caller is running.
+            let open = foreign_future(source_op.call_method1("open", (&source, "rb"))?)?;
+            return future_into_py(py, copy_from_foreign(open, this, target));
+        };
+        let source_op = source_op.borrow();
+        let src = source_op.core.clone();
+        let via = server_copy(
+            (source_op.__scheme, &source_op.__map, src.info()),
+            &source,
+            (self.__scheme, &self.__map, this.info()),
+            &target,
+        );
+        future_into_py(py, async move {
+            match via {
+                Some(ServerCopy::OnTarget(source)) => {
+                    this.copy(&source, &target).await.map_err(format_pyerr)
+                }
+                Some(ServerCopy::OnSource(target)) => {
+                    src.copy(&source, &target).await.map_err(format_pyerr)
+                }
+                None => copy_between(src, source, this, target).await,
+            }
+        })
+    }
+
+    /// Rename the source to the target.
+    ///
+    /// # Notes
+    ///
+    /// - `source` and `target` must be files.
+    /// - `target` will be overwritten if it exists.
+    /// - If the service can't rename, `emulate=True` copies to the target,
+    ///   checks the copy against the source and only then deletes the
+    ///   source. This is not atomic, and the source is kept if any step
+    ///   fails.
+    #[pyo3(signature = (source, target, *, emulate = false))]
     pub fn rename<'p>(
         &'p self,
         py: Python<'p>,
-        source: PathBuf,
-        target: PathBuf,
+        source: PyPath,
+        target: PyPath,
+        emulate: bool,
     ) -> PyResult<Bound<'p, PyAny>> {
         let this = self.core.clone();
-        let source = source.to_string_lossy().to_string();
-        let target = target.to_string_lossy().to_string();
+        let source = source.0;
+        let target = target.0;
+        let emulated = rename_emulated(&this.info(), emulate)?;
         future_into_py(py, async move {
+            if emulated {
+                let src = this.stat(&source).await.map_err(format_pyerr)?;
+                this.copy(&source, &target).await.map_err(format_pyerr)?;
+                let dst = this.stat(&target).await.map_err(format_pyerr)?;
+                verify_copy(&source, &target, &src, &dst)?;
+                return this.delete(&source).await.map_err(format_pyerr);
+            }
             this.rename(&source, &target).await.map_err(format_pyerr)
         })
     }
 
-    /// Remove all file
-    pub fn remove_all<'p>(&'p self, py: Python<'p>, path: PathBuf) -> PyResult<Bound<'p, PyAny>> {
+    /// Replace the tags of the object at `path` with `tags`.
+    ///
+    /// Tags missing from `tags` are removed, so an empty dict clears them.
+    pub fn put_tags<'p>(
+        &'p self,
+        py: Python<'p>,
+        path: PyPath,
+        tags: HashMap<String, String>,
+    ) -> PyResult<Bound<'p, PyAny>> {
+        let this = self.core.clone();
+        check_tags(&this.info(), &tags)?;
+        let path = path.0;
+        future_into_py(py, async move {
+            this.put_tags(&path, tags).await.map_err(format_pyerr)
+        })
+    }
+
+    /// Get the tags of the object at `path`, empty if it has none.
+    pub fn get_tags<'p>(&'p self, py: Python<'p>, path: PyPath) -> PyResult<Bound<'p, PyAny>> {
         let this = self.core.clone();
-        let path = path.to_string_lossy().to_string();
+        check_tagging(&this.info())?;
+        let path = path.0;
         future_into_py(py, async move {
-            this.remove_all(&path).await.map_err(format_pyerr)
+            this.get_tags(&path).await.map_err(format_pyerr)
         })
     }
 
+    /// Remove all files under the given path recursively.
+    ///
+    /// Return how many paths were deleted. With `dry_run`, nothing is
+    /// deleted and the paths that would be are returned instead.
+    ///
+    /// If `on_delete` is given, it's called with the list of paths of each
+    /// batch once it's deleted. An exception raised by it stops the removal
+    /// and propagates, leaving the remaining paths in place.
+    ///
+    /// # Notes
+    ///
+    /// - Batch delete is used if the service supports it.
+    /// - Removing a path that doesn't exist won't return errors.
+    #[pyo3(signature = (path, *, dry_run = false, on_delete = None))]
+    pub fn remove_all<'p>(
+        &'p self,
+        py: Python<'p>,
+        path: PyPath,
+        dry_run: bool,
+        on_delete: Option<PyObject>,
+    ) -> PyResult<Bound<'p, PyAny>> {
+        let this = self.core.clone();
+        let state = RemoveAll::new(&this.info(), dry_run, on_delete)?;
+        future_into_py(py, remove_all(this, path.0, state))
+    }
+
     /// Check if this operator can work correctly.
+    ///
+    /// This lists the root with a limit of 1, so misconfigured endpoints or
+    /// credentials surface here instead of on the first real request. A
+    /// missing root isn't an error.
     pub fn check<'p>(&'p self, py: Python<'p>) -> PyResult<Bound<'p, PyAny>> {
         let this = self.core.clone();
         future_into_py(py, async move { this.check().await.map_err(format_pyerr) })
@@ -548,16 +3556,18 @@ impl AsyncOperator {
     /// # Notes
     ///
     /// To indicate that a path is a directory, it is compulsory to include
-    /// a trailing / in the path. Failure to do so may result in
-    /// `NotADirectory` error being returned by OpenDAL.
+    /// a trailing / in the path, otherwise `NotADirectory` is raised. Pass a
+    /// `str`, since `pathlib.Path` drops the trailing /.
     ///
     /// # Behavior
     ///
     /// - Create on existing dir will succeed.
     /// - Create dir is always recursive, works like `mkdir -p`
-    pub fn create_dir<'p>(&'p self, py: Python<'p>, path: PathBuf) -> PyResult<Bound<'p, PyAny>> {
+    /// - On services where directories are virtual, like object stores, an
+    ///   empty object marks the directory so that it can be listed.
+    pub fn create_dir<'p>(&'p self, py: Python<'p>, path: PyPath) -> PyResult<Bound<'p, PyAny>> {
         let this = self.core.clone();
-        let path = path.to_string_lossy().to_string();
+        let path = path.0;
         future_into_py(py, async move {
             this.create_dir(&path).await.map_err(format_pyerr)
         })
@@ -567,14 +3577,50 @@ impl AsyncOperator {
     ///
     /// # Notes
     ///
-    /// - Delete not existing error won't return errors.
-    pub fn delete<'p>(&'p self, py: Python<'p>, path: PathBuf) -> PyResult<Bound<'p, PyAny>> {
+    /// - Deleting a path that doesn't exist succeeds, unless `missing_ok` is
+    ///   false, which raises `NotFound`. Services whose delete doesn't report
+    ///   a missing path stat it first, which can miss a concurrent delete.
+    /// - Pass `version` to delete that version instead of the current one, if
+    ///   the service supports versioning.
+    #[pyo3(signature = (path, *, version = None, missing_ok = true))]
+    pub fn delete<'p>(
+        &'p self,
+        py: Python<'p>,
+        path: PyPath,
+        version: Option<String>,
+        missing_ok: bool,
+    ) -> PyResult<Bound<'p, PyAny>> {
         let this = self.core.clone();
-        let path = path.to_string_lossy().to_string();
-        future_into_py(
-            py,
-            async move { this.delete(&path).await.map_err(format_pyerr) },
-        )
+        let path = path.0;
+        future_into_py(py, async move {
+            if check_missing(&this.info(), missing_ok) {
+                missing_result(
+                    this.stat_options(&path, version_stat_options(&version))
+                        .await,
+                )?;
+            }
+            let res = this
+                .delete_options(&path, ocore::options::DeleteOptions { version })
+                .await;
+            delete_result(res, missing_ok)
+        })
+    }
+
+    /// Delete every path of the given iterable, batching requests if the
+    /// service supports it.
+    ///
+    /// # Notes
+    ///
+    /// - A failed path doesn't stop the others, it's reported in the
+    ///   returned `DeleteResult` instead.
+    pub fn delete_iter<'p>(
+        &'p self,
+        py: Python<'p>,
+        paths: &Bound<PyAny>,
+    ) -> PyResult<Bound<'p, PyAny>> {
+        let paths = extract_paths(paths)?;
+        let this = self.core.clone();
+        future_into_py(py, delete_paths(this, paths))
     }
 
     /// Check given path is exists.
@@ -582,9 +3628,10 @@ impl AsyncOperator {
     /// # Notes
     ///
     /// - Check not existing path won't return errors.
-    pub fn exists<'p>(&'p self, py: Python<'p>, path: PathBuf) -> PyResult<Bound<'p, PyAny>> {
+    /// - Other errors, like `PermissionDenied`, are still raised.
+    pub fn exists<'p>(&'p self, py: Python<'p>, path: PyPath) -> PyResult<Bound<'p, PyAny>> {
         let this = self.core.clone();
-        let path = path.to_string_lossy().to_string();
+        let path = path.0;
         future_into_py(
             py,
             async move { this.exists(&path).await.map_err(format_pyerr) },
@@ -592,26 +3639,42 @@ impl AsyncOperator {
     }
 
     /// List current dir path.
-    #[pyo3(signature = (path, **kwargs))]
+    ///
+    /// Entries carry the metadata the service returns while listing, which
+    /// differs per service. Use `stat` for anything that's missing.
+    ///
+    /// If `timeout` is given, as seconds or a `datetime.timedelta`, listing
+    /// raises `Unexpected` once it's exceeded, cancelling the request. The
+    /// deadline covers iterating the returned lister too.
+    #[pyo3(signature = (path, *, timeout = None, **kwargs))]
     pub fn list<'p>(
         &'p self,
         py: Python<'p>,
-        path: PathBuf,
+        path: PyPath,
+        timeout: Option<PyDuration>,
         kwargs: Option<&Bound<PyDict>>,
     ) -> PyResult<Bound<'p, PyAny>> {
-        let this = self.core.clone();
-        let path = path.to_string_lossy().to_string();
+        let this = with_deadline(self.core.clone(), timeout);
+        let path = path.0;
         let kwargs = kwargs
             .map(|v| v.extract::<ListOptions>())
             .transpose()?
             .unwrap_or_default();
+        kwargs.check(&this.info())?;
 
+        let limit = kwargs.limit;
+        let dirs_only = kwargs.dirs_only.unwrap_or_default();
         future_into_py(py, async move {
             let lister = this
                 .lister_options(&path, kwargs.into())
                 .await
                 .map_err(format_pyerr)?;
-            let pylister = Python::with_gil(|py| AsyncLister::new(lister).into_py_any(py))?;
+            let lister = AsyncLister::new(lister, limit);
+            let lister = match dirs_only {
+                true => lister.with_filter(ListFilter::Dirs(path)),
+                false => lister,
+            };
+            let pylister = Python::with_gil(|py| lister.into_py_any(py))?;
 
             Ok(pylister)
         })
@@ -622,28 +3685,95 @@ impl AsyncOperator {
     pub fn scan<'p>(
         &'p self,
         py: Python<'p>,
-        path: PathBuf,
+        path: PyPath,
         kwargs: Option<&Bound<PyDict>>,
     ) -> PyResult<Bound<'p, PyAny>> {
         let d = PyDict::new(py);
         let kwargs = kwargs.unwrap_or(&d);
         kwargs.set_item("recursive", true)?;
 
-        self.list(py, path, Some(kwargs))
+        self.list(py, path, None, Some(kwargs))
+    }
+
+    /// List the entries matching a glob `pattern`, like
+    /// `logs/2024-*/**.json`.
+    ///
+    /// Only the literal prefix before the first wildcard is listed,
+    /// recursively if the rest of the pattern spans directories, and the
+    /// entries are filtered while iterating. `*`, `?` and `[...]` match
+    /// within a directory, `**` crosses directory boundaries and `**/` also
+    /// matches no directory at all. Directories match without their trailing
+    /// `/`.
+    pub fn glob<'p>(&'p self, py: Python<'p>, pattern: &str) -> PyResult<Bound<'p, PyAny>> {
+        let this = self.core.clone();
+        let glob = Glob::new(pattern)?;
+        future_into_py(py, async move {
+            let opts = ocore::options::ListOptions {
+                recursive: glob.recursive(),
+                ..Default::default()
+            };
+            let lister = this
+                .lister_options(glob.prefix(), opts)
+                .await
+                .map_err(format_pyerr)?;
+            Python::with_gil(|py| {
+                AsyncLister::new(lister, None)
+                    .with_filter(ListFilter::Glob(glob))
+                    .into_py_any(py)
+            })
+        })
+    }
+
+    /// Return the entries below the directory `path` as a tree.
+    ///
+    /// Each node is a dict with the `name` and `path` of the entry. Files
+    /// carry their `metadata`, directories a list of `children` sorted by
+    /// name. Without `max_depth` the whole tree is fetched with one recursive
+    /// listing. With it, directories are listed level by level, and the
+    /// directories `max_depth` levels deep get `None` for `children`.
+    #[pyo3(signature = (path, *, max_depth = None))]
+    pub fn tree<'p>(
+        &'p self,
+        py: Python<'p>,
+        path: PyPath,
+        max_depth: Option<usize>,
+    ) -> PyResult<Bound<'p, PyAny>> {
+        let this = self.core.clone();
+        let mut tree = Tree::new(&path.0, max_depth)?;
+        future_into_py(py, async move {
+            let mut dirs = vec![tree.root().to_string()];
+            while let Some(dir) = dirs.pop() {
+                let opts = ocore::options::ListOptions {
+                    recursive: max_depth.is_none(),
+                    ..Default::default()
+                };
+                let mut lister = this
+                    .lister_options(&dir, opts)
+                    .await
+                    .map_err(format_pyerr)?;
+                while let Some(entry) = lister.try_next().await.map_err(format_pyerr)? {
+                    let path = entry.path().to_string();
+                    if tree.insert(entry) && max_depth.is_some() {
+                        dirs.push(path);
+                    }
+                }
+            }
+            Python::with_gil(|py| tree.into_py(py))
+        })
     }
 
-    /// Presign an operation for stat(head) which expires after `expire_second` seconds.
+    /// Presign an operation for stat(head) which expires after `expire_second`.
     pub fn presign_stat<'p>(
         &'p self,
         py: Python<'p>,
-        path: PathBuf,
-        expire_second: u64,
+        path: PyPath,
+        expire_second: PyDuration,
     ) -> PyResult<Bound<'p, PyAny>> {
         let this = self.core.clone();
-        let path = path.to_string_lossy().to_string();
+        let path = path.0;
         future_into_py(py, async move {
             let res = this
-                .presign_stat(&path, Duration::from_secs(expire_second))
+                .presign_stat(&path, expire_second.0)
                 .await
                 .map_err(format_pyerr)
                 .map(PresignedRequest)?;
@@ -652,18 +3782,18 @@ impl AsyncOperator {
         })
     }
 
-    /// Presign an operation for read which expires after `expire_second` seconds.
+    /// Presign an operation for read which expires after `expire_second`.
     pub fn presign_read<'p>(
         &'p self,
         py: Python<'p>,
-        path: PathBuf,
-        expire_second: u64,
+        path: PyPath,
+        expire_second: PyDuration,
     ) -> PyResult<Bound<'p, PyAny>> {
         let this = self.core.clone();
-        let path = path.to_string_lossy().to_string();
+        let path = path.0;
         future_into_py(py, async move {
             let res = this
-                .presign_read(&path, Duration::from_secs(expire_second))
+                .presign_read(&path, expire_second.0)
                 .await
                 .map_err(format_pyerr)
                 .map(PresignedRequest)?;
@@ -672,18 +3802,18 @@ impl AsyncOperator {
         })
     }
 
-    /// Presign an operation for write which expires after `expire_second` seconds.
+    /// Presign an operation for write which expires after `expire_second`.
     pub fn presign_write<'p>(
         &'p self,
         py: Python<'p>,
-        path: PathBuf,
-        expire_second: u64,
+        path: PyPath,
+        expire_second: PyDuration,
     ) -> PyResult<Bound<'p, PyAny>> {
         let this = self.core.clone();
-        let path = path.to_string_lossy().to_string();
+        let path = path.0;
         future_into_py(py, async move {
             let res = this
-                .presign_write(&path, Duration::from_secs(expire_second))
+                .presign_write(&path, expire_second.0)
                 .await
                 .map_err(format_pyerr)
                 .map(PresignedRequest)?;
@@ -692,18 +3822,18 @@ impl AsyncOperator {
         })
     }
 
-    /// Presign an operation for delete which expires after `expire_second` seconds.
+    /// Presign an operation for delete which expires after `expire_second`.
     pub fn presign_delete<'p>(
         &'p self,
         py: Python<'p>,
-        path: PathBuf,
-        expire_second: u64,
+        path: PyPath,
+        expire_second: PyDuration,
     ) -> PyResult<Bound<'p, PyAny>> {
         let this = self.core.clone();
-        let path = path.to_string_lossy().to_string();
+        let path = path.0;
         future_into_py(py, async move {
             let res = this
-                .presign_delete(&path, Duration::from_secs(expire_second))
+                .presign_delete(&path, expire_second.0)
                 .await
                 .map_err(format_pyerr)
                 .map(PresignedRequest)?;
@@ -712,12 +3842,47 @@ impl AsyncOperator {
         })
     }
 
+    /// Presign `operation`, one of `"read"`, `"write"`, `"stat"` or
+    /// `"delete"`, on the path for `expire`, as seconds or a
+    /// `datetime.timedelta`.
+    ///
+    /// Raise `Unsupported` unless the service can presign the operation.
+    pub fn presign<'p>(
+        &'p self,
+        py: Python<'p>,
+        path: PyPath,
+        operation: &str,
+        expire: PyDuration,
+    ) -> PyResult<Bound<'p, PyAny>> {
+        let operation = PresignOperation::new(&self.core.info(), operation)?;
+        let this = self.core.clone();
+        future_into_py(
+            py,
+            async move { operation.sign(&this, &path.0, expire.0).await },
+        )
+    }
+
     pub fn capability(&self) -> PyResult<capability::Capability> {
         Ok(capability::Capability::new(
             self.core.info().full_capability(),
         ))
     }
 
+    /// Raise `Unsupported` unless the service supports every capability flag
+    /// given as `True`, like `op.require(read=True, presign=True)`.
+    ///
+    /// The error names each missing flag, so an operator can be validated
+    /// upfront instead of failing on its first unsupported call.
+    #[pyo3(signature = (**kwargs))]
+    pub fn require(&self, kwargs: Option<&Bound<PyDict>>) -> PyResult<()> {
+        capability::Capability::require(&self.core.info(), kwargs)
+    }
+
+    /// Get the information of this operator.
+    pub fn info(&self) -> OperatorInfo {
+        OperatorInfo(self.core.info())
+    }
+
     pub fn to_operator(&self) -> PyResult<Operator> {
         let runtime = pyo3_async_runtimes::tokio::get_runtime();
         let _guard = runtime.enter();
@@ -727,32 +3892,97 @@ impl AsyncOperator {
             core: op,
             __scheme: self.__scheme,
             __map: self.__map.clone(),
+            __http: self.__http.clone(),
+            __credential: self.__credential.clone(),
+            __write_defaults: self.__write_defaults.clone(),
+            __layers: self.__layers.clone(),
+            __read_chunk: self.__read_chunk,
         })
     }
 
     fn __repr__(&self) -> String {
-        let info = self.core.info();
-        let name = info.name();
-        if name.is_empty() {
-            format!(
-                "AsyncOperator(\"{}\", root=\"{}\")",
-                info.scheme(),
-                info.root()
-            )
-        } else {
-            format!(
-                "AsyncOperator(\"{}\", root=\"{}\", name=\"{name}\")",
-                info.scheme(),
-                info.root()
-            )
+        operator_repr("AsyncOperator", &self.core.info(), &self.__layers)
+    }
+
+    fn __copy__(&self) -> Self {
+        Self {
+            core: self.core.clone(),
+            __scheme: self.__scheme,
+            __map: self.__map.clone(),
+            __http: self.__http.clone(),
+            __credential: self.__credential.clone(),
+            __write_defaults: self.__write_defaults.clone(),
+            __layers: self.__layers.clone(),
+            __read_chunk: self.__read_chunk,
+        }
+    }
+
+    /// Operators are handles to a service, so a deep copy shares the same
+    /// service and layers just like a shallow one.
+    fn __deepcopy__(&self, _memo: &Bound<PyAny>) -> Self {
+        self.__copy__()
+    }
+
+    /// Rebuild through `opendal.AsyncOperator`, which routes the scheme to the
+    /// package providing it, since this class isn't reachable by name.
+    fn __reduce__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        reduce_operator(
+            py,
+            "AsyncOperator",
+            self.__scheme,
+            &self.__map,
+            self.__http.as_ref(),
+            self.__credential.as_ref(),
+        )
+    }
+}
+
+/// An operation `presign` can sign, checked against the capability of the
+/// service before signing.
+#[derive(Clone, Copy)]
+enum PresignOperation {
+    Read,
+    Write,
+    Stat,
+    Delete,
+}
+
+impl PresignOperation {
+    fn new(info: &ocore::OperatorInfo, operation: &str) -> PyResult<Self> {
+        let cap = info.full_capability();
+        let (op, supported) = match operation {
+            "read" => (Self::Read, cap.presign_read),
+            "write" => (Self::Write, cap.presign_write),
+            "stat" => (Self::Stat, cap.presign_stat),
+            "delete" => (Self::Delete, cap.presign_delete),
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                "operation must be \"read\", \"write\", \"stat\" or \"delete\", not {operation:?}"
+            )))
+            }
+        };
+        if !(cap.presign && supported) {
+            return Err(Unsupported::new_err(format!(
+                "service {} doesn't support presign {operation}",
+                info.scheme()
+            )));
         }
+        Ok(op)
     }
 
-    fn __getnewargs_ex__(&self, py: Python) -> PyResult<PyObject> {
-        let args = vec![self.__scheme.to_string()];
-        let args = PyTuple::new(py, args)?.into_py_any(py)?;
-        let kwargs = self.__map.clone().into_py_any(py)?;
-        PyTuple::new(py, [args, kwargs])?.into_py_any(py)
+    async fn sign(
+        self,
+        op: &ocore::Operator,
+        path: &str,
+        expire: std::time::Duration,
+    ) -> PyResult<PresignedRequest> {
+        let req = match self {
+            Self::Read => op.presign_read(path, expire).await,
+            Self::Write => op.presign_write(path, expire).await,
+            Self::Stat => op.presign_stat(path, expire).await,
+            Self::Delete => op.presign_delete(path, expire).await,
+        };
+        req.map_err(format_pyerr).map(PresignedRequest)
     }
 }
 
@@ -789,3 +4019,77 @@ impl PresignedRequest {
         Ok(headers)
     }
 }
+
+/// The outcome of `delete_iter`.
+#[pyclass(module = "opendal")]
+pub struct DeleteResult {
+    deleted: Vec<String>,
+    failed: Vec<(String, Py<PyBaseException>)>,
+}
+
+#[pymethods]
+impl DeleteResult {
+    /// Return the paths that have been deleted.
+    #[getter]
+    pub fn deleted(&self) -> Vec<String> {
+        self.deleted.clone()
+    }
+
+    /// Return the paths that failed to delete, mapped to their exception.
+    #[getter]
+    pub fn failed<'p>(&self, py: Python<'p>) -> PyResult<Bound<'p, PyDict>> {
+        let dict = PyDict::new(py);
+        for (path, err) in &self.failed {
+            dict.set_item(path, err.clone_ref(py))?;
+        }
+        Ok(dict)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "DeleteResult(deleted={}, failed={})",
+            self.deleted.len(),
+            self.failed.len()
+        )
+    }
+}
+
+/// The information of an operator: its scheme, root, name and capability.
+#[pyclass(module = "opendal")]
+pub struct OperatorInfo(ocore::OperatorInfo);
+
+#[pymethods]
+impl OperatorInfo {
+    /// Return the scheme of this operator.
+    #[getter]
+    pub fn scheme(&self) -> &'static str {
+        self.0.scheme().into_static()
+    }
+
+    /// Return the root of this operator.
+    #[getter]
+    pub fn root(&self) -> String {
+        self.0.root()
+    }
+
+    /// Return the name of this operator, e.g. the bucket for s3.
+    #[getter]
+    pub fn name(&self) -> String {
+        self.0.name()
+    }
+
+    /// Return the full capability of this operator.
+    #[getter]
+    pub fn capability(&self) -> capability::Capability {
+        capability::Capability::new(self.0.full_capability())
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "OperatorInfo(scheme=\"{}\", root=\"{}\", name=\"{}\")",
+            self.0.scheme(),
+            self.0.root(),
+            self.0.name()
+        )
+    }
+}
@@ -17,10 +17,27 @@
 
 use dict_derive::FromPyObject;
 use opendal::{self as ocore, raw::BytesRange};
-use pyo3::pyclass;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
 use std::collections::HashMap;
 
-use chrono::{DateTime, Utc};
+use crate::PyDuration;
+use crate::Unsupported;
+
+use chrono::{DateTime, FixedOffset, Utc};
+
+/// Size of the ranged reads a concurrent read is split into by default.
+const READ_CHUNK: usize = 8 * 1024 * 1024;
+
+/// Size of the parts a multipart write is split into by default.
+const WRITE_PART: usize = 8 * 1024 * 1024;
+
+/// Number of parts a multipart write uploads at once by default.
+const WRITE_CONCURRENT: usize = 4;
+
+/// Most parts a multipart upload can have, as on S3.
+const WRITE_MAX_PARTS: usize = 10_000;
 
 #[pyclass(module = "opendal")]
 #[derive(FromPyObject, Default)]
@@ -31,13 +48,15 @@ pub struct ReadOptions {
     pub gap: Option<usize>,
     pub offset: Option<usize>,
     pub size: Option<usize>,
+    pub range: Option<(usize, usize)>,
     pub if_match: Option<String>,
     pub if_none_match: Option<String>,
-    pub if_modified_since: Option<DateTime<Utc>>,
-    pub if_unmodified_since: Option<DateTime<Utc>>,
+    pub if_modified_since: Option<DateTime<FixedOffset>>,
+    pub if_unmodified_since: Option<DateTime<FixedOffset>>,
     pub content_type: Option<String>,
     pub cache_control: Option<String>,
     pub content_disposition: Option<String>,
+    pub extra_headers: Option<HashMap<String, String>>,
 }
 
 impl ReadOptions {
@@ -47,6 +66,77 @@ impl ReadOptions {
 
         BytesRange::new(offset, size)
     }
+
+    /// Validate the options that size the prefetch of a chunked read, and
+    /// fold `range=(start, end)` into `offset` and `size`.
+    ///
+    /// A zero chunk would never make progress, so it's rejected here instead
+    /// of hanging the reader.
+    pub fn check(&mut self) -> PyResult<()> {
+        if let Some((start, end)) = self.range.take() {
+            if self.has_range() {
+                return Err(PyValueError::new_err(
+                    "range can't be used with offset or size",
+                ));
+            }
+            if start > end {
+                return Err(PyValueError::new_err(format!(
+                    "range start {start} is after its end {end}"
+                )));
+            }
+            self.offset = Some(start);
+            self.size = Some(end - start);
+        }
+        if self.concurrent == Some(0) {
+            return Err(PyValueError::new_err(
+                "concurrent must be a positive integer",
+            ));
+        }
+        if self.chunk == Some(0) {
+            return Err(PyValueError::new_err("chunk must be a positive integer"));
+        }
+        // Only chunked reads run concurrently, so split the object into
+        // ranged reads when just `concurrent` is given.
+        if self.concurrent.is_some_and(|v| v > 1) && self.chunk.is_none() {
+            self.chunk = Some(READ_CHUNK);
+        }
+        Ok(())
+    }
+
+    /// Use the operator's default `chunk` if the call didn't give one.
+    pub fn with_default_chunk(mut self, chunk: Option<usize>) -> Self {
+        self.chunk = self.chunk.or(chunk);
+        self
+    }
+
+    /// Whether the caller asked for a byte range instead of the whole object.
+    pub fn has_range(&self) -> bool {
+        self.offset.is_some() || self.size.is_some()
+    }
+
+    /// Stat options used to learn the object size to clamp a range read to.
+    pub fn stat_options(&self) -> ocore::options::StatOptions {
+        ocore::options::StatOptions {
+            version: self.version.clone(),
+            if_match: self.if_match.clone(),
+            if_none_match: self.if_none_match.clone(),
+            if_modified_since: self.if_modified_since.map(|v| v.with_timezone(&Utc)),
+            if_unmodified_since: self.if_unmodified_since.map(|v| v.with_timezone(&Utc)),
+            ..Default::default()
+        }
+    }
+
+    /// Clamp the requested range to `content_length`, following HTTP range
+    /// semantics. Returns `false` if nothing is left to read.
+    pub fn clamp_range(&mut self, content_length: usize) -> bool {
+        let offset = self.offset.unwrap_or_default().min(content_length);
+        let remaining = content_length - offset;
+        let size = self.size.map_or(remaining, |v| v.min(remaining));
+
+        self.offset = Some(offset);
+        self.size = Some(size);
+        size > 0
+    }
 }
 
 #[pyclass(module = "opendal")]
@@ -63,6 +153,205 @@ pub struct WriteOptions {
     pub if_none_match: Option<String>,
     pub if_not_exists: Option<bool>,
     pub user_metadata: Option<HashMap<String, String>>,
+    pub extra_headers: Option<HashMap<String, String>>,
+    pub strategy: Option<WriteStrategy>,
+}
+
+/// How a write sends the content to the service.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum WriteStrategy {
+    /// A single request for content fitting in one part, a multipart upload
+    /// for larger content or content of unknown length.
+    #[default]
+    Auto,
+    /// A single request, buffering content of unknown length first.
+    Single,
+    /// A multipart upload of `chunk` sized parts.
+    Multipart,
+}
+
+impl<'py> FromPyObject<'py> for WriteStrategy {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        match ob.extract::<String>()?.as_str() {
+            "auto" => Ok(Self::Auto),
+            "single" => Ok(Self::Single),
+            "multipart" => Ok(Self::Multipart),
+            other => Err(PyValueError::new_err(format!(
+                "strategy must be \"auto\", \"single\" or \"multipart\", not {other:?}"
+            ))),
+        }
+    }
+}
+
+impl WriteOptions {
+    /// Fill the options that weren't given from `defaults`, so per-call
+    /// options take precedence. `user_metadata` and `extra_headers` are
+    /// merged key by key.
+    pub fn with_defaults(self, defaults: &WriteOptions) -> Self {
+        let user_metadata = match (&defaults.user_metadata, self.user_metadata) {
+            (Some(meta), Some(own)) => {
+                let mut meta = meta.clone();
+                meta.extend(own);
+                Some(meta)
+            }
+            (meta, own) => own.or_else(|| meta.clone()),
+        };
+        let extra_headers = match (&defaults.extra_headers, self.extra_headers) {
+            (Some(headers), Some(own)) => {
+                let mut headers = headers.clone();
+                headers.extend(own);
+                Some(headers)
+            }
+            (headers, own) => own.or_else(|| headers.clone()),
+        };
+        Self {
+            append: self.append.or(defaults.append),
+            chunk: self.chunk.or(defaults.chunk),
+            concurrent: self.concurrent.or(defaults.concurrent),
+            cache_control: self
+                .cache_control
+                .or_else(|| defaults.cache_control.clone()),
+            content_type: self.content_type.or_else(|| defaults.content_type.clone()),
+            content_disposition: self
+                .content_disposition
+                .or_else(|| defaults.content_disposition.clone()),
+            content_encoding: self
+                .content_encoding
+                .or_else(|| defaults.content_encoding.clone()),
+            if_match: self.if_match.or_else(|| defaults.if_match.clone()),
+            if_none_match: self
+                .if_none_match
+                .or_else(|| defaults.if_none_match.clone()),
+            if_not_exists: self.if_not_exists.or(defaults.if_not_exists),
+            user_metadata,
+            extra_headers,
+            strategy: self.strategy.or(defaults.strategy),
+        }
+    }
+
+    /// Reject append, metadata, condition and part size options the service
+    /// can't honor instead of silently truncating, dropping or clamping them.
+    pub fn check(&self, info: &ocore::OperatorInfo) -> PyResult<()> {
+        let cap = info.full_capability();
+        let unsupported = [
+            (
+                "append",
+                self.append.unwrap_or_default() && !cap.write_can_append,
+            ),
+            (
+                "content_type",
+                self.content_type.is_some() && !cap.write_with_content_type,
+            ),
+            (
+                "cache_control",
+                self.cache_control.is_some() && !cap.write_with_cache_control,
+            ),
+            (
+                "content_disposition",
+                self.content_disposition.is_some() && !cap.write_with_content_disposition,
+            ),
+            (
+                "content_encoding",
+                self.content_encoding.is_some() && !cap.write_with_content_encoding,
+            ),
+            (
+                "user_metadata",
+                self.user_metadata.is_some() && !cap.write_with_user_metadata,
+            ),
+            (
+                "if_match",
+                self.if_match.is_some() && !cap.write_with_if_match,
+            ),
+            (
+                "if_none_match",
+                self.if_none_match.is_some() && !cap.write_with_if_none_match,
+            ),
+            (
+                "if_not_exists",
+                self.if_not_exists.unwrap_or_default() && !cap.write_with_if_not_exists,
+            ),
+            (
+                "strategy \"multipart\"",
+                self.strategy == Some(WriteStrategy::Multipart) && !cap.write_can_multi,
+            ),
+        ];
+
+        if let Some((name, _)) = unsupported.iter().find(|(_, v)| *v) {
+            return Err(Unsupported::new_err(format!(
+                "service {} doesn't support write with {name}",
+                info.scheme()
+            )));
+        }
+
+        if self.append.unwrap_or_default()
+            && self.strategy.is_some_and(|v| v != WriteStrategy::Auto)
+        {
+            return Err(PyValueError::new_err("strategy can't be used with append"));
+        }
+        if self.concurrent == Some(0) {
+            return Err(PyValueError::new_err(
+                "concurrent must be a positive integer",
+            ));
+        }
+        if let Some(chunk) = self.chunk {
+            if chunk == 0 {
+                return Err(PyValueError::new_err("chunk must be a positive integer"));
+            }
+            // The core silently clamps the chunk size, which hides mistakes
+            // like passing a size in MiB instead of bytes.
+            if let Some(min) = cap.write_multi_min_size.filter(|min| chunk < *min) {
+                return Err(PyValueError::new_err(format!(
+                    "chunk {chunk} is smaller than the minimum part size {min} of service {}",
+                    info.scheme()
+                )));
+            }
+            if let Some(max) = cap.write_multi_max_size.filter(|max| chunk > *max) {
+                return Err(PyValueError::new_err(format!(
+                    "chunk {chunk} is larger than the maximum part size {max} of service {}",
+                    info.scheme()
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Apply `strategy` to a write of `len` bytes, `None` if the length
+    /// isn't known up front, filling `chunk` and `concurrent` for a
+    /// multipart upload.
+    ///
+    /// Returns whether the content must be handed to the writer in one
+    /// piece, which the writer then sends in a single request. Services
+    /// that can't upload in parts reject a second piece, so `auto` only
+    /// splits the content for the ones that can.
+    pub fn plan(&mut self, info: &ocore::OperatorInfo, len: Option<usize>) -> bool {
+        // Appends are sent as they're written.
+        if self.append.unwrap_or_default() {
+            return false;
+        }
+        let cap = info.full_capability();
+        let part = self.chunk.unwrap_or_else(|| {
+            // Grow the parts of huge content to stay within the part limit.
+            let part = len.map_or(WRITE_PART, |len| {
+                WRITE_PART.max(len.div_ceil(WRITE_MAX_PARTS))
+            });
+            let part = cap.write_multi_max_size.map_or(part, |max| part.min(max));
+            cap.write_multi_min_size.map_or(part, |min| part.max(min))
+        });
+        let multipart = match self.strategy.unwrap_or_default() {
+            WriteStrategy::Auto => cap.write_can_multi && len.is_none_or(|len| len > part),
+            WriteStrategy::Single => false,
+            WriteStrategy::Multipart => true,
+        };
+        if multipart {
+            self.chunk = Some(part);
+            self.concurrent.get_or_insert(WRITE_CONCURRENT);
+        } else {
+            // The writer would split the content again at `chunk`.
+            self.chunk = None;
+        }
+        !multipart
+    }
 }
 
 impl From<ReadOptions> for ocore::options::ReadOptions {
@@ -72,8 +361,8 @@ impl From<ReadOptions> for ocore::options::ReadOptions {
             version: opts.version,
             if_match: opts.if_match,
             if_none_match: opts.if_none_match,
-            if_modified_since: opts.if_modified_since,
-            if_unmodified_since: opts.if_unmodified_since,
+            if_modified_since: opts.if_modified_since.map(|v| v.with_timezone(&Utc)),
+            if_unmodified_since: opts.if_unmodified_since.map(|v| v.with_timezone(&Utc)),
             concurrent: opts.concurrent.unwrap_or_default(),
             chunk: opts.chunk,
             gap: opts.gap,
@@ -90,8 +379,8 @@ impl From<ReadOptions> for ocore::options::ReaderOptions {
             version: opts.version,
             if_match: opts.if_match,
             if_none_match: opts.if_none_match,
-            if_modified_since: opts.if_modified_since,
-            if_unmodified_since: opts.if_unmodified_since,
+            if_modified_since: opts.if_modified_since.map(|v| v.with_timezone(&Utc)),
+            if_unmodified_since: opts.if_unmodified_since.map(|v| v.with_timezone(&Utc)),
             concurrent: opts.concurrent.unwrap_or_default(),
             chunk: opts.chunk,
             gap: opts.gap,
@@ -103,6 +392,7 @@ impl From<WriteOptions> for ocore::options::WriteOptions {
     fn from(opts: WriteOptions) -> Self {
         Self {
             append: opts.append.unwrap_or(false),
+            offset: None,
             concurrent: opts.concurrent.unwrap_or_default(),
             chunk: opts.chunk,
             content_type: opts.content_type,
@@ -125,6 +415,46 @@ pub struct ListOptions {
     pub recursive: Option<bool>,
     pub versions: Option<bool>,
     pub deleted: Option<bool>,
+    /// Only yield the directories in the listed one, like the common
+    /// prefixes of an object store.
+    pub dirs_only: Option<bool>,
+}
+
+impl ListOptions {
+    /// Reject a `start_after` the service would silently ignore, which would
+    /// make pages overlap, and `versions` or `deleted` the service can't list.
+    pub fn check(&self, info: &ocore::OperatorInfo) -> PyResult<()> {
+        if self.limit == Some(0) {
+            return Err(PyValueError::new_err("limit must be a positive integer"));
+        }
+        if self.dirs_only.unwrap_or_default() && self.recursive.unwrap_or_default() {
+            return Err(PyValueError::new_err(
+                "dirs_only lists a single level and can't be recursive",
+            ));
+        }
+        let cap = info.full_capability();
+        let unsupported = [
+            (
+                "start_after",
+                self.start_after.is_some() && !cap.list_with_start_after,
+            ),
+            (
+                "versions",
+                self.versions.unwrap_or_default() && !cap.list_with_versions,
+            ),
+            (
+                "deleted",
+                self.deleted.unwrap_or_default() && !cap.list_with_deleted,
+            ),
+        ];
+        if let Some((name, _)) = unsupported.iter().find(|(_, v)| *v) {
+            return Err(Unsupported::new_err(format!(
+                "service {} doesn't support list with {name}",
+                info.scheme()
+            )));
+        }
+        Ok(())
+    }
 }
 
 impl From<ListOptions> for ocore::options::ListOptions {
@@ -145,11 +475,12 @@ pub struct StatOptions {
     pub version: Option<String>,
     pub if_match: Option<String>,
     pub if_none_match: Option<String>,
-    pub if_modified_since: Option<DateTime<Utc>>,
-    pub if_unmodified_since: Option<DateTime<Utc>>,
+    pub if_modified_since: Option<DateTime<FixedOffset>>,
+    pub if_unmodified_since: Option<DateTime<FixedOffset>>,
     pub content_type: Option<String>,
     pub cache_control: Option<String>,
     pub content_disposition: Option<String>,
+    pub extra_headers: Option<HashMap<String, String>>,
 }
 
 impl From<StatOptions> for ocore::options::StatOptions {
@@ -158,11 +489,75 @@ impl From<StatOptions> for ocore::options::StatOptions {
             version: opts.version,
             if_match: opts.if_match,
             if_none_match: opts.if_none_match,
-            if_modified_since: opts.if_modified_since,
-            if_unmodified_since: opts.if_unmodified_since,
+            if_modified_since: opts.if_modified_since.map(|v| v.with_timezone(&Utc)),
+            if_unmodified_since: opts.if_unmodified_since.map(|v| v.with_timezone(&Utc)),
             override_content_type: opts.content_type,
             override_cache_control: opts.cache_control,
             override_content_disposition: opts.content_disposition,
         }
     }
 }
+
+/// Settings of the HTTP client used by HTTP based services, like a proxy or a
+/// private CA. Services that don't speak HTTP ignore them.
+#[derive(FromPyObject, Default, Clone)]
+pub struct HttpOptions {
+    pub connect_timeout: Option<PyDuration>,
+    pub pool_max_idle_per_host: Option<usize>,
+    pub proxy_url: Option<String>,
+    pub ca_cert_path: Option<String>,
+}
+
+impl HttpOptions {
+    pub fn build(&self) -> PyResult<ocore::raw::HttpClient> {
+        let mut builder = reqwest::Client::builder();
+        if let Some(timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(timeout.0);
+        }
+        if let Some(max) = self.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(max);
+        }
+        if let Some(url) = &self.proxy_url {
+            let proxy = reqwest::Proxy::all(url)
+                .map_err(|err| PyValueError::new_err(format!("invalid proxy_url {url}: {err}")))?;
+            builder = builder.proxy(proxy);
+        }
+        if let Some(path) = &self.ca_cert_path {
+            let pem = std::fs::read(path).map_err(|err| {
+                std::io::Error::new(err.kind(), format!("read ca_cert_path {path}: {err}"))
+            })?;
+            let certs = reqwest::Certificate::from_pem_bundle(&pem)
+                .ok()
+                .filter(|certs| !certs.is_empty())
+                .ok_or_else(|| {
+                    PyValueError::new_err(format!("no PEM certificate in ca_cert_path {path}"))
+                })?;
+            for cert in certs {
+                builder = builder.add_root_certificate(cert);
+            }
+        }
+
+        let client = builder
+            .build()
+            .map_err(|err| PyValueError::new_err(format!("invalid http_options: {err}")))?;
+        Ok(ocore::raw::HttpClient::with(client))
+    }
+
+    /// The options as the dict they were given as, to pickle an operator.
+    pub fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new(py);
+        if let Some(timeout) = self.connect_timeout {
+            dict.set_item("connect_timeout", timeout.0.as_secs_f64())?;
+        }
+        if let Some(max) = self.pool_max_idle_per_host {
+            dict.set_item("pool_max_idle_per_host", max)?;
+        }
+        if let Some(url) = &self.proxy_url {
+            dict.set_item("proxy_url", url)?;
+        }
+        if let Some(path) = &self.ca_cert_path {
+            dict.set_item("ca_cert_path", path)?;
+        }
+        Ok(dict)
+    }
+}
@@ -15,21 +15,53 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::cell::Cell;
+use std::mem::MaybeUninit;
 use std::os::raw::c_int;
+use std::time::Duration;
 
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use bytes::Bytes;
+use md5::Digest;
+use md5::Md5;
+use pyo3::buffer::PyBuffer;
+use pyo3::exceptions::PyStopAsyncIteration;
+use pyo3::exceptions::PyTypeError;
+use pyo3::exceptions::PyValueError;
 use pyo3::ffi;
 use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+use pyo3::types::PyDelta;
+use pyo3::types::PyIterator;
+use pyo3::types::PyString;
 use pyo3::IntoPyObjectExt;
 
+use crate::*;
+
 /// A bytes-like object that implements buffer protocol.
+///
+/// It shares the memory it was built from, which stays alive as long as the
+/// object or any view on it does.
 #[pyclass(module = "opendal")]
 pub struct Buffer {
-    inner: Vec<u8>,
+    inner: Bytes,
 }
 
 impl Buffer {
     pub fn new(inner: Vec<u8>) -> Self {
-        Buffer { inner }
+        Buffer {
+            inner: inner.into(),
+        }
+    }
+
+    /// Consume self to build a bytes, or expose self directly if `zero_copy`.
+    pub fn into_bytes_or_buffer(self, py: Python, zero_copy: bool) -> PyResult<Py<PyAny>> {
+        if zero_copy {
+            self.into_py_any(py)
+        } else {
+            self.into_bytes(py)
+        }
     }
 
     /// Consume self to build a bytes
@@ -49,14 +81,27 @@ impl Buffer {
     }
 }
 
+impl From<ocore::Buffer> for Buffer {
+    /// Only non-contiguous buffers are copied.
+    fn from(buffer: ocore::Buffer) -> Self {
+        Buffer {
+            inner: buffer.to_bytes(),
+        }
+    }
+}
+
 #[pymethods]
 impl Buffer {
+    fn __len__(&self) -> usize {
+        self.inner.len()
+    }
+
     unsafe fn __getbuffer__(
         slf: PyRefMut<Self>,
         view: *mut ffi::Py_buffer,
         flags: c_int,
     ) -> PyResult<()> {
-        let bytes = slf.inner.as_slice();
+        let bytes = slf.inner.as_ref();
         let ret = ffi::PyBuffer_FillInfo(
             view,
             slf.as_ptr() as *mut _,
@@ -71,3 +116,492 @@ impl Buffer {
         Ok(())
     }
 }
+
+/// A read-only, seekable file-like object over the content of an object,
+/// like `io.BytesIO` but sharing the memory it was read into.
+///
+/// Created by `read_stream`. The content stays alive as long as the
+/// object does.
+#[pyclass(module = "opendal")]
+pub struct BufferReader {
+    inner: Bytes,
+    pos: usize,
+    closed: bool,
+}
+
+impl BufferReader {
+    pub fn new(buffer: ocore::Buffer) -> Self {
+        BufferReader {
+            inner: buffer.to_bytes(),
+            pos: 0,
+            closed: false,
+        }
+    }
+
+    /// The content from the current position to the end.
+    fn rest(&self) -> PyResult<&[u8]> {
+        if self.closed {
+            return Err(PyValueError::new_err("I/O operation on closed file."));
+        }
+        Ok(self.inner.get(self.pos..).unwrap_or_default())
+    }
+}
+
+#[pymethods]
+impl BufferReader {
+    /// Read and return at most size bytes, or if size is not given or
+    /// negative, until EOF.
+    #[pyo3(signature = (size=None,))]
+    pub fn read<'p>(&mut self, py: Python<'p>, size: Option<i64>) -> PyResult<Bound<'p, PyBytes>> {
+        let rest = self.rest()?;
+        let n = match size {
+            Some(size) if size >= 0 => rest.len().min(size as usize),
+            _ => rest.len(),
+        };
+        let bs = PyBytes::new(py, &rest[..n]);
+        self.pos += n;
+        Ok(bs)
+    }
+
+    /// Read bytes into a pre-allocated, writable buffer, returning how many
+    /// were read.
+    pub fn readinto(&mut self, buffer: PyBuffer<u8>) -> PyResult<usize> {
+        let rest = self.rest()?;
+        if buffer.readonly() {
+            return Err(PyValueError::new_err("Buffer is not writable."));
+        }
+        if !buffer.is_c_contiguous() {
+            return Err(PyValueError::new_err("Buffer is not C contiguous."));
+        }
+
+        let n = rest.len().min(buffer.len_bytes());
+        // SAFETY: the buffer is writable, contiguous and at least `n` bytes
+        // long, and `rest` can't overlap it since it's read only.
+        unsafe {
+            std::ptr::copy_nonoverlapping(rest.as_ptr(), buffer.buf_ptr() as *mut u8, n);
+        }
+        self.pos += n;
+        Ok(n)
+    }
+
+    /// Change the stream position to the given byte offset.
+    ///
+    /// `whence` is `0` for the start, `1` for the current position and `2`
+    /// for the end. Seeking past the end is allowed and reads nothing.
+    ///
+    /// Return the new absolute position.
+    #[pyo3(signature = (pos, whence = 0))]
+    pub fn seek(&mut self, pos: i64, whence: u8) -> PyResult<usize> {
+        self.rest()?;
+        let base = match whence {
+            0 => 0,
+            1 => self.pos as i64,
+            2 => self.inner.len() as i64,
+            _ => return Err(PyValueError::new_err("invalid whence")),
+        };
+        let Some(pos) = base.checked_add(pos).filter(|pos| *pos >= 0) else {
+            return Err(PyValueError::new_err(format!(
+                "negative seek position {pos}"
+            )));
+        };
+        self.pos = pos as usize;
+        Ok(self.pos)
+    }
+
+    /// Return the current stream position.
+    pub fn tell(&self) -> PyResult<usize> {
+        self.rest()?;
+        Ok(self.pos)
+    }
+
+    /// Return the whole content as a `Buffer`, without copying it.
+    pub fn getbuffer(&self) -> PyResult<Buffer> {
+        self.rest()?;
+        Ok(Buffer {
+            inner: self.inner.clone(),
+        })
+    }
+
+    /// Drop the content. Reading afterwards raises `ValueError`.
+    pub fn close(&mut self) {
+        self.inner = Bytes::new();
+        self.closed = true;
+    }
+
+    pub fn __enter__(slf: Py<Self>) -> Py<Self> {
+        slf
+    }
+
+    pub fn __exit__(
+        &mut self,
+        _exc_type: &Bound<PyAny>,
+        _exc_value: &Bound<PyAny>,
+        _traceback: &Bound<PyAny>,
+    ) {
+        self.close();
+    }
+
+    /// Return True, the stream can be read from.
+    pub fn readable(&self) -> bool {
+        true
+    }
+
+    /// Return False, the stream can't be written to.
+    pub fn writable(&self) -> bool {
+        false
+    }
+
+    /// Return True, the stream can be repositioned.
+    pub fn seekable(&self) -> bool {
+        true
+    }
+
+    /// Return True if the stream is closed.
+    #[getter]
+    pub fn closed(&self) -> bool {
+        self.closed
+    }
+}
+
+/// A read-only view on an object implementing the buffer protocol, like
+/// `bytes`, `bytearray`, `memoryview` or a numpy array.
+///
+/// The memory is borrowed rather than copied. It stays valid and in place
+/// until the view is dropped.
+pub struct BytesView(Box<ffi::Py_buffer>);
+
+// SAFETY: the exporter keeps the memory alive until the view is released,
+// which happens with the GIL held.
+unsafe impl Send for BytesView {}
+unsafe impl Sync for BytesView {}
+
+impl BytesView {
+    /// Borrow the memory of `obj`, which must be contiguous and one-dimensional.
+    pub fn get(obj: &Bound<PyAny>) -> PyResult<Self> {
+        let mut view = Box::new(MaybeUninit::<ffi::Py_buffer>::uninit());
+        let ret = unsafe {
+            ffi::PyObject_GetBuffer(obj.as_ptr(), view.as_mut_ptr(), ffi::PyBUF_C_CONTIGUOUS)
+        };
+        if ret == -1 {
+            return Err(PyErr::fetch(obj.py()));
+        }
+        let view = Self(unsafe { view.assume_init() });
+        if view.0.ndim > 1 {
+            return Err(PyValueError::new_err(format!(
+                "only one-dimensional buffers can be written, got {} dimensions",
+                view.0.ndim
+            )));
+        }
+        Ok(view)
+    }
+
+    pub fn as_slice(&self) -> &[u8] {
+        if self.0.buf.is_null() {
+            return &[];
+        }
+        unsafe { std::slice::from_raw_parts(self.0.buf as *const u8, self.0.len as usize) }
+    }
+}
+
+impl Drop for BytesView {
+    fn drop(&mut self) {
+        Python::with_gil(|_| unsafe { ffi::PyBuffer_Release(&mut *self.0) });
+    }
+}
+
+/// The content passed to `write`: a bytes-like object, or an iterable of them
+/// that's streamed chunk by chunk.
+pub enum WriteContent {
+    Bytes(BytesView),
+    Chunks(Chunks),
+}
+
+impl WriteContent {
+    /// Borrow `obj` if it's bytes-like, or else iterate it. Async iterables
+    /// are only taken if `allow_async`, for `AsyncOperator`.
+    pub fn get(obj: &Bound<PyAny>, allow_async: bool) -> PyResult<Self> {
+        let err = match BytesView::get(obj) {
+            Ok(view) => return Ok(Self::Bytes(view)),
+            Err(err) => err,
+        };
+        // A `str` is iterable, but its characters aren't bytes. Buffers that
+        // can't be borrowed, like a strided `memoryview`, would iterate as
+        // ints, so their own error is clearer.
+        if obj.is_instance_of::<PyString>()
+            || unsafe { ffi::PyObject_CheckBuffer(obj.as_ptr()) } == 1
+        {
+            return Err(err);
+        }
+        if allow_async && obj.hasattr("__aiter__")? {
+            let iter = obj.call_method0("__aiter__")?;
+            return Ok(Self::Chunks(Chunks::Async(iter.unbind())));
+        }
+        match obj.try_iter() {
+            Ok(iter) => Ok(Self::Chunks(Chunks::Sync(iter.unbind()))),
+            Err(_) => Err(PyTypeError::new_err(format!(
+                "expected a bytes-like object or an iterable of them, got {}",
+                obj.get_type().name()?
+            ))),
+        }
+    }
+}
+
+/// An iterator, or async iterator, yielding the bytes-like chunks to write.
+pub enum Chunks {
+    Sync(Py<PyIterator>),
+    Async(PyObject),
+}
+
+impl Chunks {
+    /// Pull the next chunk, awaiting it from an async iterator.
+    ///
+    /// Chunks are only pulled once the previous one is written, so a slow
+    /// service pauses the producer.
+    pub async fn next(&self) -> PyResult<Option<Bytes>> {
+        let iter = match self {
+            Self::Sync(iter) => return Python::with_gil(|py| Self::next_sync(iter.bind(py))),
+            Self::Async(iter) => iter,
+        };
+        let next = Python::with_gil(|py| {
+            pyo3_async_runtimes::tokio::into_future(iter.bind(py).call_method0("__anext__")?)
+        })?;
+        match next.await {
+            Ok(chunk) => Python::with_gil(|py| Self::copy(chunk.bind(py)).map(Some)),
+            Err(err) if Python::with_gil(|py| err.is_instance_of::<PyStopAsyncIteration>(py)) => {
+                Ok(None)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Pull the next chunk of a sync iterator.
+    pub fn blocking_next(&self, py: Python) -> PyResult<Option<Bytes>> {
+        match self {
+            Self::Sync(iter) => Self::next_sync(iter.bind(py)),
+            Self::Async(_) => unreachable!("async iterables are only taken by AsyncOperator"),
+        }
+    }
+
+    fn next_sync(iter: &Bound<PyIterator>) -> PyResult<Option<Bytes>> {
+        iter.clone()
+            .next()
+            .map(|chunk| Self::copy(&chunk?))
+            .transpose()
+    }
+
+    fn copy(chunk: &Bound<PyAny>) -> PyResult<Bytes> {
+        let view = BytesView::get(chunk)?;
+        Ok(Bytes::copy_from_slice(view.as_slice()))
+    }
+}
+
+/// A duration passed from Python, either as seconds (`int` / `float`) or as
+/// a `datetime.timedelta`.
+#[derive(Clone, Copy, Debug)]
+pub struct PyDuration(pub Duration);
+
+impl<'py> FromPyObject<'py> for PyDuration {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        if let Ok(d) = ob.downcast::<PyDelta>() {
+            return d.extract::<Duration>().map(PyDuration);
+        }
+
+        let secs = ob.extract::<f64>().map_err(|_| {
+            PyTypeError::new_err("duration must be seconds as int/float or a datetime.timedelta")
+        })?;
+        Duration::try_from_secs_f64(secs)
+            .map(PyDuration)
+            .map_err(|err| PyValueError::new_err(format!("invalid duration {secs}: {err}")))
+    }
+}
+
+/// A path passed from Python, either as a `str` or an `os.PathLike` whose
+/// `__fspath__` returns a `str`.
+///
+/// Bytes are rejected: OpenDAL paths are strings, not OS paths.
+#[derive(Clone, Debug)]
+pub struct PyPath(pub String);
+
+impl<'py> FromPyObject<'py> for PyPath {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        // SAFETY: `PyOS_FSPath` returns a new reference or NULL with an
+        // exception set.
+        let path = unsafe { Bound::from_owned_ptr_or_err(ob.py(), ffi::PyOS_FSPath(ob.as_ptr())) }
+            .map_err(|err| {
+                // Keep errors raised by `__fspath__` itself.
+                if !err.is_instance_of::<PyTypeError>(ob.py()) {
+                    return err;
+                }
+                PyTypeError::new_err(format!(
+                    "path must be str or os.PathLike, not {}",
+                    type_name(ob)
+                ))
+            })?;
+
+        match path.downcast::<PyString>() {
+            Ok(path) => Ok(PyPath(path.to_str()?.to_string())),
+            Err(_) => Err(PyTypeError::new_err(format!(
+                "path must be str or os.PathLike, not {}: OpenDAL paths are \
+                 strings, decode bytes paths first",
+                type_name(&path)
+            ))),
+        }
+    }
+}
+
+fn type_name(ob: &Bound<PyAny>) -> String {
+    ob.get_type()
+        .name()
+        .map(|name| name.to_string())
+        .unwrap_or_else(|_| "unknown".to_string())
+}
+
+/// Checks downloaded bytes against the MD5 digest a service reports for an
+/// object as its `content_md5`.
+///
+/// Etags aren't used even when they look like an MD5 hex digest: services
+/// are free to compute them some other way, like S3 does for encrypted
+/// objects, which would raise `ChecksumMismatch` for intact content.
+pub struct ChecksumVerifier {
+    path: String,
+    expected: [u8; 16],
+    hasher: Md5,
+}
+
+impl ChecksumVerifier {
+    /// Returns `None` if `meta` carries no usable checksum, or raises
+    /// `ChecksumMismatch` instead if `required`.
+    pub fn new(path: &str, meta: &ocore::Metadata, required: bool) -> PyResult<Option<Self>> {
+        match expected_md5(meta) {
+            Some(expected) => Ok(Some(Self {
+                path: path.to_string(),
+                expected,
+                hasher: Md5::new(),
+            })),
+            None if required => Err(checksum_mismatch(format!(
+                "no checksum available to verify {path} against"
+            ))),
+            None => Ok(None),
+        }
+    }
+
+    pub fn update(&mut self, bs: &[u8]) {
+        self.hasher.update(bs);
+    }
+
+    /// Compare the digest of everything passed to `update` so far.
+    pub fn finish(self) -> PyResult<()> {
+        let actual: [u8; 16] = self.hasher.finalize().into();
+        if actual == self.expected {
+            return Ok(());
+        }
+        Err(checksum_mismatch(format!(
+            "checksum mismatch for {}: expected md5 {}, got {}",
+            self.path,
+            to_hex(&self.expected),
+            to_hex(&actual)
+        )))
+    }
+
+    /// Feed all `chunks` and compare the digest.
+    pub fn verify<B: AsRef<[u8]>>(mut self, chunks: impl IntoIterator<Item = B>) -> PyResult<()> {
+        for chunk in chunks {
+            self.update(chunk.as_ref());
+        }
+        self.finish()
+    }
+}
+
+fn expected_md5(meta: &ocore::Metadata) -> Option<[u8; 16]> {
+    meta.content_md5()
+        .and_then(|v| decode_hex_md5(v).or_else(|| BASE64_STANDARD.decode(v).ok()?.try_into().ok()))
+}
+
+fn decode_hex_md5(s: &str) -> Option<[u8; 16]> {
+    if s.len() != 32 {
+        return None;
+    }
+    let mut digest = [0; 16];
+    for (i, byte) in digest.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(s.get(i * 2..i * 2 + 2)?, 16).ok()?;
+    }
+    Some(digest)
+}
+
+fn to_hex(bs: &[u8]) -> String {
+    bs.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+thread_local! {
+    /// Whether this thread left the async runtime to run a callback, so
+    /// that the blocking operator may be used from it.
+    static OUTSIDE_RUNTIME: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Run the Python callback `f` outside the async runtime, and outside
+/// the scope of the call it's invoked from.
+///
+/// The blocking operator may be used from `f`, even on a worker thread of
+/// the runtime.
+pub fn outside_runtime<T>(f: impl FnOnce() -> T) -> T {
+    struct Reset(bool);
+
+    impl Drop for Reset {
+        fn drop(&mut self) {
+            OUTSIDE_RUNTIME.set(self.0);
+        }
+    }
+
+    tokio::task::block_in_place(|| {
+        let _reset = Reset(OUTSIDE_RUNTIME.replace(true));
+        unscoped(f)
+    })
+}
+
+/// Whether the current thread runs a callback through `outside_runtime`.
+pub fn is_outside_runtime() -> bool {
+    OUTSIDE_RUNTIME.get()
+}
+
+/// Reports transfer progress to a Python callable as `(bytes_so_far, total)`.
+///
+/// The callable never runs while a file lock is held, and runs outside the
+/// async runtime, so it may call back into OpenDAL, with the blocking
+/// operator too. An exception it raises aborts the transfer.
+pub struct Progress {
+    callback: PyObject,
+    transferred: u64,
+    total: Option<u64>,
+}
+
+impl Progress {
+    pub fn new(callback: PyObject, total: Option<u64>) -> Self {
+        Self {
+            callback,
+            transferred: 0,
+            total,
+        }
+    }
+
+    /// Replace the total once the size turns out to differ.
+    pub fn set_total(&mut self, total: Option<u64>) {
+        self.total = total;
+    }
+
+    /// Record `n` more transferred bytes and report them.
+    pub fn advance(&mut self, n: usize) -> PyResult<()> {
+        self.transferred += n as u64;
+        self.report()
+    }
+
+    /// Invoke the callable with the current progress.
+    pub fn report(&self) -> PyResult<()> {
+        outside_runtime(|| {
+            Python::with_gil(|py| {
+                self.callback
+                    .call1(py, (self.transferred, self.total))
+                    .map(|_| ())
+            })
+        })
+    }
+}
@@ -103,6 +103,7 @@ impl From<WriteOptions> for ocore::options::WriteOptions {
     fn from(opts: WriteOptions) -> Self {
         Self {
             append: opts.append.unwrap_or(false),
+            offset: None,
             concurrent: opts.concurrent.unwrap_or_default(),
             chunk: opts.chunk,
             content_type: opts.content_type,
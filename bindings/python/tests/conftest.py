@@ -15,7 +15,11 @@
 # specific language governing permissions and limitations
 # under the License.
 
+import multiprocessing
 import os
+import threading
+import types
+from http.server import ThreadingHTTPServer
 from uuid import uuid4
 
 import pytest
@@ -93,3 +97,77 @@ def check_capability(request, operator, async_operator):
                     f"{request.node.get_closest_marker('need_capability').args}"
                     " not supported"
                 )
+
+
+def _share(manager, value):
+    # The server runs in a subprocess, so the state that handlers record or
+    # tests change has to live in the manager to be seen on both sides.
+    if isinstance(value, list):
+        return manager.list(value)
+    if isinstance(value, dict):
+        return manager.dict(value)
+    if isinstance(value, threading.Event):
+        return manager.Event()
+    return value
+
+
+def _serve(handler, state, port):
+    for k, v in state.items():
+        setattr(handler, k, v)
+    server = ThreadingHTTPServer(("127.0.0.1", 0), handler)
+    server.daemon_threads = True
+    # Clients hanging up early, like timed out or cancelled ones, are what
+    # some tests are after.
+    server.handle_error = lambda request, client_address: None
+    port.send(server.server_port)
+    server.serve_forever()
+
+
+@pytest.fixture
+def http_server():
+    """Serve a `BaseHTTPRequestHandler` class on a local port.
+
+    Call it with the handler class to start a server and get its port back
+    as `server_port`. The server runs in a subprocess, so blocking calls
+    holding the GIL get their answers too. Lists, dicts and events among the
+    handler's class attributes, like the requests it records, are replaced
+    by fresh copies shared with the subprocess, other attributes are only
+    read by it. They're put back along with stopping every server once the
+    test ends, even if it fails.
+    """
+    context = multiprocessing.get_context("spawn")
+    managers = []
+    processes = []
+    saved = []
+
+    def serve(handler):
+        if not managers:
+            managers.append(context.Manager())
+        state = {
+            k: v
+            for k, v in vars(handler).items()
+            if not k.startswith("_") and not callable(v)
+        }
+        saved.append((handler, state))
+        shared = {k: _share(managers[0], v) for k, v in state.items()}
+        for k, v in shared.items():
+            setattr(handler, k, v)
+        receiver, sender = context.Pipe(duplex=False)
+        process = context.Process(
+            target=_serve, args=(handler, shared, sender), daemon=True
+        )
+        process.start()
+        processes.append(process)
+        if not receiver.poll(30):
+            raise RuntimeError(f"{handler.__name__} server didn't start")
+        return types.SimpleNamespace(server_port=receiver.recv())
+
+    yield serve
+    for process in processes:
+        process.terminate()
+        process.join()
+    for handler, state in saved:
+        for k, v in state.items():
+            setattr(handler, k, v)
+    for manager in managers:
+        manager.shutdown()
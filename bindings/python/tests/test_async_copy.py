@@ -15,11 +15,13 @@
 # specific language governing permissions and limitations
 # under the License.
 
+import io
 import os
 from uuid import uuid4
 
 import pytest
 
+import opendal
 from opendal.exceptions import IsADirectory, IsSameFile, NotFound
 
 
@@ -113,3 +115,74 @@ async def test_async_copy_overwrite(service_name, operator, async_operator):
     assert target_content == source_content
     await async_operator.delete(source_path)
     await async_operator.delete(target_path)
+
+
+@pytest.mark.asyncio
+@pytest.mark.need_capability("read", "write", "delete")
+async def test_async_copy_from(service_name, operator, async_operator):
+    source_op = opendal.AsyncOperator("memory")
+    source_path = f"random_file_{str(uuid4())}"
+    content = os.urandom(1024 * 1024 + 3)
+    await source_op.write(source_path, content)
+    target_path = f"random_file_{str(uuid4())}"
+    await async_operator.copy_from(source_op, source_path, target_path)
+    assert await async_operator.read(target_path) == content
+    with pytest.raises(NotFound):
+        await async_operator.copy_from(
+            source_op, f"random_file_{str(uuid4())}", target_path
+        )
+    assert await async_operator.read(target_path) == content
+    await async_operator.delete(target_path)
+
+
+@pytest.mark.asyncio
+async def test_async_copy_from_same_service(tmp_path):
+    parent = opendal.AsyncOperator("fs", root=str(tmp_path))
+    child = opendal.AsyncOperator("fs", root=str(tmp_path / "child"))
+    content = os.urandom(1024)
+    await child.write("source", content)
+
+    await parent.copy_from(child, "source", "from_child")
+    assert await parent.read("from_child") == content
+    await child.copy_from(parent, "from_child", "from_parent")
+    assert await child.read("from_parent") == content
+
+
+class _ForeignOperator:
+    """Stands in for the blocking `Operator` of another OpenDAL package.
+
+    A real one can't be called from the event loop, so it only hands back an
+    empty file.
+    """
+
+    def open(self, path, mode):
+        return io.BytesIO()
+
+
+class _ForeignAsyncOperator:
+    """Stands in for the `AsyncOperator` of another OpenDAL package."""
+
+    def __init__(self, op):
+        self._op = op
+
+    async def open(self, path, mode):
+        return await self._op.open(path, mode)
+
+
+@pytest.mark.asyncio
+async def test_async_copy_from_other_package(tmp_path):
+    source_op = _ForeignAsyncOperator(opendal.AsyncOperator("fs", root=str(tmp_path)))
+    target_op = opendal.AsyncOperator("memory")
+    content = os.urandom(9 * 1024 * 1024 + 3)
+    (tmp_path / "source").write_bytes(content)
+
+    await target_op.copy_from(source_op, "source", "target")
+    assert await target_op.read("target") == content
+    with pytest.raises(NotFound):
+        await target_op.copy_from(source_op, "missing", "target")
+    assert await target_op.read("target") == content
+    with pytest.raises(TypeError, match="must be an OpenDAL AsyncOperator"):
+        await target_op.copy_from(object(), "source", "target")
+    with pytest.raises(TypeError, match="must be an AsyncOperator"):
+        await target_op.copy_from(_ForeignOperator(), "source", "target")
+
@@ -47,3 +47,68 @@ async def test_async_remove_all(service_name, operator, async_operator):
             with pytest.raises(NotFound):
                 await async_operator.read(f"{parent}/{path}")
     await async_operator.remove_all(f"{parent}/")
+
+
+@pytest.mark.asyncio
+@pytest.mark.need_capability("read", "write", "delete", "list")
+async def test_async_remove_all_dry_run(service_name, operator, async_operator):
+    parent = f"random_dir_{str(uuid4())}/"
+    paths = [f"{parent}{i}" for i in range(3)] + [f"{parent}x/y"]
+    for path in paths:
+        await async_operator.write(path, os.urandom(16))
+
+    planned = await async_operator.remove_all(parent, dry_run=True)
+    assert set(paths) <= set(planned)
+    for path in paths:
+        assert await async_operator.exists(path)
+
+    batches = []
+    removed = await async_operator.remove_all(parent, on_delete=batches.append)
+    assert removed == len(planned)
+    assert sorted(p for batch in batches for p in batch) == sorted(planned)
+    for path in paths:
+        assert not await async_operator.exists(path)
+
+    with pytest.raises(TypeError):
+        await async_operator.remove_all(parent, on_delete=1)
+
+
+@pytest.mark.asyncio
+@pytest.mark.need_capability("write", "delete", "list")
+async def test_async_remove_all_missing_prefix(service_name, operator, async_operator):
+    parent = f"random_dir_{str(uuid4())}/"
+    await async_operator.remove_all(parent)
+    await async_operator.remove_all(f"{parent}missing")
+
+
+@pytest.mark.asyncio
+@pytest.mark.need_capability("read", "write", "delete")
+async def test_async_delete_iter(service_name, operator, async_operator):
+    parent = f"random_dir_{str(uuid4())}"
+    paths = [f"{parent}/{i}" for i in range(5)]
+    for path in paths:
+        await async_operator.write(path, os.urandom(16))
+
+    # Deleting a missing path is not a failure.
+    result = await async_operator.delete_iter(iter(paths + [f"{parent}/missing"]))
+    assert sorted(result.deleted) == sorted(paths + [f"{parent}/missing"])
+    assert result.failed == {}
+    for path in paths:
+        with pytest.raises(NotFound):
+            await async_operator.read(path)
+
+    with pytest.raises(TypeError):
+        await async_operator.delete_iter(parent)
+
+
+@pytest.mark.asyncio
+@pytest.mark.need_capability("write", "delete", "stat")
+async def test_async_delete_missing_ok(service_name, operator, async_operator):
+    filename = f"random_file_{str(uuid4())}"
+    await async_operator.delete(filename)
+    with pytest.raises(NotFound):
+        await async_operator.delete(filename, missing_ok=False)
+
+    await async_operator.write(filename, os.urandom(16))
+    await async_operator.delete(filename, missing_ok=False)
+    assert not await async_operator.exists(filename)
@@ -29,3 +29,10 @@ async def test_async_remove_all(service_name, operator, async_operator):
     await async_operator.write(target, content)
     assert await async_operator.exists(target)
     assert not await async_operator.exists(target + "1")
+
+    dir_path = f"random_dir_{str(uuid4())}/"
+    await async_operator.create_dir(dir_path)
+    assert await async_operator.exists(dir_path)
+    await async_operator.delete(target)
+    await async_operator.delete(dir_path)
+    assert not await async_operator.exists(target)
@@ -61,3 +61,68 @@ async def test_async_list_with_start_after(service_name, operator, async_operato
     )
     # 6. Cleanup
     await async_operator.remove_all(test_dir)
+
+
+@pytest.mark.asyncio
+@pytest.mark.need_capability("write", "list")
+async def test_async_list_limit(service_name, operator, async_operator):
+    test_dir = f"test_async_list_dir_{uuid4()}/"
+    for i in range(5):
+        await async_operator.write(f"{test_dir}file_{i}", b"test_content")
+
+    entries = [entry async for entry in await async_operator.list(test_dir, limit=3)]
+    assert len(entries) == 3
+
+    await async_operator.remove_all(test_dir)
+
+
+@pytest.mark.asyncio
+@pytest.mark.need_capability("write", "list", "delete")
+async def test_async_glob(service_name, operator, async_operator):
+    test_dir = f"test_async_glob_dir_{uuid4()}/"
+    files = [
+        "logs/2024-01/a.json",
+        "logs/2024-01/deep/b.json",
+        "logs/2024-02/c.txt",
+    ]
+    for f in files:
+        await async_operator.write(f"{test_dir}{f}", b"test_content")
+
+    lister = await async_operator.glob(f"{test_dir}logs/2024-*/**.json")
+    entries = sorted([entry.path async for entry in lister])
+    assert entries == [f"{test_dir}{f}" for f in files[:2]]
+    with pytest.raises(ValueError):
+        await async_operator.glob(f"{test_dir}logs/[a")
+
+    await async_operator.remove_all(test_dir)
+
+
+@pytest.mark.asyncio
+@pytest.mark.need_capability("write", "list", "delete")
+async def test_async_list_dirs_only(service_name, operator, async_operator):
+    test_dir = f"test_async_list_dir_{uuid4()}/"
+    for f in ("a.txt", "sub/b.txt", "other/d.txt"):
+        await async_operator.write(f"{test_dir}{f}", b"test_content")
+
+    lister = await async_operator.list(test_dir, dirs_only=True)
+    entries = sorted([entry.path async for entry in lister])
+    assert entries == [f"{test_dir}other/", f"{test_dir}sub/"]
+
+    await async_operator.remove_all(test_dir)
+
+
+@pytest.mark.asyncio
+@pytest.mark.need_capability("write", "list", "delete")
+async def test_async_tree(service_name, operator, async_operator):
+    test_dir = f"test_async_tree_dir_{uuid4()}/"
+    for f in ("a.txt", "sub/b.txt", "sub/deep/c.txt"):
+        await async_operator.write(f"{test_dir}{f}", b"test_content")
+
+    tree = await async_operator.tree(test_dir, max_depth=2)
+    a, sub = tree["children"]
+    assert a["name"] == "a.txt"
+    assert "metadata" in a
+    assert [child["name"] for child in sub["children"]] == ["b.txt", "deep/"]
+    assert sub["children"][1]["children"] is None
+
+    await async_operator.remove_all(test_dir)
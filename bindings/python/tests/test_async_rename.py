@@ -16,11 +16,19 @@
 # under the License.
 
 import os
+from http.server import BaseHTTPRequestHandler
 from uuid import uuid4
 
 import pytest
 
-from opendal.exceptions import IsADirectory, IsSameFile, NotFound
+import opendal
+from opendal.exceptions import (
+    IsADirectory,
+    IsSameFile,
+    NotFound,
+    PermissionDenied,
+    Unsupported,
+)
 
 
 @pytest.mark.asyncio
@@ -111,3 +119,76 @@ async def test_async_rename_overwrite(service_name, operator, async_operator):
     assert await async_operator.read(target_path) == source_content
     await async_operator.delete(target_path)
     await async_operator.delete(source_path)
+
+
+@pytest.mark.asyncio
+@pytest.mark.need_capability("read", "write", "copy", "delete")
+async def test_async_rename_emulate(service_name, operator, async_operator):
+    if async_operator.capability().rename:
+        pytest.skip("service supports native rename")
+    source_path = f"random_file_{str(uuid4())}"
+    target_path = f"random_file_{str(uuid4())}"
+    content = os.urandom(1024)
+    await async_operator.write(source_path, content)
+    with pytest.raises(Unsupported):
+        await async_operator.rename(source_path, target_path)
+    await async_operator.rename(source_path, target_path, emulate=True)
+    assert not await async_operator.exists(source_path)
+    assert await async_operator.read(target_path) == content
+    await async_operator.delete(target_path)
+
+
+class _NoDeleteS3Handler(BaseHTTPRequestHandler):
+    """A bucket that can stat and copy objects but refuses to delete them."""
+
+    objects = {}
+
+    def do_HEAD(self):
+        body = self.objects.get(self.path.lstrip("/"))
+        self.send_response(404 if body is None else 200)
+        self.send_header("Content-Length", str(len(body or b"")))
+        self.end_headers()
+
+    def do_PUT(self):
+        source = self.headers["x-amz-copy-source"]
+        self.objects[self.path.lstrip("/")] = self.objects[source]
+        body = b"<CopyObjectResult></CopyObjectResult>"
+        self.send_response(200)
+        self.send_header("Content-Length", str(len(body)))
+        self.end_headers()
+        self.wfile.write(body)
+
+    def do_DELETE(self):
+        body = b"<Error><Code>AccessDenied</Code></Error>"
+        self.send_response(403)
+        self.send_header("Content-Length", str(len(body)))
+        self.end_headers()
+        self.wfile.write(body)
+
+    def log_message(self, *args):
+        pass
+
+
+@pytest.mark.asyncio
+async def test_async_rename_emulate_keeps_source(
+    service_name, operator, async_operator, http_server
+):
+    server = http_server(_NoDeleteS3Handler)
+    op = opendal.AsyncOperator(
+        "s3",
+        bucket="bucket",
+        region="us-east-1",
+        endpoint=f"http://127.0.0.1:{server.server_port}",
+        access_key_id="access_key_id",
+        secret_access_key="secret_access_key",
+        disable_config_load="true",
+        disable_ec2_metadata="true",
+    )
+    _NoDeleteS3Handler.objects["bucket/source"] = b"hello world"
+
+    with pytest.raises(Unsupported):
+        await op.rename("source", "target")
+    with pytest.raises(PermissionDenied):
+        await op.rename("source", "target", emulate=True)
+    assert _NoDeleteS3Handler.objects["bucket/source"] == b"hello world"
+    assert _NoDeleteS3Handler.objects["bucket/target"] == b"hello world"
@@ -15,8 +15,14 @@
 # specific language governing permissions and limitations
 # under the License.
 
+import pickle
+
 import pytest
 
+import opendal
+from opendal import Capability
+from opendal.exceptions import Unsupported
+
 
 def test_capability(service_name, operator):
     cap = operator.capability()
@@ -29,3 +35,68 @@ def test_capability_exception(service_name, operator):
     assert cap is not None
     with pytest.raises(AttributeError):
         cap.read_demo
+
+
+def test_capability_repr_and_pickle(service_name, operator):
+    cap = operator.capability()
+    assert repr(cap).startswith("Capability(stat=")
+    assert f"read={cap.read!r}" in repr(cap)
+
+    restored = pickle.loads(pickle.dumps(cap))
+    assert restored == cap
+    assert restored.write_multi_max_size == cap.write_multi_max_size
+
+
+def test_capability_new():
+    cap = Capability(read=True, write_multi_min_size=5)
+    assert cap.read
+    assert not cap.write
+    assert cap.write_multi_min_size == 5
+    assert cap != Capability()
+    with pytest.raises(TypeError):
+        Capability(read_demo=True)
+
+
+def test_operator_info(service_name, operator, async_operator):
+    info = operator.info()
+    assert info.scheme == service_name
+    assert info.root
+    assert isinstance(info.name, str)
+    assert info.capability == operator.capability()
+    assert repr(info).startswith(f'OperatorInfo(scheme="{service_name}"')
+
+    async_info = async_operator.info()
+    assert async_info.scheme == info.scheme
+    assert async_info.capability == async_operator.capability()
+
+
+def test_operator_repr(service_name, operator, async_operator):
+    assert repr(operator).startswith(f'Operator("{service_name}", root=')
+    assert repr(async_operator).startswith(f'AsyncOperator("{service_name}", root=')
+
+    op = opendal.Operator(
+        "s3", bucket="bucket", region="us-east-1", secret_access_key="hunter2"
+    )
+    assert repr(op) == 'Operator("s3", root="/", name="bucket")'
+    assert str(op) == repr(op)
+
+
+def test_operator_require(service_name, operator, async_operator):
+    cap = operator.capability()
+    flags = ("stat", "read", "write", "list")
+    supported = [name for name in flags if getattr(cap, name)]
+    operator.require(**{name: True for name in supported})
+    async_operator.require(**{name: True for name in supported})
+    operator.require(presign=False, write_can_append=False)
+
+    flags = ("presign", "write_can_append", "copy")
+    missing = [name for name in flags if not getattr(cap, name)]
+    if missing:
+        with pytest.raises(Unsupported) as excinfo:
+            operator.require(read=cap.read, **{name: True for name in missing})
+        assert str(excinfo.value).endswith(", ".join(missing))
+
+    with pytest.raises(TypeError):
+        operator.require(read_demo=True)
+    with pytest.raises(TypeError):
+        operator.require(delete_max_size=True)
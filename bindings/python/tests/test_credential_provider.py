@@ -0,0 +1,124 @@
+# Licensed to the Apache Software Foundation (ASF) under one
+# or more contributor license agreements.  See the NOTICE file
+# distributed with this work for additional information
+# regarding copyright ownership.  The ASF licenses this file
+# to you under the Apache License, Version 2.0 (the
+# "License"); you may not use this file except in compliance
+# with the License.  You may obtain a copy of the License at
+#
+#   http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing,
+# software distributed under the License is distributed on an
+# "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+# KIND, either express or implied.  See the License for the
+# specific language governing permissions and limitations
+# under the License.
+
+import pickle
+import re
+from datetime import datetime, timedelta, timezone
+from http.server import BaseHTTPRequestHandler
+
+import pytest
+
+import opendal
+from opendal.exceptions import PermissionDenied, Unsupported
+
+
+class _S3Handler(BaseHTTPRequestHandler):
+    """Accept requests signed with any access key but `expired`."""
+
+    keys = []
+
+    def do_HEAD(self):
+        key = re.search(r"Credential=([^/]+)/", self.headers["Authorization"])[1]
+        self.keys.append(key)
+        self.send_response(403 if key == "expired" else 200)
+        self.send_header("Content-Length", "3")
+        self.end_headers()
+
+    def log_message(self, *args):
+        pass
+
+
+class _Provider:
+    def __init__(self, *keys, expiry=None):
+        self.keys = list(keys)
+        self.expiry = expiry
+        self.calls = 0
+
+    def __call__(self):
+        self.calls += 1
+        key = self.keys.pop(0) if len(self.keys) > 1 else self.keys[0]
+        return key, "secret", "token", self.expiry
+
+
+@pytest.fixture
+def s3_endpoint(http_server):
+    server = http_server(_S3Handler)
+    return f"http://127.0.0.1:{server.server_port}"
+
+
+def _s3(endpoint, provider):
+    return opendal.Operator(
+        "s3",
+        bucket="bucket",
+        region="us-east-1",
+        endpoint=endpoint,
+        credential_provider=provider,
+    )
+
+
+def test_credential_provider_cached(
+    service_name, operator, async_operator, s3_endpoint
+):
+    provider = _Provider("first", "second")
+    op = _s3(s3_endpoint, provider)
+    assert (op.stat("a")).content_length == 3
+    assert (op.stat("b")).content_length == 3
+    assert provider.calls == 1
+    assert list(_S3Handler.keys) == ["first", "first"]
+
+    # The provider is pickled along with the operator, in its current state.
+    restored = pickle.loads(pickle.dumps(op))
+    assert (restored.stat("c")).content_length == 3
+    assert _S3Handler.keys[-1] == "second"
+
+
+def test_credential_provider_expiry(
+    service_name, operator, async_operator, s3_endpoint
+):
+    # Credentials expiring within two minutes are fetched again.
+    expiry = datetime.now(timezone.utc) + timedelta(minutes=1)
+    provider = _Provider("first", "second", expiry=expiry)
+    op = _s3(s3_endpoint, provider)
+    op.stat("a")
+    op.stat("b")
+    assert provider.calls == 2
+    assert list(_S3Handler.keys) == ["first", "second"]
+
+
+def test_credential_provider_refresh_on_denied(
+    service_name, operator, async_operator, s3_endpoint
+):
+    provider = _Provider("expired", "fresh")
+    op = _s3(s3_endpoint, provider)
+    assert (op.stat("a")).content_length == 3
+    assert (op.stat("b")).content_length == 3
+    assert provider.calls == 2
+    assert list(_S3Handler.keys) == ["expired", "fresh", "fresh"]
+
+    # Credentials that keep being rejected are retried only once.
+    provider = _Provider("expired")
+    op = _s3(s3_endpoint, provider)
+    with pytest.raises(PermissionDenied):
+        op.stat("a")
+    assert provider.calls == 2
+
+
+def test_credential_provider_invalid(service_name, operator, async_operator):
+    with pytest.raises(TypeError):
+        opendal.Operator("s3", bucket="bucket", credential_provider="key")
+    with pytest.raises(Unsupported):
+        opendal.Operator("memory", credential_provider=lambda: ("a", "b", None, None))
@@ -16,12 +16,31 @@
 # under the License.
 
 import inspect
+import pickle
+from uuid import uuid4
+
+import pytest
 
 from opendal import exceptions
-from opendal.exceptions import Error
+from opendal.exceptions import Error, NotFound
 
 
 def test_exceptions():
     for name, obj in inspect.getmembers(exceptions):
         if inspect.isclass(obj):
             assert issubclass(obj, Error)
+
+
+@pytest.mark.need_capability("stat")
+def test_exception_attributes(service_name, operator, async_operator):
+    with pytest.raises(NotFound) as excinfo:
+        operator.stat(f"random_file_{str(uuid4())}")
+    assert excinfo.value.kind == "NotFound"
+    assert excinfo.value.is_temporary() is False
+
+    restored = pickle.loads(pickle.dumps(excinfo.value))
+    assert isinstance(restored, NotFound)
+    assert str(restored) == str(excinfo.value)
+    assert restored.kind == "NotFound"
+    assert restored.is_temporary() is False
+    assert exceptions.Unsupported("not here").kind == "Unsupported"
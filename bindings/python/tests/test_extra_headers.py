@@ -0,0 +1,113 @@
+# Licensed to the Apache Software Foundation (ASF) under one
+# or more contributor license agreements.  See the NOTICE file
+# distributed with this work for additional information
+# regarding copyright ownership.  The ASF licenses this file
+# to you under the Apache License, Version 2.0 (the
+# "License"); you may not use this file except in compliance
+# with the License.  You may obtain a copy of the License at
+#
+#   http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing,
+# software distributed under the License is distributed on an
+# "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+# KIND, either express or implied.  See the License for the
+# specific language governing permissions and limitations
+# under the License.
+
+from http.server import BaseHTTPRequestHandler
+
+import pytest
+
+import opendal
+
+
+class _S3Handler(BaseHTTPRequestHandler):
+    """Serve a single object, recording the tenant header of every request."""
+
+    body = b"hello world"
+    tenants = []
+
+    def _respond(self, status, body=b""):
+        self.tenants.append((self.command, self.headers.get("X-Tenant-Id")))
+        self.send_response(status)
+        self.send_header("Content-Length", str(len(body)))
+        self.end_headers()
+        return body
+
+    def do_HEAD(self):
+        self._respond(200, self.body)
+
+    def do_GET(self):
+        body = self.body
+        if self.headers.get("Range"):
+            start, end = self.headers["Range"].removeprefix("bytes=").split("-")
+            body = body[int(start) : int(end) + 1]
+            self.wfile.write(self._respond(206, body))
+        else:
+            self.wfile.write(self._respond(200, body))
+
+    def do_PUT(self):
+        self.rfile.read(int(self.headers["Content-Length"]))
+        self._respond(200)
+
+    def log_message(self, *args):
+        pass
+
+
+@pytest.fixture
+def s3(service_name, operator, async_operator, http_server):
+    server = http_server(_S3Handler)
+    return opendal.Operator(
+        "s3",
+        bucket="bucket",
+        region="us-east-1",
+        endpoint=f"http://127.0.0.1:{server.server_port}",
+        access_key_id="access",
+        secret_access_key="secret",
+    )
+
+
+def test_extra_headers(s3):
+    headers = {"X-Tenant-Id": "tenant"}
+    s3.stat("file", extra_headers=headers)
+    assert s3.read("file", extra_headers=headers) == _S3Handler.body
+    s3.write("file", b"content", extra_headers=headers)
+    assert list(_S3Handler.tenants) == [
+        ("HEAD", "tenant"),
+        ("GET", "tenant"),
+        ("PUT", "tenant"),
+    ]
+
+    del _S3Handler.tenants[:]
+    s3.stat("file")
+    assert list(_S3Handler.tenants) == [("HEAD", None)]
+
+
+def test_extra_headers_concurrent_read(s3):
+    content = s3.read(
+        "file", chunk=2, concurrent=3, extra_headers={"X-Tenant-Id": "tenant"}
+    )
+    assert content == _S3Handler.body
+    gets = [tenant for command, tenant in _S3Handler.tenants if command == "GET"]
+    assert len(gets) > 1
+    assert all(tenant == "tenant" for tenant in gets)
+
+
+def test_extra_headers_write_defaults(s3):
+    op = s3.with_default_write_options(extra_headers={"X-Tenant-Id": "tenant"})
+    op.write("file", b"content")
+    op.write("file", b"content", extra_headers={"X-Tenant-Id": "other"})
+    assert list(_S3Handler.tenants) == [("PUT", "tenant"), ("PUT", "other")]
+
+
+def test_extra_headers_invalid(service_name, operator, async_operator):
+    for name in ("Authorization", "host", "x-amz-date"):
+        with pytest.raises(ValueError, match="can't be passed in extra_headers"):
+            operator.stat("file", extra_headers={name: "value"})
+    with pytest.raises(ValueError, match="invalid header name"):
+        operator.stat("file", extra_headers={"bad name": "value"})
+    with pytest.raises(ValueError, match="invalid value"):
+        operator.stat("file", extra_headers={"X-Tenant-Id": "a\nb"})
+    with pytest.raises(ValueError, match="open doesn't support extra_headers"):
+        operator.open("file", "rb", extra_headers={"X-Tenant-Id": "tenant"})
@@ -0,0 +1,107 @@
+# Licensed to the Apache Software Foundation (ASF) under one
+# or more contributor license agreements.  See the NOTICE file
+# distributed with this work for additional information
+# regarding copyright ownership.  The ASF licenses this file
+# to you under the Apache License, Version 2.0 (the
+# "License"); you may not use this file except in compliance
+# with the License.  You may obtain a copy of the License at
+#
+#   http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing,
+# software distributed under the License is distributed on an
+# "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+# KIND, either express or implied.  See the License for the
+# specific language governing permissions and limitations
+# under the License.
+
+
+import os
+from random import randint
+from uuid import uuid4
+
+import pytest
+
+pytest.importorskip("fsspec")
+
+from opendal.fsspec import OpendalFileSystem
+
+
+@pytest.fixture
+def fs(operator):
+    return OpendalFileSystem(operator=operator, skip_instance_cache=True)
+
+
+@pytest.mark.need_capability("read", "write", "delete", "stat", "list")
+def test_fsspec_file_roundtrip(service_name, operator, fs):
+    size = randint(1, 1024)
+    parent = f"random_dir_{str(uuid4())}"
+    path = f"{parent}/file"
+    content = os.urandom(size)
+
+    fs.pipe_file(f"odal://{path}", content)
+    assert fs.exists(path)
+    assert fs.cat_file(path) == content
+    assert fs.cat_file(path, start=1, end=size) == content[1:]
+    assert fs.cat_file(path, start=-1) == content[-1:]
+
+    info = fs.info(path)
+    assert info["name"] == path
+    assert info["type"] == "file"
+    assert info["size"] == size
+
+    assert fs.ls(parent, detail=False) == [path]
+    [entry] = fs.ls(parent)
+    assert entry["name"] == path
+    assert entry["size"] == size
+
+    with fs.open(path, "rb") as f:
+        assert f.fs is fs
+        assert f.path == path
+        assert f.read() == content
+        f.seek(size // 2)
+        assert f.read(1) == content[size // 2 : size // 2 + 1]
+
+    fs.rm(path)
+    assert not fs.exists(path)
+    with pytest.raises(FileNotFoundError):
+        fs.info(path)
+    with pytest.raises(FileNotFoundError):
+        fs.cat_file(path)
+
+
+@pytest.mark.need_capability("read", "write", "delete", "stat", "list")
+def test_fsspec_open_write(service_name, operator, fs):
+    parent = f"random_dir_{str(uuid4())}"
+    path = f"{parent}/file"
+    content = os.urandom(3 * 1024 + 5)
+
+    with fs.open(path, "wb", block_size=1024) as f:
+        assert f.fs is fs
+        assert f.path == path
+        for i in range(0, len(content), 100):
+            f.write(content[i : i + 100])
+    assert fs.cat_file(path) == content
+
+    with fs.open(path, "wb"):
+        pass
+    assert fs.cat_file(path) == b""
+    [entry] = fs.ls(parent)
+    assert entry["size"] == 0
+    fs.rm(path)
+
+
+@pytest.mark.need_capability("write", "delete", "stat", "list", "create_dir")
+def test_fsspec_directories(service_name, operator, fs):
+    parent = f"random_dir_{str(uuid4())}"
+
+    fs.makedirs(f"{parent}/sub", exist_ok=True)
+    assert fs.info(f"{parent}/sub")["type"] == "directory"
+    with pytest.raises(FileExistsError):
+        fs.makedirs(f"{parent}/sub")
+
+    fs.pipe_file(f"{parent}/sub/file", b"data")
+    assert fs.ls(parent, detail=False) == [f"{parent}/sub"]
+
+    fs.rm(parent, recursive=True)
+    assert not fs.exists(f"{parent}/sub/file")
@@ -0,0 +1,72 @@
+# Licensed to the Apache Software Foundation (ASF) under one
+# or more contributor license agreements.  See the NOTICE file
+# distributed with this work for additional information
+# regarding copyright ownership.  The ASF licenses this file
+# to you under the Apache License, Version 2.0 (the
+# "License"); you may not use this file except in compliance
+# with the License.  You may obtain a copy of the License at
+#
+#   http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing,
+# software distributed under the License is distributed on an
+# "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+# KIND, either express or implied.  See the License for the
+# specific language governing permissions and limitations
+# under the License.
+
+import pickle
+from http.server import BaseHTTPRequestHandler
+
+import pytest
+
+import opendal
+
+
+class _ProxyHandler(BaseHTTPRequestHandler):
+    requests = []
+
+    def do_HEAD(self):
+        self.requests.append(self.path)
+        self.send_response(200)
+        self.send_header("Content-Length", "3")
+        self.end_headers()
+
+    def log_message(self, *args):
+        pass
+
+
+def test_http_options_proxy(service_name, operator, async_operator, http_server):
+    server = http_server(_ProxyHandler)
+    op = opendal.Operator(
+        "http",
+        endpoint="http://opendal.invalid",
+        http_options={
+            "proxy_url": f"http://127.0.0.1:{server.server_port}",
+            "connect_timeout": 5,
+            "pool_max_idle_per_host": 1,
+        },
+    )
+
+    assert op.stat("file").content_length == 3
+    assert pickle.loads(pickle.dumps(op)).stat("other").content_length == 3
+    assert list(_ProxyHandler.requests) == [
+        "http://opendal.invalid/file",
+        "http://opendal.invalid/other",
+    ]
+
+
+def test_http_options_invalid(service_name, operator, async_operator, tmp_path):
+    with pytest.raises(ValueError):
+        opendal.Operator("http", endpoint="http://x", http_options={"proxy_url": "::"})
+    with pytest.raises(FileNotFoundError):
+        opendal.Operator(
+            "http", endpoint="http://x", http_options={"ca_cert_path": "missing.pem"}
+        )
+
+    bad_pem = tmp_path / "bad.pem"
+    bad_pem.write_text("not a certificate")
+    with pytest.raises(ValueError):
+        opendal.Operator(
+            "http", endpoint="http://x", http_options={"ca_cert_path": str(bad_pem)}
+        )
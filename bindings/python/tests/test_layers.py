@@ -0,0 +1,418 @@
+# Licensed to the Apache Software Foundation (ASF) under one
+# or more contributor license agreements.  See the NOTICE file
+# distributed with this work for additional information
+# regarding copyright ownership.  The ASF licenses this file
+# to you under the Apache License, Version 2.0 (the
+# "License"); you may not use this file except in compliance
+# with the License.  You may obtain a copy of the License at
+#
+#   http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing,
+# software distributed under the License is distributed on an
+# "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+# KIND, either express or implied.  See the License for the
+# specific language governing permissions and limitations
+# under the License.
+
+
+import asyncio
+import logging
+import os
+import time
+from datetime import timedelta
+from http.server import BaseHTTPRequestHandler
+from uuid import uuid4
+
+import pytest
+
+import opendal
+from opendal.exceptions import NotFound, Unexpected
+from opendal.layers import (
+    CacheLayer,
+    ConcurrentLimitLayer,
+    LoggingLayer,
+    MetricsLayer,
+    MimeGuessLayer,
+    RetryLayer,
+    ThrottleLayer,
+    TimeoutLayer,
+    TracingLayer,
+)
+
+
+@pytest.mark.need_capability("read", "write", "delete")
+def test_retry_layer(service_name, operator, async_operator):
+    op = operator.layer(
+        RetryLayer(
+            max_times=5,
+            factor=2.0,
+            jitter=True,
+            min_delay=0.1,
+            max_delay=timedelta(seconds=10),
+        )
+    )
+    filename = f"random_file_{str(uuid4())}"
+    content = os.urandom(16)
+    op.write(filename, content)
+    assert op.read(filename) == content
+    op.delete(filename)
+
+
+@pytest.mark.filterwarnings("ignore::pytest.PytestUnraisableExceptionWarning")
+def test_retry_layer_on_retry(service_name, operator, async_operator):
+    calls = []
+
+    def on_retry(err, attempt, delay):
+        calls.append((err, attempt, delay))
+        raise RuntimeError("must not stop the retries")
+
+    # Nothing listens on port 1, so every attempt fails with a temporary error.
+    op = opendal.Operator("http", endpoint="http://127.0.0.1:1").layer(
+        RetryLayer(max_times=3, min_delay=0.01, on_retry=on_retry)
+    )
+    with pytest.raises(Unexpected):
+        op.stat("file")
+    assert [attempt for _, attempt, _ in calls] == [1, 2, 3]
+    assert all(isinstance(err, Unexpected) for err, _, _ in calls)
+    assert all(err.is_temporary() for err, _, _ in calls)
+    assert all(delay > 0 for _, _, delay in calls)
+
+    with pytest.raises(TypeError):
+        RetryLayer(on_retry=1)
+
+
+def test_retry_layer_on_retry_blocking_call(service_name, operator, async_operator):
+    errors = []
+
+    def on_retry(err, attempt, delay):
+        # The callback runs inside the runtime, where blocking calls can't.
+        try:
+            opendal.Operator("memory").exists("file")
+        except RuntimeError as e:
+            errors.append(e)
+
+    op = opendal.Operator("http", endpoint="http://127.0.0.1:1").layer(
+        RetryLayer(max_times=1, min_delay=0.01, on_retry=on_retry)
+    )
+    with pytest.raises(Unexpected):
+        op.stat("file")
+    assert len(errors) == 1
+    assert "AsyncOperator" in str(errors[0])
+
+
+def test_blocking_call_in_event_loop(service_name, operator, async_operator):
+    filename = f"random_file_{str(uuid4())}"
+
+    async def blocking():
+        return operator.exists(filename)
+
+    with pytest.raises(RuntimeError, match="AsyncOperator"):
+        asyncio.run(blocking())
+
+    # Off the loop's thread, like in an executor, blocking calls are fine.
+    async def in_executor():
+        loop = asyncio.get_running_loop()
+        return await loop.run_in_executor(None, operator.exists, filename)
+
+    assert asyncio.run(in_executor()) is False
+
+
+@pytest.mark.need_capability("read", "write", "delete")
+def test_timeout_layer(service_name, operator, async_operator):
+    op = operator.layer(TimeoutLayer(timeout=30, io_timeout=timedelta(seconds=10)))
+    filename = f"random_file_{str(uuid4())}"
+    content = os.urandom(16)
+    op.write(filename, content)
+    assert op.read(filename) == content
+    op.delete(filename)
+
+
+class _SlowHandler(BaseHTTPRequestHandler):
+    """Answer stats late and stall reads halfway through the body."""
+
+    def do_HEAD(self):
+        time.sleep(2)
+        self.send_response(200)
+        self.send_header("Content-Length", "11")
+        self.end_headers()
+
+    def do_GET(self):
+        self.send_response(200)
+        self.send_header("Content-Length", "11")
+        self.end_headers()
+        self.wfile.write(b"hello")
+        self.wfile.flush()
+        time.sleep(2)
+        self.wfile.write(b" world")
+
+    def log_message(self, *args):
+        pass
+
+
+def test_timeout_layer_reached(service_name, operator, async_operator, http_server):
+    server = http_server(_SlowHandler)
+    endpoint = f"http://127.0.0.1:{server.server_port}"
+    layer = TimeoutLayer(timeout=0.2, io_timeout=timedelta(milliseconds=200))
+    op = opendal.Operator("http", endpoint=endpoint).layer(layer)
+    for call, message in (
+        (lambda: op.stat("file"), "operation timeout reached"),
+        (lambda: op.read("file"), "io operation timeout reached"),
+    ):
+        start = time.monotonic()
+        with pytest.raises(Unexpected, match=message) as excinfo:
+            call()
+        assert 0.2 <= time.monotonic() - start < 1.5
+        assert excinfo.value.is_temporary()
+
+
+@pytest.mark.asyncio
+@pytest.mark.need_capability("read", "write", "delete")
+async def test_concurrent_limit_layer(service_name, operator, async_operator):
+    for limit in (0, -1):
+        with pytest.raises(ValueError):
+            ConcurrentLimitLayer(limit)
+
+    op = async_operator.layer(ConcurrentLimitLayer(2))
+    filenames = [f"random_file_{str(uuid4())}" for _ in range(8)]
+    await asyncio.gather(*(op.write(f, b"content") for f in filenames))
+    assert all(await asyncio.gather(*(op.exists(f) for f in filenames)))
+    await asyncio.gather(*(op.delete(f) for f in filenames))
+
+
+@pytest.mark.need_capability("read", "write", "delete")
+def test_throttle_layer(service_name, operator, async_operator):
+    with pytest.raises(ValueError):
+        ThrottleLayer(0, 1024)
+    with pytest.raises(ValueError):
+        ThrottleLayer(1024, 0)
+
+    op = operator.layer(ThrottleLayer(bandwidth=64 * 1024, burst=16 * 1024))
+    filename = f"random_file_{str(uuid4())}"
+    content = os.urandom(48 * 1024)
+    start = time.monotonic()
+    op.write(filename, content)
+    assert op.read(filename) == content
+    # 96 KiB moved against a 16 KiB burst must be paid back at 64 KiB/s.
+    assert time.monotonic() - start >= 1
+    op.delete(filename)
+
+
+@pytest.mark.need_capability("read", "write", "delete")
+def test_cache_layer(service_name, operator, async_operator):
+    with pytest.raises(ValueError):
+        CacheLayer(0, 60)
+    with pytest.raises(ValueError):
+        CacheLayer(1000, 0)
+    with pytest.raises(ValueError):
+        CacheLayer(1000, 60, max_entry_size=0)
+
+    cached = operator.layer(CacheLayer(max_entries=1, ttl=timedelta(minutes=1)))
+    filename = f"random_file_{str(uuid4())}"
+    other = f"random_file_{str(uuid4())}"
+    operator.write(filename, b"first")
+    assert cached.read(filename) == b"first"
+    # Writes bypassing the layer aren't seen until the entry expires.
+    operator.write(filename, b"second")
+    assert cached.read(filename) == b"first"
+    assert cached.read(filename, offset=1, size=3) == b"irs"
+
+    cached.write(filename, b"third")
+    assert cached.read(filename) == b"third"
+    # The only entry is evicted to make room for another path.
+    operator.write(other, b"other")
+    assert cached.read(other) == b"other"
+    operator.write(filename, b"fourth")
+    assert cached.read(filename) == b"fourth"
+
+    cached.delete(filename)
+    with pytest.raises(NotFound):
+        cached.read(filename)
+    operator.delete(other)
+
+    # Objects over max_entry_size are streamed, not cached.
+    small = operator.layer(CacheLayer(max_entries=10, ttl=60, max_entry_size=8))
+    operator.write(filename, b"a large object")
+    assert small.read(filename) == b"a large object"
+    operator.write(filename, b"changed")
+    assert small.read(filename) == b"changed"
+    operator.write(filename, b"changed again")
+    assert small.read(filename) == b"changed"
+    operator.delete(filename)
+
+    expiring = operator.layer(CacheLayer(max_entries=1000, ttl=0.1))
+    operator.write(filename, b"first")
+    assert expiring.read(filename) == b"first"
+    operator.write(filename, b"second")
+    time.sleep(0.2)
+    assert expiring.read(filename) == b"second"
+    operator.delete(filename)
+
+
+@pytest.mark.need_capability("write", "write_with_content_type", "delete", "stat")
+def test_mime_guess_layer(service_name, operator, async_operator):
+    op = operator.layer(MimeGuessLayer())
+    guessed = f"random_file_{str(uuid4())}.pdf"
+    explicit = f"random_file_{str(uuid4())}.pdf"
+    op.write(guessed, b"%PDF")
+    op.write(explicit, b"%PDF", content_type="text/plain")
+    assert op.stat(guessed).content_type == "application/pdf"
+    assert op.stat(explicit).content_type == "text/plain"
+    op.delete(guessed)
+    op.delete(explicit)
+
+
+@pytest.mark.need_capability("read", "write", "delete")
+def test_layer_returns_new_operator(service_name, operator, async_operator):
+    base_repr = repr(operator)
+    fast = operator.layer(ConcurrentLimitLayer(256))
+    safe = operator.layer(RetryLayer(max_times=10))
+    assert fast is not operator
+    assert safe is not operator
+    assert repr(operator) == base_repr
+    assert repr(fast).endswith("ConcurrentLimitLayer])")
+    assert repr(safe).endswith("RetryLayer])")
+    assert repr(safe.layer(TimeoutLayer())).endswith("RetryLayer, TimeoutLayer])")
+
+    filename = f"random_file_{str(uuid4())}"
+    content = os.urandom(16)
+    fast.write(filename, content)
+    assert safe.read(filename) == content
+    assert operator.read(filename) == content
+    operator.delete(filename)
+
+
+@pytest.mark.need_capability("read", "write", "delete", "stat")
+def test_logging_layer(service_name, operator, async_operator, caplog):
+    op = operator.layer(LoggingLayer("opendal.test"))
+    filename = f"random_file_{str(uuid4())}"
+
+    caplog.set_level(logging.INFO, logger="opendal.test")
+    op.write(filename, b"content")
+    assert not caplog.records
+
+    caplog.set_level(logging.DEBUG, logger="opendal.test")
+    op.write(filename, b"content")
+    records = [r for r in caplog.records if r.operation == "write"]
+    assert records
+    assert all(r.path == filename for r in records)
+    assert records[0].outcome == "started"
+
+    caplog.clear()
+    with pytest.raises(NotFound):
+        op.stat(f"not_exist_{str(uuid4())}")
+    assert caplog.records[-1].levelno == logging.WARNING
+    assert caplog.records[-1].outcome == "failed"
+    op.delete(filename)
+
+
+@pytest.mark.need_capability("read", "write", "delete", "stat", "list")
+def test_metrics_layer(service_name, operator, async_operator):
+    calls = []
+    op = operator.layer(MetricsLayer(lambda *args: calls.append(args)))
+    filename = f"random_file_{str(uuid4())}"
+
+    op.write(filename, b"content")
+    assert op.read(filename) == b"content"
+    op.stat(filename)
+    list(op.list("/"))
+    op.delete(filename)
+    with pytest.raises(NotFound):
+        op.stat(filename)
+
+    deadline = time.monotonic() + 5
+    while len(calls) < 6 and time.monotonic() < deadline:
+        time.sleep(0.01)
+    operations = [c[0] for c in calls]
+    for operation in ("write", "read", "stat", "list", "delete"):
+        assert operation in operations
+    assert all(c[1] == service_name for c in calls)
+    assert all(isinstance(c[2], float) and c[2] >= 0 for c in calls)
+    assert calls[-1][0] == "stat"
+    assert calls[-1][3] == "NotFound"
+    assert all(c[3] == "ok" for c in calls[:-1])
+
+    with pytest.raises(TypeError):
+        MetricsLayer("not callable")
+
+
+@pytest.mark.need_capability("read", "write", "delete", "stat")
+def test_tracing_layer(service_name, operator, async_operator):
+    pytest.importorskip("opentelemetry.sdk")
+    from opentelemetry import trace
+    from opentelemetry.sdk.trace import TracerProvider
+    from opentelemetry.sdk.trace.export import SimpleSpanProcessor
+    from opentelemetry.sdk.trace.export.in_memory_span_exporter import (
+        InMemorySpanExporter,
+    )
+
+    exporter = InMemorySpanExporter()
+    provider = TracerProvider()
+    provider.add_span_processor(SimpleSpanProcessor(exporter))
+    tracer = provider.get_tracer("test")
+    op = operator.layer(TracingLayer(tracer))
+    filename = f"random_file_{str(uuid4())}"
+
+    with tracer.start_as_current_span("caller") as caller:
+        op.write(filename, b"content")
+        assert op.read(filename) == b"content"
+        op.delete(filename)
+        with pytest.raises(NotFound):
+            op.stat(filename)
+
+    deadline = time.monotonic() + 5
+    while len(exporter.get_finished_spans()) < 5 and time.monotonic() < deadline:
+        time.sleep(0.01)
+    spans = [s for s in exporter.get_finished_spans() if s.name != "caller"]
+    names = [s.name for s in spans]
+    for name in ("opendal.write", "opendal.read", "opendal.stat", "opendal.delete"):
+        assert name in names
+    caller_id = caller.get_span_context().span_id
+    assert all(s.parent.span_id == caller_id for s in spans)
+    assert all(s.attributes["opendal.scheme"] == service_name for s in spans)
+    assert spans[-1].attributes["opendal.path"] == filename
+    assert spans[-1].attributes["error.type"] == "NotFound"
+    assert spans[-1].status.status_code == trace.StatusCode.ERROR
+
+    # Async operations nest under the span active when they're called.
+    exporter.clear()
+    aop = async_operator.layer(TracingLayer(tracer))
+
+    async def traced():
+        with tracer.start_as_current_span("caller") as caller:
+            await aop.write(filename, b"content")
+            future = aop.delete(filename)
+        await future
+        return caller
+
+    caller = asyncio.run(traced())
+    deadline = time.monotonic() + 5
+    while len(exporter.get_finished_spans()) < 3 and time.monotonic() < deadline:
+        time.sleep(0.01)
+    spans = [s for s in exporter.get_finished_spans() if s.name != "caller"]
+    assert {"opendal.write", "opendal.delete"} <= {s.name for s in spans}
+    caller_id = caller.get_span_context().span_id
+    assert all(s.parent.span_id == caller_id for s in spans)
+
+    # Async operations nest under the explicit parent.
+    exporter.clear()
+    trace_id, span_id = 0x1234, 0x5678
+    aop = async_operator.layer(TracingLayer(tracer, parent=(trace_id, span_id)))
+
+    async def write_and_delete():
+        await aop.write(filename, b"content")
+        await aop.delete(filename)
+
+    asyncio.run(write_and_delete())
+    deadline = time.monotonic() + 5
+    while len(exporter.get_finished_spans()) < 2 and time.monotonic() < deadline:
+        time.sleep(0.01)
+    spans = exporter.get_finished_spans()
+    assert spans
+    assert all(s.context.trace_id == trace_id for s in spans)
+    assert all(s.parent.span_id == span_id for s in spans)
+
+    with pytest.raises(TypeError):
+        TracingLayer(tracer, parent="not a parent")
+    with pytest.raises(ValueError):
+        TracingLayer(tracer, parent=(0, 0))
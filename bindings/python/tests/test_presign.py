@@ -0,0 +1,74 @@
+# Licensed to the Apache Software Foundation (ASF) under one
+# or more contributor license agreements.  See the NOTICE file
+# distributed with this work for additional information
+# regarding copyright ownership.  The ASF licenses this file
+# to you under the Apache License, Version 2.0 (the
+# "License"); you may not use this file except in compliance
+# with the License.  You may obtain a copy of the License at
+#
+#   http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing,
+# software distributed under the License is distributed on an
+# "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+# KIND, either express or implied.  See the License for the
+# specific language governing permissions and limitations
+# under the License.
+
+
+from datetime import timedelta
+from uuid import uuid4
+
+import pytest
+
+from opendal.exceptions import Unsupported
+
+
+@pytest.mark.need_capability("presign", "presign_read")
+def test_sync_presign_read(service_name, operator, async_operator):
+    filename = f"random_file_{str(uuid4())}"
+    req = operator.presign_read(filename, 60)
+    assert req.method == "GET"
+    assert req.url
+
+    req = operator.presign_read(filename, timedelta(minutes=1))
+    assert req.method == "GET"
+    assert isinstance(req.headers, dict)
+
+
+@pytest.mark.asyncio
+@pytest.mark.need_capability("presign", "presign_read")
+async def test_async_presign_read(service_name, operator, async_operator):
+    filename = f"random_file_{str(uuid4())}"
+    req = await async_operator.presign_read(filename, timedelta(minutes=1))
+    assert req.method == "GET"
+    assert req.url
+
+
+@pytest.mark.need_capability("presign", "presign_read", "presign_stat")
+def test_sync_presign(service_name, operator, async_operator):
+    filename = f"random_file_{str(uuid4())}"
+    req = operator.presign(filename, "read", timedelta(minutes=1))
+    assert req.method == "GET"
+    assert req.url
+    req = operator.presign(filename, "stat", 60)
+    assert req.method == "HEAD"
+    with pytest.raises(ValueError):
+        operator.presign(filename, "list", 60)
+
+
+@pytest.mark.asyncio
+@pytest.mark.need_capability("presign", "presign_read")
+async def test_async_presign(service_name, operator, async_operator):
+    filename = f"random_file_{str(uuid4())}"
+    req = await async_operator.presign(filename, "read", timedelta(minutes=1))
+    assert req.method == "GET"
+    assert req.url
+
+
+def test_presign_unsupported(service_name, operator, async_operator):
+    cap = operator.capability()
+    for operation in ("read", "write", "stat", "delete"):
+        if not (cap.presign and getattr(cap, f"presign_{operation}")):
+            with pytest.raises(Unsupported):
+                operator.presign("file", operation, 60)
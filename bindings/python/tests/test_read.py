@@ -15,16 +15,29 @@
 # specific language governing permissions and limitations
 # under the License.
 
+import asyncio
+import base64
+import hashlib
 import io
+import json
 import os
-from datetime import timedelta
+import threading
+import time
+from datetime import datetime, timedelta, timezone
+from http.server import BaseHTTPRequestHandler
 from pathlib import Path
 from random import choices, randint
 from uuid import uuid4
 
 import pytest
 
-from opendal.exceptions import ConditionNotMatch, NotFound
+import opendal
+from opendal.exceptions import (
+    ChecksumMismatch,
+    ConditionNotMatch,
+    NotFound,
+    Unsupported,
+)
 
 
 @pytest.mark.need_capability("read", "write", "delete")
@@ -118,6 +131,96 @@ def test_sync_reader_readline(service_name, operator, async_operator):
     operator.delete(filename)
 
 
+@pytest.mark.need_capability("read", "write", "delete", "stat")
+def test_sync_read_range(service_name, operator, async_operator):
+    size = randint(1, 1024)
+    filename = f"random_file_{str(uuid4())}"
+    content = os.urandom(size)
+    operator.write(filename, content)
+
+    offset = randint(0, size - 1)
+    length = randint(0, size - offset)
+    assert operator.read(filename, offset=offset, size=length) == content[
+        offset : offset + length
+    ]
+    assert operator.read(filename, offset=offset, size=size * 2) == content[offset:]
+    assert operator.read(filename, offset=size * 2) == b""
+    assert operator.read(filename, offset=size * 2, size=10) == b""
+    assert operator.read(filename, range=(offset, size * 2), chunk=7) == (
+        content[offset:]
+    )
+
+    operator.delete(filename)
+
+
+@pytest.mark.need_capability("read", "write", "delete", "stat")
+def test_sync_read_concurrent(service_name, operator, async_operator):
+    filename = f"random_file_{str(uuid4())}"
+    content = os.urandom(10 * 1024 + 7)
+    operator.write(filename, content)
+
+    assert operator.read(filename, concurrent=4, chunk=1024) == content
+    assert operator.read(filename, concurrent=4) == content
+    assert operator.read(filename, concurrent=3, chunk=1000, offset=5, size=4000) == (
+        content[5:4005]
+    )
+    with pytest.raises(ValueError):
+        operator.read(filename, concurrent=0)
+
+    operator.delete(filename)
+
+
+@pytest.mark.need_capability("read", "write", "delete")
+def test_sync_read_stream(service_name, operator, async_operator):
+    filename = f"random_file_{str(uuid4())}"
+    content = b'{"name": "opendal", "tags": ["a", "b"]}'
+    operator.write(filename, content)
+
+    with operator.read_stream(filename) as f:
+        assert f.readable() and f.seekable() and not f.writable()
+        assert json.load(f) == {"name": "opendal", "tags": ["a", "b"]}
+        assert f.tell() == len(content)
+        assert f.read() == b""
+        assert f.seek(-5, 2) == len(content) - 5
+        buf = bytearray(3)
+        assert f.readinto(buf) == 3
+        assert bytes(buf) == content[-5:-2]
+        assert f.seek(1, 1) == len(content) - 1
+        assert f.read(-1) == b"}"
+        assert f.seek(0) == 0
+        assert f.read(5) == content[:5]
+        assert bytes(memoryview(f.getbuffer())) == content
+        with pytest.raises(ValueError):
+            f.seek(-1)
+    assert f.closed
+    with pytest.raises(ValueError):
+        f.read()
+
+    assert operator.read_stream(filename, offset=2, size=4).read() == content[2:6]
+    operator.delete(filename)
+
+
+@pytest.mark.need_capability("read", "write", "delete", "stat")
+def test_sync_reader_with_size(service_name, operator, async_operator):
+    filename = f"random_file_{str(uuid4())}"
+    content = os.urandom(200 * 1024)
+    operator.write(filename, content)
+
+    size, f = operator.reader_with_size(filename)
+    assert size == len(content)
+    with f:
+        assert b"".join(f) == content
+    size, f = operator.reader_with_size(filename, offset=len(content) - 100, size=200)
+    assert (size, f.read()) == (100, content[-100:])
+    size, f = operator.reader_with_size(filename, range=(10, 20))
+    assert (size, f.read()) == (10, content[10:20])
+    size, f = operator.reader_with_size(filename, offset=len(content) + 1)
+    assert (size, f.read()) == (0, b"")
+    with pytest.raises(ValueError):
+        operator.reader_with_size(filename, extra_headers={"x-test": "1"})
+    operator.delete(filename)
+
+
 @pytest.mark.asyncio
 @pytest.mark.need_capability("read", "write", "delete")
 async def test_async_read(service_name, operator, async_operator):
@@ -133,6 +236,63 @@ async def test_async_read(service_name, operator, async_operator):
     await async_operator.delete(filename)
 
 
+@pytest.mark.asyncio
+@pytest.mark.need_capability("read", "write", "delete")
+async def test_async_read_stream(service_name, operator, async_operator):
+    filename = f"random_file_{str(uuid4())}"
+    content = b"key = value\nother = 1\n"
+    await async_operator.write(filename, content)
+
+    f = await async_operator.read_stream(filename)
+    assert io.TextIOWrapper(io.BufferedReader(f)).readlines() == [
+        "key = value\n",
+        "other = 1\n",
+    ]
+    f = await async_operator.read_stream(filename, offset=6)
+    assert f.read() == content[6:]
+    await async_operator.delete(filename)
+
+
+@pytest.mark.asyncio
+@pytest.mark.need_capability("read", "write", "delete", "stat")
+async def test_async_reader_with_size(service_name, operator, async_operator):
+    filename = f"random_file_{str(uuid4())}"
+    content = os.urandom(200 * 1024)
+    await async_operator.write(filename, content)
+
+    size, f = await async_operator.reader_with_size(filename)
+    assert size == len(content)
+    chunks = [chunk async for chunk in f]
+    assert b"".join(chunks) == content
+    size, f = await async_operator.reader_with_size(filename, offset=5, size=10)
+    assert (size, await f.read()) == (10, content[5:15])
+    await async_operator.delete(filename)
+
+
+@pytest.mark.asyncio
+@pytest.mark.need_capability("read", "write", "delete", "stat")
+async def test_async_read_range(service_name, operator, async_operator):
+    size = randint(1, 1024)
+    filename = f"random_file_{str(uuid4())}"
+    content = os.urandom(size)
+    await async_operator.write(filename, content)
+
+    offset = randint(0, size - 1)
+    length = randint(0, size - offset)
+    read_content = await async_operator.read(filename, offset=offset, size=length)
+    assert read_content == content[offset : offset + length]
+    read_content = await async_operator.read(filename, offset=offset, size=size * 2)
+    assert read_content == content[offset:]
+    assert await async_operator.read(filename, offset=size * 2) == b""
+    assert await async_operator.read(filename, offset=size * 2, size=10) == b""
+    read_content = await async_operator.read(
+        filename, range=(offset, size * 2), chunk=7
+    )
+    assert read_content == content[offset:]
+
+    await async_operator.delete(filename)
+
+
 @pytest.mark.asyncio
 @pytest.mark.need_capability("read", "write", "delete")
 async def test_async_read_path(service_name, operator, async_operator):
@@ -177,6 +337,15 @@ async def test_async_reader(service_name, operator, async_operator):
         assert read_content is not None
         assert read_content == content
 
+    buf = bytearray(size)
+    async with await async_operator.open(filename, "rb") as reader:
+        n = await reader.readinto(memoryview(buf))
+        assert buf[:n] == content[:n]
+        while n < size:
+            n += await reader.readinto(memoryview(buf)[n:])
+        assert bytes(buf) == content
+        assert await reader.readinto(buf) == 0
+
     range_start = randint(0, len(content) - 1)
     range_end = randint(range_start, len(content) - 1)
 
@@ -226,6 +395,22 @@ def test_sync_read_stat(service_name, operator, async_operator):
     operator.delete(filename)
 
 
+@pytest.mark.need_capability("read", "write", "delete", "stat")
+def test_sync_stat_typed_metadata(service_name, operator, async_operator):
+    filename = f"random_file_{str(uuid4())}"
+    operator.write(filename, os.urandom(16))
+
+    metadata = operator.stat(filename)
+    assert isinstance(metadata.content_length, int)
+    if metadata.last_modified is not None:
+        assert metadata.last_modified.utcoffset() == timedelta(0)
+    for field in ("etag", "content_md5", "content_type", "version"):
+        value = getattr(metadata, field)
+        assert value is None or (isinstance(value, str) and value != "")
+
+    operator.delete(filename)
+
+
 @pytest.mark.asyncio
 @pytest.mark.need_capability("read", "write", "delete", "stat")
 async def test_async_read_stat(service_name, operator, async_operator):
@@ -241,8 +426,6 @@ async def test_async_read_stat(service_name, operator, async_operator):
 
     await async_operator.delete(filename)
 
-    operator.delete(filename)
-
 
 @pytest.mark.need_capability("read")
 def test_sync_read_not_exists(service_name, operator, async_operator):
@@ -284,3 +467,464 @@ def test_sync_conditional_reads(service_name, operator):
         operator.read(path, if_unmodified_since=before)
 
     operator.delete(path)
+
+
+@pytest.mark.need_capability(
+    "read", "stat", "read_with_if_match", "read_with_if_none_match"
+)
+def test_sync_conditional_reads_etag(service_name, operator):
+    path = f"random_file_{str(uuid4())}"
+    content = b"test data"
+    operator.write(path, content)
+
+    etag = operator.stat(path).etag
+    assert etag is not None
+
+    assert operator.read(path, if_match=etag) == content
+    with pytest.raises(ConditionNotMatch):
+        operator.read(path, if_match=f"{etag}-stale")
+    with pytest.raises(ConditionNotMatch):
+        operator.read(path, if_none_match=etag)
+
+    operator.delete(path)
+
+
+@pytest.mark.asyncio
+@pytest.mark.need_capability(
+    "stat", "stat_with_if_modified_since", "stat_with_if_unmodified_since"
+)
+async def test_async_conditional_stat(service_name, operator, async_operator):
+    path = f"random_file_{str(uuid4())}"
+    await async_operator.write(path, b"test data")
+
+    mod_time = (await async_operator.stat(path)).last_modified
+    assert mod_time is not None
+
+    # Any timezone-aware datetime is accepted.
+    before = (mod_time - timedelta(minutes=1)).astimezone(timezone(timedelta(hours=8)))
+    meta = await async_operator.stat(path, if_modified_since=before)
+    assert meta.last_modified == mod_time
+
+    with pytest.raises(ConditionNotMatch):
+        await async_operator.stat(path, if_unmodified_since=before)
+
+    await async_operator.delete(path)
+
+
+@pytest.mark.need_capability("read", "write", "delete")
+def test_sync_file_seek(service_name, operator, async_operator):
+    filename = f"random_file_{str(uuid4())}"
+    content = os.urandom(1024)
+    operator.write(filename, content)
+
+    with operator.open(filename, "rb") as reader:
+        assert reader.seek(-16, os.SEEK_END) == len(content) - 16
+        assert reader.read() == content[-16:]
+        assert reader.seek(100) == 100
+        assert reader.seek(-50, os.SEEK_CUR) == 50
+        assert reader.tell() == 50
+        assert reader.read(10) == content[50:60]
+        with pytest.raises(ValueError):
+            reader.seek(-1)
+
+    with operator.open(filename, "wb") as writer:
+        with pytest.raises(Unsupported):
+            writer.seek(0)
+    operator.delete(filename)
+
+
+@pytest.mark.need_capability("read", "write", "delete")
+def test_sync_file_range(service_name, operator, async_operator):
+    filename = f"random_file_{str(uuid4())}"
+    content = os.urandom(1024)
+    operator.write(filename, content)
+
+    with operator.open(filename, "rb", range=(100, 300)) as reader:
+        assert reader.read(150) == content[100:250]
+        assert reader.read(150) == content[250:300]
+        assert reader.read() == b""
+        assert reader.seek(0, os.SEEK_END) == 200
+        assert reader.seek(10) == 10
+        assert reader.read() == content[110:300]
+
+    with pytest.raises(ValueError):
+        operator.open(filename, "rb", range=(300, 100))
+    with pytest.raises(ValueError):
+        operator.open(filename, "rb", range=(0, 10), offset=5)
+    operator.delete(filename)
+
+
+@pytest.mark.asyncio
+@pytest.mark.need_capability("read", "write", "delete")
+async def test_async_file_range(service_name, operator, async_operator):
+    filename = f"random_file_{str(uuid4())}"
+    content = os.urandom(1024)
+    await async_operator.write(filename, content)
+
+    async with await async_operator.open(filename, "rb", range=(512, 1000)) as reader:
+        assert await reader.read() == content[512:1000]
+        assert await reader.read() == b""
+        assert await reader.seek(-8, os.SEEK_END) == 480
+        assert await reader.read() == content[992:1000]
+    await async_operator.delete(filename)
+
+
+@pytest.mark.asyncio
+@pytest.mark.need_capability("read", "write", "delete")
+async def test_async_file_seek(service_name, operator, async_operator):
+    filename = f"random_file_{str(uuid4())}"
+    content = os.urandom(1024)
+    await async_operator.write(filename, content)
+
+    async with await async_operator.open(filename, "rb") as reader:
+        assert await reader.seek(-16, os.SEEK_END) == len(content) - 16
+        assert await reader.read() == content[-16:]
+        assert await reader.seek(100) == 100
+        assert await reader.seek(-50, os.SEEK_CUR) == 50
+        assert await reader.tell() == 50
+        assert await reader.read(10) == content[50:60]
+
+    async with await async_operator.open(filename, "wb") as writer:
+        with pytest.raises(Unsupported):
+            await writer.seek(0)
+    await async_operator.delete(filename)
+
+
+@pytest.mark.asyncio
+@pytest.mark.need_capability("read", "write", "delete")
+async def test_async_file_prefetch(service_name, operator, async_operator):
+    filename = f"random_file_{str(uuid4())}"
+    content = os.urandom(10 * 1024 + 7)
+    await async_operator.write(filename, content)
+
+    async with await async_operator.open(
+        filename, "rb", concurrent=4, chunk=1024
+    ) as reader:
+        assert await reader.read(100) == content[:100]
+        assert await reader.read(2000) == content[100:2100]
+        assert await reader.seek(5000) == 5000
+        assert await reader.read(3000) == content[5000:8000]
+        assert await reader.seek(10) == 10
+        assert await reader.read() == content[10:]
+
+    with pytest.raises(ValueError):
+        await async_operator.open(filename, "rb", chunk=0)
+    await async_operator.delete(filename)
+
+
+@pytest.mark.need_capability("read", "write", "delete")
+def test_sync_file_read_chunks(service_name, operator, async_operator):
+    filename = f"random_file_{str(uuid4())}"
+    content = os.urandom(1000)
+    operator.write(filename, content)
+
+    with operator.open(filename, "rb") as reader:
+        chunks = list(reader.read_chunks(300))
+    assert [len(chunk) for chunk in chunks] == [300, 300, 300, 100]
+    assert b"".join(chunks) == content
+
+    with operator.open(filename, "rb") as reader:
+        reader.seek(10)
+        assert b"".join(reader) == content[10:]
+        with pytest.raises(ValueError):
+            reader.read_chunks(0)
+    operator.delete(filename)
+
+
+@pytest.mark.asyncio
+@pytest.mark.need_capability("read", "write", "delete")
+async def test_async_file_read_chunks(service_name, operator, async_operator):
+    filename = f"random_file_{str(uuid4())}"
+    content = os.urandom(1000)
+    await async_operator.write(filename, content)
+
+    async with await async_operator.open(filename, "rb") as reader:
+        chunks = [chunk async for chunk in reader.read_chunks(300)]
+    assert [len(chunk) for chunk in chunks] == [300, 300, 300, 100]
+    assert b"".join(chunks) == content
+
+    async with await async_operator.open(filename, "rb") as reader:
+        assert b"".join([chunk async for chunk in reader]) == content
+    await async_operator.delete(filename)
+
+
+@pytest.mark.need_capability("read", "write", "delete")
+def test_sync_read_buffer_protocol(service_name, operator, async_operator):
+    filename = f"random_file_{str(uuid4())}"
+    content = os.urandom(1024)
+    operator.write(filename, content)
+
+    buffer = operator.read(filename, buffer_protocol=True)
+    assert not isinstance(buffer, bytes)
+    assert len(buffer) == len(content)
+    view = memoryview(buffer)
+    del buffer
+    assert view.readonly
+    assert view.tobytes() == content
+
+    ranged = operator.read(filename, offset=10, size=20, buffer_protocol=True)
+    assert bytes(ranged) == content[10:30]
+    operator.delete(filename)
+
+
+@pytest.mark.asyncio
+@pytest.mark.need_capability("read", "write", "delete")
+async def test_async_read_buffer_protocol(service_name, operator, async_operator):
+    filename = f"random_file_{str(uuid4())}"
+    content = os.urandom(1024)
+    await async_operator.write(filename, content)
+
+    buffer = await async_operator.read(filename, buffer_protocol=True)
+    assert memoryview(buffer).tobytes() == content
+    await async_operator.delete(filename)
+
+
+@pytest.mark.asyncio
+@pytest.mark.need_capability("read", "write", "delete", "stat")
+async def test_async_stat_many(service_name, operator, async_operator):
+    paths = [f"random_file_{str(uuid4())}" for _ in range(4)]
+    for i, path in enumerate(paths):
+        await async_operator.write(path, os.urandom(i + 1))
+    missing = f"not_exist_{str(uuid4())}"
+
+    results = await async_operator.stat_many([*paths, missing], concurrency=2)
+    assert [r.content_length for r in results[:-1]] == [1, 2, 3, 4]
+    assert isinstance(results[-1], NotFound)
+
+    with pytest.raises(ValueError):
+        await async_operator.stat_many(paths, concurrency=0)
+    for path in paths:
+        await async_operator.delete(path)
+
+
+@pytest.mark.need_capability("read", "write", "delete", "stat")
+def test_sync_read_verify(service_name, operator, async_operator):
+    filename = f"random_file_{str(uuid4())}"
+    content = os.urandom(1024)
+    operator.write(filename, content)
+
+    assert operator.read(filename, verify=True) == content
+    metadata = operator.stat(filename)
+    if metadata.content_md5 is None:
+        with pytest.raises(ChecksumMismatch):
+            operator.read(filename, require_checksum=True)
+    with pytest.raises(ValueError):
+        operator.read(filename, verify=True, offset=1)
+    operator.delete(filename)
+
+
+def _content_md5(body):
+    return base64.b64encode(hashlib.md5(body).digest()).decode()
+
+
+class _ChecksumHandler(BaseHTTPRequestHandler):
+    body = b"hello world"
+    response_headers = {"Content-MD5": _content_md5(body)}
+
+    def do_HEAD(self):
+        self.send_response(200)
+        self.send_header("Content-Length", str(len(self.body)))
+        for name, value in self.response_headers.items():
+            self.send_header(name, value)
+        self.end_headers()
+
+    def do_GET(self):
+        self.do_HEAD()
+        self.wfile.write(self.body)
+
+    def log_message(self, *args):
+        pass
+
+
+@pytest.mark.asyncio
+async def test_async_read_verify_mismatch(
+    service_name, operator, async_operator, tmp_path, http_server
+):
+    server = http_server(_ChecksumHandler)
+    endpoint = f"http://127.0.0.1:{server.server_port}"
+    op = opendal.AsyncOperator("http", endpoint=endpoint)
+
+    assert await op.read("file", verify=True) == _ChecksumHandler.body
+    _ChecksumHandler.response_headers["Content-MD5"] = _content_md5(b"something else")
+    with pytest.raises(ChecksumMismatch) as excinfo:
+        await op.read("file", verify=True)
+    assert excinfo.value.is_temporary()
+
+    local_path = tmp_path / "file"
+    with pytest.raises(ChecksumMismatch):
+        await op.read_to_file("file", local_path, verify=True)
+    assert not local_path.exists()
+
+    # An etag that looks like an MD5 isn't taken for one.
+    del _ChecksumHandler.response_headers["Content-MD5"]
+    etag = hashlib.md5(b"something else").hexdigest()
+    _ChecksumHandler.response_headers["ETag"] = f'"{etag}"'
+    assert await op.read("file", verify=True) == _ChecksumHandler.body
+    with pytest.raises(ChecksumMismatch):
+        await op.read("file", require_checksum=True)
+
+
+@pytest.mark.need_capability("read", "write", "delete", "stat")
+def test_sync_read_if_modified(service_name, operator, async_operator):
+    filename = f"random_file_{str(uuid4())}"
+    content = os.urandom(1024)
+    operator.write(filename, content)
+
+    epoch = datetime(1970, 1, 1, tzinfo=timezone.utc)
+    metadata, data = operator.read_if_modified(filename, epoch)
+    assert metadata.content_length == len(content)
+    assert data == content
+    last_modified = operator.stat(filename).last_modified
+    if last_modified is not None:
+        metadata, data = operator.read_if_modified(filename, last_modified)
+        assert metadata.content_length == len(content)
+        assert data is None
+    with pytest.raises(NotFound):
+        operator.read_if_modified(f"not_exist_{str(uuid4())}", epoch)
+    operator.delete(filename)
+
+
+@pytest.mark.asyncio
+@pytest.mark.need_capability("read", "write", "delete", "stat")
+async def test_async_read_if_modified(service_name, operator, async_operator):
+    filename = f"random_file_{str(uuid4())}"
+    content = os.urandom(1024)
+    await async_operator.write(filename, content)
+
+    epoch = datetime(1970, 1, 1, tzinfo=timezone.utc)
+    _, data = await async_operator.read_if_modified(filename, epoch)
+    assert data == content
+    last_modified = (await async_operator.stat(filename)).last_modified
+    if last_modified is not None:
+        _, data = await async_operator.read_if_modified(filename, last_modified)
+        assert data is None
+    await async_operator.delete(filename)
+
+
+@pytest.mark.need_capability("read", "write", "delete", "stat")
+def test_sync_read_with_metadata(service_name, operator, async_operator):
+    filename = f"random_file_{str(uuid4())}"
+    content = os.urandom(1024)
+    operator.write(filename, content)
+
+    metadata, data = operator.read_with_metadata(filename)
+    assert data == content
+    assert metadata.is_file
+    assert metadata.content_length == len(content)
+    with pytest.raises(ValueError):
+        operator.read_with_metadata(filename, offset=1)
+    with pytest.raises(NotFound):
+        operator.read_with_metadata(f"not_exist_{str(uuid4())}")
+    operator.delete(filename)
+
+
+class _ChangingHandler(BaseHTTPRequestHandler):
+    """Serve an object whose `HEAD` already sees the next version of it."""
+
+    body = b"hello world"
+
+    def do_HEAD(self):
+        self.send_response(200)
+        self.send_header("Content-Length", "42")
+        self.send_header("ETag", '"next"')
+        self.end_headers()
+
+    def do_GET(self):
+        self.send_response(200)
+        self.send_header("Content-Length", str(len(self.body)))
+        self.send_header("Content-Type", "text/plain")
+        self.send_header("ETag", '"current"')
+        self.send_header("Last-Modified", "Wed, 21 Oct 2015 07:28:00 GMT")
+        self.end_headers()
+        self.wfile.write(self.body)
+
+    def log_message(self, *args):
+        pass
+
+
+@pytest.mark.asyncio
+async def test_async_read_with_metadata(
+    service_name, operator, async_operator, http_server
+):
+    server = http_server(_ChangingHandler)
+    endpoint = f"http://127.0.0.1:{server.server_port}"
+    op = opendal.AsyncOperator("http", endpoint=endpoint)
+
+    metadata, data = await op.read_with_metadata("file")
+    assert data == _ChangingHandler.body
+    assert metadata.etag == '"current"'
+    assert metadata.content_length == len(_ChangingHandler.body)
+    assert metadata.content_type == "text/plain"
+    assert metadata.last_modified == datetime(2015, 10, 21, 7, 28, tzinfo=timezone.utc)
+
+
+class _StallingHandler(BaseHTTPRequestHandler):
+    """Trickle a large body until the client goes away."""
+
+    started = threading.Event()
+    dropped = threading.Event()
+
+    def do_GET(self):
+        self.send_response(200)
+        self.send_header("Content-Length", str(1024 * 1024 * 1024))
+        self.end_headers()
+        self.started.set()
+        try:
+            while True:
+                self.wfile.write(b"x" * 1024)
+                self.wfile.flush()
+                time.sleep(0.01)
+        except (BrokenPipeError, ConnectionResetError):
+            self.dropped.set()
+
+    def log_message(self, *args):
+        pass
+
+
+@pytest.mark.asyncio
+async def test_async_read_cancelled(
+    service_name, operator, async_operator, http_server
+):
+    server = http_server(_StallingHandler)
+    endpoint = f"http://127.0.0.1:{server.server_port}"
+    op = opendal.AsyncOperator("http", endpoint=endpoint)
+    loop = asyncio.get_running_loop()
+
+    future = op.read("file")
+    assert await loop.run_in_executor(None, _StallingHandler.started.wait, 10)
+    future.cancel()
+    with pytest.raises(asyncio.CancelledError):
+        await future
+    # Dropping the Rust future closes the connection instead of leaving the
+    # request running in the background.
+    assert await loop.run_in_executor(None, _StallingHandler.dropped.wait, 10)
+
+
+@pytest.mark.need_capability("read", "write", "delete")
+def test_sync_read_ranges(service_name, operator, async_operator):
+    filename = f"random_file_{str(uuid4())}"
+    content = os.urandom(4 * 1024)
+    operator.write(filename, content)
+
+    ranges = [(4000, 96), (0, 16), (8, 32), (2048, 0), (1024, 100)]
+    parts = operator.read_ranges(filename, ranges)
+    assert parts == [content[off : off + size] for off, size in ranges]
+    assert operator.read_ranges(filename, ranges, gap=0) == parts
+    assert operator.read_ranges(filename, [(4090, 100)]) == [content[4090:]]
+    assert operator.read_ranges(filename, []) == []
+    with pytest.raises(ValueError):
+        operator.read_ranges(filename, ranges, offset=1)
+    operator.delete(filename)
+
+
+@pytest.mark.asyncio
+@pytest.mark.need_capability("read", "write", "delete")
+async def test_async_read_ranges(service_name, operator, async_operator):
+    filename = f"random_file_{str(uuid4())}"
+    content = os.urandom(4 * 1024)
+    await async_operator.write(filename, content)
+
+    ranges = [(3000, 1000), (10, 20), (15, 100)]
+    parts = await async_operator.read_ranges(filename, ranges, concurrent=2)
+    assert parts == [content[off : off + size] for off, size in ranges]
+    await async_operator.delete(filename)
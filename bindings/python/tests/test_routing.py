@@ -0,0 +1,106 @@
+# Licensed to the Apache Software Foundation (ASF) under one
+# or more contributor license agreements.  See the NOTICE file
+# distributed with this work for additional information
+# regarding copyright ownership.  The ASF licenses this file
+# to you under the Apache License, Version 2.0 (the
+# "License"); you may not use this file except in compliance
+# with the License.  You may obtain a copy of the License at
+#
+#   http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing,
+# software distributed under the License is distributed on an
+# "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+# KIND, either express or implied.  See the License for the
+# specific language governing permissions and limitations
+# under the License.
+
+import sys
+
+import pytest
+
+import opendal
+from opendal.exceptions import ConfigInvalid, Unsupported
+
+
+def test_redis_routes_to_database_package():
+    assert opendal._get_service_package("redis") == "opendal_database"
+
+
+def test_redis_operator_from_database_package():
+    pytest.importorskip("opendal_database")
+    op = opendal.Operator("redis", endpoint="tcp://127.0.0.1:6379")
+    assert op.info().scheme == "redis"
+
+
+def test_enabled_services():
+    services = opendal.enabled_services()
+    assert services == sorted(services)
+    assert {"fs", "memory", "s3"} <= set(services)
+
+
+def test_unknown_scheme_unsupported():
+    with pytest.raises(Unsupported, match="not enabled"):
+        opendal.Operator("no_such_service")
+
+
+def test_missing_package_unsupported(monkeypatch):
+    monkeypatch.setitem(sys.modules, "opendal_database", None)
+    with pytest.raises(Unsupported, match=r"pip install opendal\[database\]"):
+        opendal.Operator("redis", endpoint="tcp://127.0.0.1:6379")
+    assert "redis" not in opendal.enabled_services()
+
+
+def test_operator_from_uri(tmp_path):
+    op = opendal.Operator.from_uri(
+        "s3://bucket/prefix/?region=us-east-1&endpoint=http://127.0.0.1:9000",
+        access_key_id="access_key_id",
+        secret_access_key="secret_access_key",
+    )
+    assert op.info().scheme == "s3"
+    assert op.info().name == "bucket"
+    assert op.info().root == "/prefix/"
+
+    op = opendal.Operator.from_uri("fs:///ignored", root=str(tmp_path))
+    op.write("file", b"content")
+    assert (tmp_path / "file").read_bytes() == b"content"
+    assert opendal.AsyncOperator.from_uri("memory://").info().scheme == "memory"
+
+
+def test_operator_from_env(monkeypatch, tmp_path):
+    monkeypatch.setenv("OPENDAL_S3_BUCKET", "bucket")
+    monkeypatch.setenv("OPENDAL_S3_REGION", "us-east-1")
+    monkeypatch.setenv("OPENDAL_S3_ENDPOINT", "http://127.0.0.1:9000")
+    monkeypatch.setenv("OPENDAL_GCS_BUCKET", "other")
+    op = opendal.Operator.from_env("s3", root="/prefix/")
+    assert op.info().scheme == "s3"
+    assert op.info().name == "bucket"
+    assert op.info().root == "/prefix/"
+
+    monkeypatch.setenv("APP_FS_ROOT", str(tmp_path))
+    op = opendal.Operator.from_env("fs", prefix="APP")
+    op.write("file", b"content")
+    assert (tmp_path / "file").read_bytes() == b"content"
+    assert opendal.AsyncOperator.from_env("memory").info().scheme == "memory"
+
+    monkeypatch.delenv("OPENDAL_S3_BUCKET")
+    with pytest.raises(ConfigInvalid, match="OPENDAL_S3_BUCKET"):
+        opendal.Operator.from_env("s3")
+
+    # Keys checked by the service itself point at the variable naming.
+    monkeypatch.setenv("OPENDAL_S3_BUCKET", "bucket")
+    monkeypatch.setenv("OPENDAL_S3_DISABLE_CONFIG_LOAD", "true")
+    monkeypatch.delenv("OPENDAL_S3_REGION")
+    with pytest.raises(ConfigInvalid, match="(?s)region.*OPENDAL_S3_<KEY>"):
+        opendal.Operator.from_env("s3")
+    with pytest.raises(ConfigInvalid, match="APP_S3_<KEY>"):
+        opendal.AsyncOperator.from_env("s3", prefix="APP", bucket="bucket")
+
+
+@pytest.mark.parametrize(
+    "uri",
+    ["not a uri", "s3://key:secret@bucket/prefix", "memory://host/prefix"],
+)
+def test_operator_from_invalid_uri(uri):
+    with pytest.raises(ValueError):
+        opendal.Operator.from_uri(uri)
@@ -0,0 +1,98 @@
+# Licensed to the Apache Software Foundation (ASF) under one
+# or more contributor license agreements.  See the NOTICE file
+# distributed with this work for additional information
+# regarding copyright ownership.  The ASF licenses this file
+# to you under the Apache License, Version 2.0 (the
+# "License"); you may not use this file except in compliance
+# with the License.  You may obtain a copy of the License at
+#
+#   http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing,
+# software distributed under the License is distributed on an
+# "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+# KIND, either express or implied.  See the License for the
+# specific language governing permissions and limitations
+# under the License.
+
+import ast
+import inspect
+from pathlib import Path
+
+import pytest
+
+import opendal
+import opendal_core
+
+STUBS = Path(opendal.__file__).parent
+
+
+def _stub_classes(name):
+    tree = ast.parse((STUBS / name).read_text())
+    return {node.name: node for node in tree.body if isinstance(node, ast.ClassDef)}
+
+
+def _stub_members(node):
+    members = {}
+    for item in node.body:
+        if isinstance(item, (ast.FunctionDef, ast.AsyncFunctionDef)):
+            members[item.name] = item
+        elif isinstance(item, ast.AnnAssign):
+            members[item.target.id] = None
+    return members
+
+
+def _stub_params(func):
+    args = func.args
+    params = [a.arg for a in args.posonlyargs + args.args + args.kwonlyargs]
+    if args.vararg:
+        params.append(f"*{args.vararg.arg}")
+    if args.kwarg:
+        params.append("**")
+    return [p for p in params if p != "self"]
+
+
+def _runtime_params(obj):
+    params = []
+    for p in inspect.signature(obj).parameters.values():
+        if p.kind == p.VAR_KEYWORD:
+            params.append("**")
+        elif p.kind == p.VAR_POSITIONAL:
+            params.append(f"*{p.name}")
+        else:
+            params.append(p.name)
+    return [p for p in params if p not in ("self", "$self")]
+
+
+@pytest.mark.parametrize(
+    "stub, module",
+    [("__init__.pyi", opendal_core), ("layers.pyi", opendal_core.layers)],
+)
+def test_stubs_match_runtime(stub, module):
+    classes = _stub_classes(stub)
+    runtime = {
+        name
+        for name, obj in vars(module).items()
+        if inspect.isclass(obj) and not name.startswith("_")
+    }
+    assert runtime - classes.keys() == set(), "classes missing from the stub"
+
+    for name in sorted(runtime):
+        cls = getattr(module, name)
+        members = _stub_members(classes[name])
+        public = {m for m in vars(cls) if not m.startswith("_")}
+        stubbed = {m for m in members if not m.startswith("_")}
+        assert public == stubbed, f"members of {name} differ"
+
+        for member, func in members.items():
+            if func is None or (member.startswith("_") and member != "__init__"):
+                continue
+            target = cls if member == "__init__" else getattr(cls, member, None)
+            static = inspect.getattr_static(cls, member, None)
+            if target is None or isinstance(static, property):
+                continue
+            try:
+                params = _runtime_params(target)
+            except (TypeError, ValueError):
+                continue
+            assert _stub_params(func) == params, f"parameters of {name}.{member} differ"
@@ -20,6 +20,7 @@ from uuid import uuid4
 
 import pytest
 
+import opendal
 from opendal.exceptions import IsADirectory, IsSameFile, NotFound
 
 
@@ -106,3 +107,58 @@ def test_sync_copy_overwrite(service_name, operator, async_operator):
     assert target_content == source_content
     operator.delete(source_path)
     operator.delete(target_path)
+
+
+@pytest.mark.need_capability("read", "write", "delete")
+def test_sync_copy_from(service_name, operator, async_operator):
+    source_op = opendal.Operator("memory")
+    source_path = f"random_file_{str(uuid4())}"
+    content = os.urandom(1024 * 1024 + 3)
+    source_op.write(source_path, content)
+    target_path = f"random_file_{str(uuid4())}"
+    operator.copy_from(source_op, source_path, target_path)
+    assert operator.read(target_path) == content
+    with pytest.raises(NotFound):
+        operator.copy_from(source_op, f"random_file_{str(uuid4())}", target_path)
+    assert operator.read(target_path) == content
+    operator.delete(target_path)
+
+
+def test_sync_copy_from_same_service(tmp_path):
+    parent = opendal.Operator("fs", root=str(tmp_path))
+    child = opendal.Operator("fs", root=str(tmp_path / "child"))
+    sibling = opendal.Operator("fs", root=str(tmp_path / "sibling"))
+    content = os.urandom(1024)
+    child.write("source", content)
+
+    parent.copy_from(child, "source", "from_child")
+    assert parent.read("from_child") == content
+    child.copy_from(parent, "from_child", "from_parent")
+    assert child.read("from_parent") == content
+    sibling.copy_from(child, "source", "from_sibling")
+    assert sibling.read("from_sibling") == content
+
+
+class _ForeignOperator:
+    """Stands in for the `Operator` of another OpenDAL package."""
+
+    def __init__(self, op):
+        self._op = op
+
+    def open(self, path, mode):
+        return self._op.open(path, mode)
+
+
+def test_sync_copy_from_other_package(tmp_path):
+    source_op = _ForeignOperator(opendal.Operator("fs", root=str(tmp_path)))
+    target_op = opendal.Operator("memory")
+    content = os.urandom(9 * 1024 * 1024 + 3)
+    (tmp_path / "source").write_bytes(content)
+
+    target_op.copy_from(source_op, "source", "target")
+    assert target_op.read("target") == content
+    with pytest.raises(NotFound):
+        target_op.copy_from(source_op, "missing", "target")
+    assert target_op.read("target") == content
+    with pytest.raises(TypeError, match="must be an OpenDAL Operator"):
+        target_op.copy_from(object(), "source", "target")
@@ -46,3 +46,64 @@ def test_sync_remove_all(service_name, operator, async_operator):
             with pytest.raises(NotFound):
                 operator.read(f"{parent}/{path}")
     operator.remove_all(f"{parent}/")
+
+
+@pytest.mark.need_capability("read", "write", "delete", "list")
+def test_sync_remove_all_dry_run(service_name, operator, async_operator):
+    parent = f"random_dir_{str(uuid4())}/"
+    paths = [f"{parent}{i}" for i in range(3)] + [f"{parent}x/y"]
+    for path in paths:
+        operator.write(path, os.urandom(16))
+
+    planned = operator.remove_all(parent, dry_run=True)
+    assert set(paths) <= set(planned)
+    for path in paths:
+        assert operator.exists(path)
+
+    batches = []
+    removed = operator.remove_all(parent, on_delete=batches.append)
+    assert removed == len(planned)
+    assert sorted(p for batch in batches for p in batch) == sorted(planned)
+    for path in paths:
+        assert not operator.exists(path)
+
+    with pytest.raises(TypeError):
+        operator.remove_all(parent, on_delete=1)
+
+
+@pytest.mark.need_capability("write", "delete", "list")
+def test_sync_remove_all_missing_prefix(service_name, operator, async_operator):
+    parent = f"random_dir_{str(uuid4())}/"
+    operator.remove_all(parent)
+    operator.remove_all(f"{parent}missing")
+
+
+@pytest.mark.need_capability("read", "write", "delete")
+def test_sync_delete_iter(service_name, operator, async_operator):
+    parent = f"random_dir_{str(uuid4())}"
+    paths = [f"{parent}/{i}" for i in range(5)]
+    for path in paths:
+        operator.write(path, os.urandom(16))
+
+    # Deleting a missing path is not a failure.
+    result = operator.delete_iter(iter(paths + [f"{parent}/missing"]))
+    assert sorted(result.deleted) == sorted(paths + [f"{parent}/missing"])
+    assert result.failed == {}
+    for path in paths:
+        with pytest.raises(NotFound):
+            operator.read(path)
+
+    with pytest.raises(TypeError):
+        operator.delete_iter(parent)
+
+
+@pytest.mark.need_capability("write", "delete", "stat")
+def test_sync_delete_missing_ok(service_name, operator, async_operator):
+    filename = f"random_file_{str(uuid4())}"
+    operator.delete(filename)
+    with pytest.raises(NotFound):
+        operator.delete(filename, missing_ok=False)
+
+    operator.write(filename, os.urandom(16))
+    operator.delete(filename, missing_ok=False)
+    assert not operator.exists(filename)
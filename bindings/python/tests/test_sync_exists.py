@@ -28,3 +28,10 @@ def test_sync_exists(service_name, operator, async_operator):
     operator.write(target, content)
     assert operator.exists(target)
     assert not operator.exists(target + "1")
+
+    dir_path = f"random_dir_{str(uuid4())}/"
+    operator.create_dir(dir_path)
+    assert operator.exists(dir_path)
+    operator.delete(target)
+    operator.delete(dir_path)
+    assert not operator.exists(target)
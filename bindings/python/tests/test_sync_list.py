@@ -15,10 +15,14 @@
 # specific language governing permissions and limitations
 # under the License.
 
+import json
+from datetime import datetime
 from uuid import uuid4
 
 import pytest
 
+from opendal.exceptions import Unsupported
+
 
 @pytest.mark.need_capability("read", "write", "copy", "list", "list_with_start_after")
 def test_sync_list_with_start_after(service_name, operator, async_operator):
@@ -55,3 +59,233 @@ def test_sync_list_with_start_after(service_name, operator, async_operator):
     )
     # 6. Cleanup
     operator.remove_all(test_dir)
+
+
+@pytest.mark.need_capability("write", "list", "stat", "delete")
+def test_sync_list_entry_metadata(service_name, operator, async_operator):
+    test_dir = f"test_sync_list_dir_{uuid4()}/"
+    content = b"test_content"
+    operator.write(f"{test_dir}file", content)
+
+    for entry in operator.list(test_dir):
+        if not entry.metadata.is_file:
+            continue
+        # Services either fill the size while listing or leave it unset.
+        assert entry.metadata.content_length in (0, len(content))
+        assert operator.stat(entry.path).content_length == len(content)
+
+    operator.remove_all(test_dir)
+
+
+@pytest.mark.need_capability("write", "list", "delete")
+def test_sync_list_iterator(service_name, operator, async_operator):
+    test_dir = f"test_sync_list_dir_{uuid4()}/"
+    files = sorted(f"{test_dir}file_{i}" for i in range(3))
+    for f in files:
+        operator.write(f, b"test_content")
+
+    lister = operator.list(test_dir)
+    assert iter(lister) is lister
+    paths = []
+    while True:
+        try:
+            paths.append(next(lister).path)
+        except StopIteration:
+            break
+    assert sorted(p for p in paths if p != test_dir) == files
+    # An exhausted lister stays exhausted.
+    with pytest.raises(StopIteration):
+        next(lister)
+
+    operator.remove_all(test_dir)
+
+
+@pytest.mark.need_capability("write", "list", "list_with_start_after")
+def test_sync_list_paginate(service_name, operator, async_operator):
+    test_dir = f"test_sync_list_dir_{uuid4()}/"
+    files = sorted(f"{test_dir}file_{i}" for i in range(5))
+    for f in files:
+        operator.write(f, b"test_content")
+
+    pages = []
+    start_after = test_dir
+    while True:
+        page = [
+            entry.path
+            for entry in operator.list(test_dir, start_after=start_after, limit=2)
+        ]
+        if not page:
+            break
+        assert len(page) <= 2
+        pages.append(page)
+        start_after = page[-1]
+    assert [path for page in pages for path in page] == files
+
+    operator.remove_all(test_dir)
+
+
+@pytest.mark.need_capability("write", "list")
+def test_sync_list_limit(service_name, operator, async_operator):
+    test_dir = f"test_sync_list_dir_{uuid4()}/"
+    for i in range(5):
+        operator.write(f"{test_dir}file_{i}", b"test_content")
+
+    assert len(list(operator.list(test_dir, limit=3))) == 3
+    with pytest.raises(ValueError):
+        operator.list(test_dir, limit=0)
+
+    operator.remove_all(test_dir)
+
+
+@pytest.mark.need_capability(
+    "read",
+    "write",
+    "delete",
+    "list_with_versions",
+    "read_with_version",
+    "stat_with_version",
+    "delete_with_version",
+)
+def test_sync_list_versions(service_name, operator, async_operator):
+    test_dir = f"test_sync_list_dir_{uuid4()}/"
+    path = f"{test_dir}file"
+    operator.write(path, b"v1")
+    operator.write(path, b"v2")
+
+    entries = [e for e in operator.list(test_dir, versions=True) if e.path == path]
+    assert len(entries) == 2
+    old = next(e.metadata for e in entries if e.metadata.is_current is False)
+    assert old.version is not None
+    assert operator.read(path, version=old.version) == b"v1"
+    assert operator.stat(path, version=old.version).content_length == 2
+    assert operator.read(path) == b"v2"
+
+    operator.delete(path, version=old.version)
+    assert [e for e in operator.list(test_dir, versions=True) if e.path == path]
+    assert operator.read(path) == b"v2"
+
+    operator.remove_all(test_dir)
+
+
+@pytest.mark.need_capability("list")
+def test_sync_list_versions_unsupported(service_name, operator, async_operator):
+    if not operator.capability().list_with_versions:
+        with pytest.raises(Unsupported):
+            operator.list("", versions=True)
+    if not operator.capability().delete_with_version:
+        with pytest.raises(Unsupported):
+            operator.delete(f"not_exist_{uuid4()}", version="v1")
+
+
+@pytest.mark.need_capability("write", "create_dir", "list", "delete")
+def test_sync_list_entry_mode(service_name, operator, async_operator):
+    test_dir = f"test_sync_list_dir_{uuid4()}/"
+    operator.create_dir(f"{test_dir}sub/")
+    operator.write(f"{test_dir}file", b"test_content")
+
+    modes = {
+        entry.path: (entry.metadata.is_file, entry.metadata.is_dir)
+        for entry in operator.list(test_dir)
+    }
+    assert modes[f"{test_dir}sub/"] == (False, True)
+    assert modes[f"{test_dir}file"] == (True, False)
+    assert operator.stat(f"{test_dir}sub/").mode.is_dir()
+
+    operator.remove_all(test_dir)
+
+
+@pytest.mark.need_capability("write", "list", "delete")
+def test_sync_list_dirs_only(service_name, operator, async_operator):
+    test_dir = f"test_sync_list_dir_{uuid4()}/"
+    for f in ("a.txt", "sub/b.txt", "sub/deep/c.txt", "other/d.txt"):
+        operator.write(f"{test_dir}{f}", b"test_content")
+
+    # `sub/` and `other/` only exist as the prefixes of the files below.
+    entries = sorted(entry.path for entry in operator.list(test_dir, dirs_only=True))
+    assert entries == [f"{test_dir}other/", f"{test_dir}sub/"]
+    assert all(e.metadata.is_dir for e in operator.list(test_dir, dirs_only=True))
+    with pytest.raises(ValueError):
+        operator.list(test_dir, dirs_only=True, recursive=True)
+
+    operator.remove_all(test_dir)
+
+
+@pytest.mark.need_capability("write", "list", "stat", "delete")
+def test_sync_list_entry_to_dict(service_name, operator, async_operator):
+    test_dir = f"test_sync_list_dir_{uuid4()}/"
+    content = b"test_content"
+    operator.write(f"{test_dir}file", content)
+
+    entries = {entry.path: entry.to_dict() for entry in operator.list(test_dir)}
+    entry = json.loads(json.dumps(entries))[f"{test_dir}file"]
+    assert entry["path"] == f"{test_dir}file"
+    assert entry["mode"] == "file"
+
+    metadata = operator.stat(f"{test_dir}file").to_dict()
+    assert json.loads(json.dumps(metadata)) == metadata
+    assert metadata["content_length"] == len(content)
+    if metadata["last_modified"] is not None:
+        last_modified = datetime.fromisoformat(metadata["last_modified"])
+        assert last_modified.utcoffset().total_seconds() == 0
+
+    operator.remove_all(test_dir)
+
+
+@pytest.mark.need_capability("write", "list", "delete")
+def test_sync_glob(service_name, operator, async_operator):
+    test_dir = f"test_sync_glob_dir_{uuid4()}/"
+    files = [
+        "logs/2024-01/a.json",
+        "logs/2024-01/deep/b.json",
+        "logs/2024-02/c.txt",
+        "logs/2023-12/d.json",
+    ]
+    for f in files:
+        operator.write(f"{test_dir}{f}", b"test_content")
+
+    def glob(pattern):
+        return sorted(
+            entry.path[len(test_dir) :] for entry in operator.glob(test_dir + pattern)
+        )
+
+    assert glob("logs/2024-*/**.json") == files[:2]
+    assert glob("logs/2024-*/*.json") == files[:1]
+    assert glob("logs/**/d.json") == files[3:]
+    assert glob("logs/202[!4]-??/*") == files[3:]
+    assert glob("logs/2024-02/c.txt") == files[2:3]
+    assert glob("logs/*.json") == []
+    # Matching takes time linear in the stars, not exponential.
+    operator.write(f"{test_dir}logs/{'a' * 60}", b"test_content")
+    assert glob("logs/*a*a*a*a*a*a*a*b") == []
+    assert glob("logs/*a*a*a*a*a*a*a*a") == [f"logs/{'a' * 60}"]
+    with pytest.raises(ValueError):
+        operator.glob(f"{test_dir}logs/[a")
+
+    operator.remove_all(test_dir)
+
+
+@pytest.mark.need_capability("write", "list", "delete")
+def test_sync_tree(service_name, operator, async_operator):
+    test_dir = f"test_sync_tree_dir_{uuid4()}/"
+    for f in ("a.txt", "sub/b.txt", "sub/deep/c.txt"):
+        operator.write(f"{test_dir}{f}", b"test_content")
+
+    def names(node):
+        return [child["name"] for child in node["children"]]
+
+    tree = operator.tree(test_dir)
+    assert tree["path"] == test_dir
+    assert names(tree) == ["a.txt", "sub/"]
+    a, sub = tree["children"]
+    assert a["path"] == f"{test_dir}a.txt"
+    assert a["metadata"].is_file
+    assert names(sub) == ["b.txt", "deep/"]
+    assert names(sub["children"][1]) == ["c.txt"]
+
+    shallow = operator.tree(test_dir, max_depth=1)
+    assert names(shallow) == ["a.txt", "sub/"]
+    assert shallow["children"][1]["children"] is None
+    with pytest.raises(ValueError):
+        operator.tree(test_dir, max_depth=0)
+
+    operator.remove_all(test_dir)
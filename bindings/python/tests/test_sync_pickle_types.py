@@ -15,8 +15,11 @@
 # specific language governing permissions and limitations
 # under the License.
 
+import copy
+import multiprocessing
 import os
 import pickle
+from concurrent.futures import ProcessPoolExecutor
 from random import randint
 from uuid import uuid4
 
@@ -37,3 +40,35 @@ def test_operator_pickle(service_name, operator, async_operator):
     assert deserialized.read(filename) == content
 
     operator.delete(filename)
+
+
+def _read_in_worker(op, filename):
+    return op.read(filename)
+
+
+@pytest.mark.need_capability("read", "write", "delete", "shared")
+def test_operator_pickle_multiprocessing(service_name, operator, async_operator):
+    filename = f"random_file_{str(uuid4())}"
+    content = os.urandom(randint(1, 1024))
+    operator.write(filename, content)
+
+    context = multiprocessing.get_context("spawn")
+    with ProcessPoolExecutor(max_workers=1, mp_context=context) as executor:
+        assert executor.submit(_read_in_worker, operator, filename).result() == content
+
+    operator.delete(filename)
+
+
+@pytest.mark.need_capability("read", "write", "delete")
+def test_operator_copy(service_name, operator, async_operator):
+    filename = f"random_file_{str(uuid4())}"
+    content = os.urandom(randint(1, 1024))
+    operator.write(filename, content)
+
+    for duplicate in (copy.copy(operator), copy.deepcopy(operator)):
+        assert duplicate is not operator
+        assert repr(duplicate) == repr(operator)
+        assert duplicate.read(filename) == content
+    assert copy.deepcopy(async_operator) is not async_operator
+
+    operator.delete(filename)
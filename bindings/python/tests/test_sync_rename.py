@@ -20,7 +20,7 @@ from uuid import uuid4
 
 import pytest
 
-from opendal.exceptions import IsADirectory, IsSameFile, NotFound
+from opendal.exceptions import IsADirectory, IsSameFile, NotFound, Unsupported
 
 
 @pytest.mark.need_capability("read", "write", "rename")
@@ -104,3 +104,19 @@ def test_sync_rename_overwrite(service_name, operator, async_operator):
     assert operator.read(target_path) == source_content
     operator.delete(target_path)
     operator.delete(source_path)
+
+
+@pytest.mark.need_capability("read", "write", "copy", "delete")
+def test_sync_rename_emulate(service_name, operator, async_operator):
+    if operator.capability().rename:
+        pytest.skip("service supports native rename")
+    source_path = f"random_file_{str(uuid4())}"
+    target_path = f"random_file_{str(uuid4())}"
+    content = os.urandom(1024)
+    operator.write(source_path, content)
+    with pytest.raises(Unsupported):
+        operator.rename(source_path, target_path)
+    operator.rename(source_path, target_path, emulate=True)
+    assert not operator.exists(source_path)
+    assert operator.read(target_path) == content
+    operator.delete(target_path)
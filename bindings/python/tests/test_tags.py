@@ -0,0 +1,124 @@
+# Licensed to the Apache Software Foundation (ASF) under one
+# or more contributor license agreements.  See the NOTICE file
+# distributed with this work for additional information
+# regarding copyright ownership.  The ASF licenses this file
+# to you under the Apache License, Version 2.0 (the
+# "License"); you may not use this file except in compliance
+# with the License.  You may obtain a copy of the License at
+#
+#   http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing,
+# software distributed under the License is distributed on an
+# "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+# KIND, either express or implied.  See the License for the
+# specific language governing permissions and limitations
+# under the License.
+
+
+from http.server import BaseHTTPRequestHandler
+from uuid import uuid4
+
+import pytest
+
+import opendal
+from opendal.exceptions import Unsupported
+
+
+@pytest.mark.need_capability("write", "delete", "tagging")
+def test_sync_tags(service_name, operator, async_operator):
+    filename = f"random_file_{str(uuid4())}"
+    operator.write(filename, b"content")
+    assert operator.get_tags(filename) == {}
+
+    tags = {"team": "data", "cost-center": "a1/b2"}
+    operator.put_tags(filename, tags)
+    assert operator.get_tags(filename) == tags
+    operator.put_tags(filename, {})
+    assert operator.get_tags(filename) == {}
+    operator.delete(filename)
+
+
+@pytest.mark.asyncio
+@pytest.mark.need_capability("write", "delete", "tagging")
+async def test_async_tags(service_name, operator, async_operator):
+    filename = f"random_file_{str(uuid4())}"
+    await async_operator.write(filename, b"content")
+    await async_operator.put_tags(filename, {"team": "data"})
+    assert await async_operator.get_tags(filename) == {"team": "data"}
+    await async_operator.delete(filename)
+
+
+@pytest.mark.need_capability("tagging")
+@pytest.mark.parametrize(
+    "tags",
+    [
+        {"": "v"},
+        {"k" * 129: "v"},
+        {"k": "v" * 257},
+        {"aws:owner": "me"},
+        {"k": "a|b"},
+        {f"k{i}": "v" for i in range(11)},
+    ],
+)
+def test_sync_put_tags_invalid(service_name, operator, async_operator, tags):
+    with pytest.raises(ValueError):
+        operator.put_tags(f"random_file_{str(uuid4())}", tags)
+
+
+def test_sync_tags_unsupported(service_name, operator, async_operator):
+    if operator.capability().tagging:
+        pytest.skip("service supports tagging")
+    filename = f"random_file_{str(uuid4())}"
+    with pytest.raises(Unsupported):
+        operator.put_tags(filename, {"team": "data"})
+    with pytest.raises(Unsupported):
+        operator.get_tags(filename)
+
+
+class _TaggingS3Handler(BaseHTTPRequestHandler):
+    """A bucket that only stores the tagging documents it's sent."""
+
+    tagging = {}
+
+    def do_PUT(self):
+        body = self.rfile.read(int(self.headers["Content-Length"]))
+        self.tagging[self.path] = body
+        self.send_response(200)
+        self.send_header("Content-Length", "0")
+        self.end_headers()
+
+    def do_GET(self):
+        body = self.tagging.get(self.path, b"<Tagging><TagSet></TagSet></Tagging>")
+        self.send_response(200)
+        self.send_header("Content-Length", str(len(body)))
+        self.end_headers()
+        self.wfile.write(body)
+
+    def log_message(self, *args):
+        pass
+
+
+@pytest.mark.asyncio
+async def test_async_tags_s3(service_name, operator, async_operator, http_server):
+    server = http_server(_TaggingS3Handler)
+    op = opendal.AsyncOperator(
+        "s3",
+        bucket="bucket",
+        region="us-east-1",
+        endpoint=f"http://127.0.0.1:{server.server_port}",
+        access_key_id="access_key_id",
+        secret_access_key="secret_access_key",
+        disable_config_load="true",
+        disable_ec2_metadata="true",
+    )
+
+    assert await op.get_tags("file") == {}
+    await op.put_tags("file", {"team": "data", "owner": "a b"})
+    assert _TaggingS3Handler.tagging["/bucket/file?tagging"] == (
+        b"<Tagging><TagSet>"
+        b"<Tag><Key>owner</Key><Value>a b</Value></Tag>"
+        b"<Tag><Key>team</Key><Value>data</Value></Tag>"
+        b"</TagSet></Tagging>"
+    )
+    assert await op.get_tags("file") == {"team": "data", "owner": "a b"}
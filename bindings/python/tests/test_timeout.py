@@ -0,0 +1,109 @@
+# Licensed to the Apache Software Foundation (ASF) under one
+# or more contributor license agreements.  See the NOTICE file
+# distributed with this work for additional information
+# regarding copyright ownership.  The ASF licenses this file
+# to you under the Apache License, Version 2.0 (the
+# "License"); you may not use this file except in compliance
+# with the License.  You may obtain a copy of the License at
+#
+#   http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing,
+# software distributed under the License is distributed on an
+# "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+# KIND, either express or implied.  See the License for the
+# specific language governing permissions and limitations
+# under the License.
+
+import os
+import time
+from datetime import timedelta
+from http.server import BaseHTTPRequestHandler
+from uuid import uuid4
+
+import pytest
+
+import opendal
+from opendal.exceptions import Unexpected
+
+
+@pytest.mark.need_capability("read", "write", "delete", "stat", "list")
+def test_sync_timeout_not_reached(service_name, operator, async_operator):
+    filename = f"random_file_{str(uuid4())}"
+    content = os.urandom(1024)
+    operator.write(filename, content, timeout=30)
+
+    assert operator.read(filename, timeout=timedelta(seconds=30)) == content
+    assert operator.stat(filename, timeout=30).content_length == len(content)
+    entries = [entry.path for entry in operator.list("", timeout=30)]
+    assert filename in entries
+    operator.delete(filename)
+
+
+@pytest.mark.asyncio
+@pytest.mark.need_capability("read", "write", "delete", "stat", "list")
+async def test_async_timeout_not_reached(service_name, operator, async_operator):
+    filename = f"random_file_{str(uuid4())}"
+    content = os.urandom(1024)
+    await async_operator.write(filename, content, timeout=30)
+
+    assert await async_operator.read(filename, timeout=30) == content
+    meta = await async_operator.stat(filename, timeout=timedelta(seconds=30))
+    assert meta.content_length == len(content)
+    entries = [entry.path async for entry in await async_operator.list("", timeout=30)]
+    assert filename in entries
+    await async_operator.delete(filename)
+
+
+class _SlowHandler(BaseHTTPRequestHandler):
+    body = b"hello world"
+
+    def do_HEAD(self):
+        time.sleep(2)
+        self.send_response(200)
+        self.send_header("Content-Length", str(len(self.body)))
+        self.end_headers()
+
+    def do_GET(self):
+        self.do_HEAD()
+        self.wfile.write(self.body)
+
+    def log_message(self, *args):
+        pass
+
+
+@pytest.fixture
+def slow_endpoint(http_server):
+    server = http_server(_SlowHandler)
+    return f"http://127.0.0.1:{server.server_port}"
+
+
+def test_sync_timeout_reached(slow_endpoint):
+    op = opendal.Operator("http", endpoint=slow_endpoint)
+    for call in (
+        lambda: op.read("file", timeout=0.2),
+        lambda: op.stat("file", timeout=timedelta(milliseconds=200)),
+    ):
+        start = time.monotonic()
+        with pytest.raises(Unexpected, match="operation timeout reached") as excinfo:
+            call()
+        # The server answers after two seconds, so only the timeout can end
+        # the call this early.
+        assert 0.2 <= time.monotonic() - start < 1.5
+        assert excinfo.value.is_temporary()
+    assert op.read("file", timeout=5) == _SlowHandler.body
+
+
+@pytest.mark.asyncio
+async def test_async_timeout_reached(slow_endpoint):
+    op = opendal.AsyncOperator("http", endpoint=slow_endpoint)
+    for call in (
+        lambda: op.read("file", timeout=0.2),
+        lambda: op.stat("file", timeout=timedelta(milliseconds=200)),
+    ):
+        start = time.monotonic()
+        with pytest.raises(Unexpected, match="operation timeout reached") as excinfo:
+            await call()
+        assert 0.2 <= time.monotonic() - start < 1.5
+        assert excinfo.value.is_temporary()
+    assert await op.read("file", timeout=5) == _SlowHandler.body
@@ -15,14 +15,21 @@
 # specific language governing permissions and limitations
 # under the License.
 
+import array
 import os
-from pathlib import Path
+from pathlib import Path, PurePosixPath
 from random import randint
 from uuid import uuid4
 
 import pytest
 
-from opendal.exceptions import NotFound
+from opendal.exceptions import (
+    ConditionNotMatch,
+    NotADirectory,
+    NotFound,
+    RangeNotSatisfied,
+    Unsupported,
+)
 
 
 @pytest.mark.need_capability("write", "delete", "stat")
@@ -55,6 +62,23 @@ def test_sync_write_path(service_name, operator, async_operator):
     operator.delete(filename)
 
 
+@pytest.mark.need_capability("write", "read", "delete", "stat")
+def test_sync_path_like(service_name, operator, async_operator):
+    path = PurePosixPath(f"test_dir_{str(uuid4())}") / "test_file.txt"
+    content = os.urandom(16)
+    operator.write(path, content)
+    assert operator.exists(path)
+    assert operator.read(path) == content
+    assert operator.stat(path).content_length == len(content)
+
+    with pytest.raises(TypeError, match="bytes"):
+        operator.read(str(path).encode())
+    with pytest.raises(TypeError, match="bytes"):
+        operator.delete_iter([str(path).encode()])
+    operator.delete_iter([path])
+    assert not operator.exists(path)
+
+
 @pytest.mark.asyncio
 @pytest.mark.need_capability("write", "delete", "stat")
 async def test_async_write(service_name, operator, async_operator):
@@ -195,3 +219,656 @@ def test_sync_writer_options(service_name, operator, async_operator):
         with operator.open(filename, "wb", if_not_exists=True) as w:
             w.write(content)
         assert "ConditionNotMatch" in str(excinfo.value)
+
+
+@pytest.mark.need_capability("write", "delete")
+def test_sync_writer_exit_with_exception(service_name, operator, async_operator):
+    filename = f"test_file_{str(uuid4())}.txt"
+    with pytest.raises(ValueError, match="boom"):
+        with operator.open(filename, "wb") as w:
+            w.write(os.urandom(16))
+            raise ValueError("boom")
+    assert w.closed
+    operator.delete(filename)
+
+
+@pytest.mark.asyncio
+@pytest.mark.need_capability("write", "delete")
+async def test_async_writer_exit_with_exception(service_name, operator, async_operator):
+    filename = f"test_file_{str(uuid4())}.txt"
+    with pytest.raises(ValueError, match="boom"):
+        async with await async_operator.open(filename, "wb") as w:
+            await w.write(os.urandom(16))
+            raise ValueError("boom")
+    assert await w.closed
+    await async_operator.delete(filename)
+
+
+@pytest.mark.need_capability("write", "read", "delete")
+def test_sync_writer_abort(service_name, operator, async_operator):
+    filename = f"test_file_{str(uuid4())}.txt"
+    w = operator.open(filename, "wb")
+    w.write(os.urandom(16))
+    try:
+        w.abort()
+    except Unsupported:
+        pytest.skip("service can't abort writes")
+    assert w.closed
+    assert not operator.exists(filename)
+
+    operator.write(filename, b"content")
+    with operator.open(filename, "rb") as r:
+        r.abort()
+        assert r.read() == b"content"
+    operator.delete(filename)
+
+
+@pytest.mark.asyncio
+@pytest.mark.need_capability("write", "delete")
+async def test_async_writer_abort(service_name, operator, async_operator):
+    filename = f"test_file_{str(uuid4())}.txt"
+    w = await async_operator.open(filename, "wb")
+    await w.write(os.urandom(16))
+    try:
+        await w.abort()
+    except Unsupported:
+        pytest.skip("service can't abort writes")
+    assert await w.closed
+    assert not await async_operator.exists(filename)
+
+
+@pytest.mark.need_capability("write", "delete", "stat")
+def test_sync_write_returns_metadata(service_name, operator, async_operator):
+    filename = f"test_file_{str(uuid4())}.txt"
+    content = os.urandom(16)
+    metadata = operator.write(filename, content)
+    assert metadata.mode.is_file()
+    if metadata.etag is not None:
+        assert metadata.etag == operator.stat(filename).etag
+
+    with operator.open(filename, "wb") as f:
+        f.write(content)
+    f = operator.open(filename, "wb")
+    f.write(content)
+    assert f.close().mode.is_file()
+    assert f.close() is None
+    operator.delete(filename)
+
+
+@pytest.mark.need_capability(
+    "write",
+    "delete",
+    "stat",
+    "write_with_cache_control",
+    "write_with_content_disposition",
+    "write_with_user_metadata",
+)
+def test_sync_stat_round_trips_metadata(service_name, operator, async_operator):
+    filename = f"test_file_{str(uuid4())}.txt"
+    operator.write(
+        filename,
+        os.urandom(16),
+        cache_control="max-age=60",
+        content_disposition="attachment",
+        user_metadata={"Owner": "alice"},
+    )
+    metadata = operator.stat(filename)
+    assert metadata.cache_control == "max-age=60"
+    assert metadata.content_disposition == "attachment"
+    assert metadata.user_metadata == {"owner": "alice"}
+    assert metadata.to_dict()["cache_control"] == "max-age=60"
+    operator.delete(filename)
+
+
+@pytest.mark.asyncio
+@pytest.mark.need_capability("write", "delete", "stat")
+async def test_async_write_returns_metadata(service_name, operator, async_operator):
+    filename = f"test_file_{str(uuid4())}.txt"
+    content = os.urandom(16)
+    metadata = await async_operator.write(filename, content)
+    assert metadata.mode.is_file()
+    if metadata.etag is not None:
+        assert metadata.etag == (await async_operator.stat(filename)).etag
+
+    f = await async_operator.open(filename, "wb")
+    await f.write(content)
+    assert (await f.close()).mode.is_file()
+    assert await f.close() is None
+    await async_operator.delete(filename)
+
+
+@pytest.mark.need_capability("write", "read", "delete", "write_with_if_not_exists")
+def test_sync_write_if_not_exists(service_name, operator, async_operator):
+    filename = f"test_file_{str(uuid4())}.lock"
+    content = os.urandom(16)
+    operator.write(filename, content, if_not_exists=True)
+    with pytest.raises(ConditionNotMatch):
+        operator.write(filename, os.urandom(16), if_not_exists=True)
+    assert operator.read(filename) == content
+    operator.delete(filename)
+
+
+@pytest.mark.asyncio
+@pytest.mark.need_capability("write", "read", "delete", "write_with_if_not_exists")
+async def test_async_write_if_not_exists(service_name, operator, async_operator):
+    filename = f"test_file_{str(uuid4())}.lock"
+    content = os.urandom(16)
+    await async_operator.write(filename, content, if_not_exists=True)
+    with pytest.raises(ConditionNotMatch):
+        await async_operator.write(filename, os.urandom(16), if_not_exists=True)
+    assert await async_operator.read(filename) == content
+    await async_operator.delete(filename)
+
+
+@pytest.mark.need_capability("write", "read", "stat", "delete", "write_with_if_match")
+def test_sync_write_if_match(service_name, operator, async_operator):
+    filename = f"test_file_{str(uuid4())}.txt"
+    operator.write(filename, os.urandom(16))
+    etag = operator.stat(filename).etag
+    content = os.urandom(16)
+    operator.write(filename, content, if_match=etag)
+    with pytest.raises(ConditionNotMatch):
+        operator.write(filename, os.urandom(16), if_match=etag)
+    assert operator.read(filename) == content
+    operator.delete(filename)
+
+
+@pytest.mark.asyncio
+@pytest.mark.need_capability("write", "read", "stat", "delete", "write_with_if_match")
+async def test_async_write_if_match(service_name, operator, async_operator):
+    filename = f"test_file_{str(uuid4())}.txt"
+    await async_operator.write(filename, b"0")
+
+    # An optimistic-concurrency loop: read with the etag, write it back only if
+    # nobody else wrote in between.
+    etag = (await async_operator.stat(filename)).etag
+    value = int(await async_operator.read(filename, if_match=etag))
+    await async_operator.write(filename, str(value + 1).encode(), if_match=etag)
+    with pytest.raises(ConditionNotMatch):
+        await async_operator.write(filename, b"stale", if_match=etag)
+    assert await async_operator.read(filename) == b"1"
+    await async_operator.delete(filename)
+
+
+@pytest.mark.need_capability("write")
+def test_sync_write_unsupported_condition(service_name, operator, async_operator):
+    cap = operator.capability()
+    filename = f"test_file_{str(uuid4())}.txt"
+    if not cap.write_with_if_match:
+        with pytest.raises(Unsupported):
+            operator.write(filename, os.urandom(16), if_match='"etag"')
+    if not cap.write_with_if_none_match:
+        with pytest.raises(Unsupported):
+            operator.write(filename, os.urandom(16), if_none_match='"etag"')
+    if not cap.write_with_if_not_exists:
+        with pytest.raises(Unsupported):
+            operator.write(filename, os.urandom(16), if_not_exists=True)
+
+
+@pytest.mark.need_capability("write", "delete", "stat", "write_with_content_type")
+def test_sync_write_default_options(service_name, operator, async_operator):
+    op = operator.with_default_write_options(content_type="text/plain")
+    filename = f"test_file_{str(uuid4())}.txt"
+    op.write(filename, os.urandom(16))
+    assert op.stat(filename).content_type == "text/plain"
+    op.write(filename, os.urandom(16), content_type="application/octet-stream")
+    assert op.stat(filename).content_type == "application/octet-stream"
+    with op.open(filename, "wb") as f:
+        f.write(os.urandom(16))
+    assert op.stat(filename).content_type == "text/plain"
+    op.delete(filename)
+
+
+@pytest.mark.asyncio
+@pytest.mark.need_capability("write", "delete", "stat", "write_with_content_type")
+async def test_async_write_default_options(service_name, operator, async_operator):
+    op = async_operator.with_default_write_options(content_type="text/plain")
+    filename = f"test_file_{str(uuid4())}.txt"
+    await op.write(filename, os.urandom(16))
+    assert (await op.stat(filename)).content_type == "text/plain"
+    await op.write(filename, os.urandom(16), content_type="application/json")
+    assert (await op.stat(filename)).content_type == "application/json"
+    await op.delete(filename)
+
+
+@pytest.mark.need_capability("write")
+def test_sync_write_default_options_unsupported(
+    service_name, operator, async_operator
+):
+    if operator.capability().write_with_user_metadata:
+        pytest.skip("service supports write with user_metadata")
+    op = operator.with_default_write_options(user_metadata={"k": "v"})
+    filename = f"test_file_{str(uuid4())}.txt"
+    with pytest.raises(Unsupported):
+        op.write(filename, os.urandom(16))
+
+
+@pytest.mark.need_capability("write")
+def test_sync_write_unsupported_metadata(service_name, operator, async_operator):
+    if operator.capability().write_with_user_metadata:
+        pytest.skip("service supports write with user_metadata")
+    filename = f"test_file_{str(uuid4())}.txt"
+    with pytest.raises(Unsupported):
+        operator.write(filename, os.urandom(16), user_metadata={"k": "v"})
+    with pytest.raises(Unsupported):
+        operator.open(filename, "wb", user_metadata={"k": "v"})
+
+
+@pytest.mark.need_capability("write", "read", "delete", "write_can_append")
+def test_sync_writer_append(service_name, operator, async_operator):
+    filename = f"test_file_{str(uuid4())}.txt"
+    first, second = os.urandom(16), os.urandom(16)
+    operator.write(filename, first, append=True)
+    with operator.open(filename, "ab") as f:
+        f.write(second)
+    assert operator.read(filename) == first + second
+    operator.delete(filename)
+
+
+@pytest.mark.asyncio
+@pytest.mark.need_capability("write", "read", "delete", "write_can_append")
+async def test_async_writer_append(service_name, operator, async_operator):
+    filename = f"test_file_{str(uuid4())}.txt"
+    first, second = os.urandom(16), os.urandom(16)
+    await async_operator.write(filename, first, append=True)
+    async with await async_operator.open(filename, "ab") as f:
+        await f.write(second)
+    assert await async_operator.read(filename) == first + second
+    await async_operator.delete(filename)
+
+
+@pytest.mark.need_capability("write")
+def test_sync_write_append_unsupported(service_name, operator, async_operator):
+    if operator.capability().write_can_append:
+        pytest.skip("service supports append")
+    filename = f"test_file_{str(uuid4())}.txt"
+    with pytest.raises(Unsupported):
+        operator.write(filename, os.urandom(16), append=True)
+    with pytest.raises(Unsupported):
+        operator.open(filename, "ab")
+
+
+@pytest.mark.need_capability("write", "read", "delete", "write_with_offset")
+def test_sync_write_at(service_name, operator, async_operator):
+    filename = f"test_file_{str(uuid4())}.txt"
+    operator.write(filename, b"0123456789")
+    operator.write_at(filename, 2, b"ab")
+    assert operator.read(filename) == b"01ab456789"
+    operator.write_at(filename, 8, b"xyz")
+    assert operator.read(filename) == b"01ab4567xyz"
+    with pytest.raises(RangeNotSatisfied):
+        operator.write_at(filename, 100, b"x")
+    with pytest.raises(NotFound):
+        operator.write_at(f"not_exist_{str(uuid4())}", 0, b"x")
+    operator.delete(filename)
+
+
+@pytest.mark.asyncio
+@pytest.mark.need_capability("write", "read", "delete", "write_with_offset")
+async def test_async_write_at(service_name, operator, async_operator):
+    filename = f"test_file_{str(uuid4())}.txt"
+    await async_operator.write(filename, b"0123456789")
+    await async_operator.write_at(filename, 4, b"cd")
+    assert await async_operator.read(filename) == b"0123cd6789"
+    await async_operator.delete(filename)
+
+
+@pytest.mark.need_capability("write")
+def test_sync_write_at_unsupported(service_name, operator, async_operator):
+    if operator.capability().write_with_offset:
+        pytest.skip("service supports write at offset")
+    with pytest.raises(Unsupported):
+        operator.write_at(f"test_file_{str(uuid4())}.txt", 0, b"x")
+
+
+@pytest.mark.asyncio
+@pytest.mark.need_capability("write", "read", "delete", "write_can_multi")
+async def test_async_writer_concurrent_chunks(service_name, operator, async_operator):
+    cap = async_operator.capability()
+    chunk = cap.write_multi_min_size or 1024
+    filename = f"test_file_{str(uuid4())}.txt"
+    content = os.urandom(chunk * 3 + 1)
+    async with await async_operator.open(
+        filename, "wb", chunk=chunk, concurrent=4
+    ) as f:
+        await f.write(content)
+    assert await async_operator.read(filename) == content
+    await async_operator.delete(filename)
+
+
+@pytest.mark.need_capability("write", "write_multi_min_size")
+def test_sync_write_chunk_out_of_bounds(service_name, operator, async_operator):
+    cap = operator.capability()
+    filename = f"test_file_{str(uuid4())}.txt"
+    with pytest.raises(ValueError, match="minimum part size"):
+        operator.open(filename, "wb", chunk=cap.write_multi_min_size - 1)
+    if cap.write_multi_max_size is not None:
+        with pytest.raises(ValueError, match="maximum part size"):
+            operator.write(filename, b"", chunk=cap.write_multi_max_size + 1)
+    with pytest.raises(ValueError):
+        operator.write(filename, b"", concurrent=0)
+
+
+@pytest.mark.need_capability("write", "read", "delete")
+def test_sync_with_chunk_sizes(service_name, operator, async_operator):
+    cap = operator.capability()
+    chunk = cap.write_multi_min_size or 1024
+    op = operator.with_chunk_sizes(read_chunk=1024, write_chunk=chunk)
+    filename = f"test_file_{str(uuid4())}.txt"
+    content = os.urandom(chunk * 2 + 1)
+    with op.open(filename, "wb") as f:
+        f.write(content)
+    assert op.read(filename, concurrent=4) == content
+    with op.open(filename, "rb", concurrent=2) as f:
+        assert f.read() == content
+    # A per-call chunk takes precedence.
+    assert op.read(filename, chunk=4096, concurrent=2) == content
+
+    with pytest.raises(ValueError):
+        operator.with_chunk_sizes(read_chunk=0)
+    if cap.write_multi_min_size is not None:
+        with pytest.raises(ValueError, match="minimum part size"):
+            operator.with_chunk_sizes(write_chunk=cap.write_multi_min_size - 1)
+    operator.delete(filename)
+
+
+@pytest.mark.need_capability("write", "read", "delete")
+def test_sync_write_strategy(service_name, operator, async_operator):
+    cap = operator.capability()
+    chunk = cap.write_multi_min_size or 1024 * 1024
+    filename = f"test_file_{str(uuid4())}.txt"
+    content = os.urandom(chunk * 2 + 1)
+    pieces = [content[i : i + 65536] for i in range(0, len(content), 65536)]
+    strategies = ["auto", "single"] + (["multipart"] if cap.write_can_multi else [])
+    for strategy in strategies:
+        operator.write(filename, content, strategy=strategy, chunk=chunk)
+        assert operator.read(filename) == content
+        operator.write(filename, iter(pieces), strategy=strategy)
+        assert operator.read(filename) == content
+
+    if not cap.write_can_multi:
+        with pytest.raises(Unsupported):
+            operator.write(filename, content, strategy="multipart")
+    with pytest.raises(TypeError, match="strategy"):
+        operator.write(filename, content, strategy="parallel")
+    with pytest.raises(ValueError):
+        operator.open(filename, "wb", strategy="single")
+    if cap.write_can_append:
+        with pytest.raises(ValueError):
+            operator.write(filename, content, append=True, strategy="single")
+    operator.delete(filename)
+
+
+@pytest.mark.asyncio
+@pytest.mark.need_capability("write", "read", "delete")
+async def test_async_write_strategy(
+    service_name, operator, async_operator, tmp_path
+):
+    filename = f"test_file_{str(uuid4())}.txt"
+    content = os.urandom(3 * 1024 * 1024)
+    source = tmp_path / "source"
+    source.write_bytes(content)
+    for strategy in ["auto", "single"]:
+        await async_operator.write(filename, content, strategy=strategy)
+        assert await async_operator.read(filename) == content
+        await async_operator.write_from_file(filename, source, strategy=strategy)
+        assert await async_operator.read(filename) == content
+    await async_operator.delete(filename)
+
+
+@pytest.mark.need_capability("write", "read", "delete", "stat")
+def test_sync_write_read_progress(service_name, operator, async_operator):
+    filename = f"test_file_{str(uuid4())}.txt"
+    content = os.urandom(1024)
+    events = []
+    operator.write(filename, content, progress=lambda n, t: events.append((n, t)))
+    assert events[0] == (0, len(content))
+    assert events[-1] == (len(content), len(content))
+
+    events.clear()
+    # The callback may call back into the operator.
+    def progress(n, total):
+        events.append((n, total, operator.exists(filename)))
+
+    assert operator.read(filename, progress=progress) == content
+    assert events[-1] == (len(content), len(content), True)
+    operator.delete(filename)
+
+
+@pytest.mark.asyncio
+@pytest.mark.need_capability("write", "read", "delete", "stat")
+async def test_async_write_read_progress(service_name, operator, async_operator):
+    filename = f"test_file_{str(uuid4())}.txt"
+    content = os.urandom(1024)
+    events = []
+
+    # The callback may use the blocking operator.
+    def progress(n, total):
+        events.append((n, total, operator.exists(filename)))
+
+    await async_operator.write(filename, content, progress=progress)
+    assert events[0] == (0, len(content), False)
+    assert events[-1][:2] == (len(content), len(content))
+
+    events.clear()
+    read_content = await async_operator.read(
+        filename, offset=24, progress=lambda n, t: events.append((n, t))
+    )
+    assert read_content == content[24:]
+    assert events[0] == (0, len(content) - 24)
+    assert events[-1] == (len(content) - 24, len(content) - 24)
+
+    # A range running past the end is clamped once the service stops.
+    events.clear()
+    read_content = await async_operator.read(
+        filename, offset=1000, size=100, progress=lambda n, t: events.append((n, t))
+    )
+    assert read_content == content[1000:]
+    assert events[0] == (0, 100)
+    assert events[-1] == (24, 24)
+    await async_operator.delete(filename)
+
+
+@pytest.mark.need_capability("write", "read", "delete", "stat")
+def test_sync_progress_exception_aborts(service_name, operator, async_operator):
+    filename = f"test_file_{str(uuid4())}.txt"
+
+    def progress(n, total):
+        if n:
+            raise KeyError("stop")
+
+    operator.write(filename, os.urandom(1024))
+    with pytest.raises(KeyError):
+        operator.read(filename, progress=progress)
+    operator.delete(filename)
+
+    w = operator.open(filename, "wb")
+    try:
+        w.abort()
+    except Unsupported:
+        operator.delete(filename)
+        pytest.skip("service can't abort writes")
+    with pytest.raises(KeyError):
+        operator.write(filename, os.urandom(1024), progress=progress)
+    # The write is aborted, so nothing is left at the path.
+    assert not operator.exists(filename)
+
+
+@pytest.mark.asyncio
+@pytest.mark.need_capability("read", "write", "delete", "stat")
+async def test_async_write_from_and_read_to_file(
+    service_name, operator, async_operator, tmp_path
+):
+    filename = f"test_file_{str(uuid4())}.txt"
+    content = os.urandom(randint(1, 1024))
+    source = tmp_path / "source"
+    source.write_bytes(content)
+    await async_operator.write_from_file(filename, source)
+    assert (await async_operator.stat(filename)).content_length == len(content)
+
+    target = tmp_path / "target"
+    await async_operator.read_to_file(filename, target)
+    assert target.read_bytes() == content
+
+    await async_operator.delete(filename)
+
+
+@pytest.mark.asyncio
+@pytest.mark.need_capability("read", "write", "delete", "stat")
+async def test_async_read_to_file_concurrent(
+    service_name, operator, async_operator, tmp_path
+):
+    filename = f"test_file_{str(uuid4())}.txt"
+    content = os.urandom(10 * 1024 + 7)
+    await async_operator.write(filename, content)
+
+    target = tmp_path / "target"
+    await async_operator.read_to_file(filename, target, concurrent=4, chunk=1024)
+    assert target.read_bytes() == content
+    with pytest.raises(ValueError):
+        await async_operator.read_to_file(filename, target, offset=1)
+
+    await async_operator.delete(filename)
+
+
+@pytest.mark.asyncio
+@pytest.mark.need_capability("read", "write")
+async def test_async_read_to_file_not_found(
+    service_name, operator, async_operator, tmp_path
+):
+    target = tmp_path / "target"
+    with pytest.raises(NotFound):
+        await async_operator.read_to_file(f"not_exist_{str(uuid4())}", target)
+    assert not target.exists()
+
+    with pytest.raises(FileNotFoundError):
+        await async_operator.write_from_file(
+            f"test_file_{str(uuid4())}", tmp_path / "missing"
+        )
+
+
+@pytest.mark.need_capability("read", "write", "delete")
+def test_sync_write_buffer_protocol(service_name, operator, async_operator):
+    filename = f"test_file_{str(uuid4())}.txt"
+    content = os.urandom(1024)
+    for bs in (bytearray(content), memoryview(content), array.array("B", content)):
+        operator.write(filename, bs)
+        assert operator.read(filename) == content
+
+    with operator.open(filename, "wb") as f:
+        assert f.write(memoryview(content)[:512]) == 512
+        assert f.write(bytearray(content[512:])) == 512
+    assert operator.read(filename) == content
+
+    # Buffers that can't be borrowed aren't iterated as ints instead.
+    with pytest.raises(BufferError, match="contiguous"):
+        operator.write(filename, memoryview(content)[::2])
+    with pytest.raises(ValueError, match="one-dimensional"):
+        operator.write(filename, memoryview(content).cast("B", (32, 32)))
+    with pytest.raises(TypeError):
+        operator.write(filename, "not bytes")
+    operator.delete(filename)
+
+
+@pytest.mark.asyncio
+@pytest.mark.need_capability("read", "write", "delete")
+async def test_async_write_buffer_protocol(service_name, operator, async_operator):
+    filename = f"test_file_{str(uuid4())}.txt"
+    content = os.urandom(1024)
+    await async_operator.write(filename, bytearray(content))
+    assert await async_operator.read(filename) == content
+
+    async with await async_operator.open(filename, "wb") as f:
+        assert await f.write(memoryview(content)) == len(content)
+    assert await async_operator.read(filename) == content
+
+    with pytest.raises(BufferError, match="contiguous"):
+        await async_operator.write(filename, memoryview(content)[::2])
+    with pytest.raises(ValueError, match="one-dimensional"):
+        await async_operator.write(filename, memoryview(content).cast("B", (32, 32)))
+    await async_operator.delete(filename)
+
+
+@pytest.mark.need_capability("create_dir", "stat", "delete", "list")
+def test_sync_create_dir_nested(service_name, operator, async_operator):
+    parent = f"random_dir_{str(uuid4())}/"
+    operator.create_dir(f"{parent}a/b/")
+    operator.create_dir(f"{parent}a/b/")
+    assert operator.stat(f"{parent}a/b/").mode.is_dir()
+
+    with pytest.raises(NotADirectory):
+        operator.create_dir(f"{parent}c")
+    operator.remove_all(parent)
+
+
+@pytest.mark.asyncio
+@pytest.mark.need_capability("create_dir", "stat", "delete", "list")
+async def test_async_create_dir_nested(service_name, operator, async_operator):
+    parent = f"random_dir_{str(uuid4())}/"
+    await async_operator.create_dir(f"{parent}a/b/")
+    await async_operator.create_dir(f"{parent}a/b/")
+    assert (await async_operator.stat(f"{parent}a/b/")).mode.is_dir()
+
+    with pytest.raises(NotADirectory):
+        await async_operator.create_dir(f"{parent}c")
+    await async_operator.remove_all(parent)
+
+
+@pytest.mark.need_capability("write", "read", "delete", "stat")
+def test_sync_write_iterable(service_name, operator, async_operator):
+    filename = f"test_file_{str(uuid4())}.txt"
+    chunks = [os.urandom(randint(1, 1024)) for _ in range(5)]
+    events = []
+
+    def produce():
+        for chunk in chunks:
+            yield bytearray(chunk)
+
+    operator.write(filename, produce(), progress=lambda n, t: events.append((n, t)))
+    assert operator.read(filename) == b"".join(chunks)
+    assert events[-1] == (len(b"".join(chunks)), None)
+
+    operator.write(filename, [b"a", memoryview(b"b")])
+    assert operator.read(filename) == b"ab"
+
+    def broken():
+        yield b"partial"
+        raise KeyError("stop")
+
+    with pytest.raises(KeyError):
+        operator.write(filename, broken())
+
+    with pytest.raises(TypeError):
+        operator.write(filename, "text")
+    with pytest.raises(TypeError):
+        operator.write(filename, ["text"])
+    operator.delete(filename)
+
+
+@pytest.mark.asyncio
+@pytest.mark.need_capability("write", "read", "delete", "stat")
+async def test_async_write_async_iterable(service_name, operator, async_operator):
+    filename = f"test_file_{str(uuid4())}.txt"
+    chunks = [os.urandom(randint(1, 1024)) for _ in range(5)]
+    produced = []
+
+    async def produce():
+        for chunk in chunks:
+            # Each chunk is only pulled once the previous one is written.
+            assert len(produced) == chunks.index(chunk)
+            produced.append(chunk)
+            yield chunk
+
+    await async_operator.write(filename, produce())
+    assert await async_operator.read(filename) == b"".join(chunks)
+
+    await async_operator.write(filename, iter([b"a", b"b"]))
+    assert await async_operator.read(filename) == b"ab"
+
+    async def broken():
+        yield b"partial"
+        raise KeyError("stop")
+
+    with pytest.raises(KeyError):
+        await async_operator.write(filename, broken())
+    await async_operator.delete(filename)
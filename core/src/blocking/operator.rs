@@ -15,6 +15,8 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::collections::HashMap;
+
 use tokio::runtime::Handle;
 
 use crate::Operator as AsyncOperator;
@@ -477,6 +479,54 @@ impl Operator {
         self.handle.block_on(self.op.rename(from, to))
     }
 
+    /// Replace the tags of the file at `path` with `tags`.
+    ///
+    /// # Notes
+    ///
+    /// - `path` must be a file.
+    /// - Existing tags not in `tags` are removed, an empty map clears them all.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::collections::HashMap;
+    /// # use opendal::Result;
+    /// use opendal::blocking;
+    /// # use opendal::blocking::Operator;
+    ///
+    /// # fn test(op: blocking::Operator) -> Result<()> {
+    /// let tags = HashMap::from([("team".to_string(), "data".to_string())]);
+    /// op.put_tags("path/to/file", tags)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn put_tags(&self, path: &str, tags: HashMap<String, String>) -> Result<()> {
+        self.handle.block_on(self.op.put_tags(path, tags))
+    }
+
+    /// Get the tags of the file at `path`.
+    ///
+    /// # Notes
+    ///
+    /// - `path` must be a file.
+    /// - A file without tags returns an empty map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// use opendal::blocking;
+    /// # use opendal::blocking::Operator;
+    ///
+    /// # fn test(op: blocking::Operator) -> Result<()> {
+    /// let tags = op.get_tags("path/to/file")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_tags(&self, path: &str) -> Result<HashMap<String, String>> {
+        self.handle.block_on(self.op.get_tags(path))
+    }
+
     /// Delete given path.
     ///
     /// # Notes
@@ -52,6 +52,20 @@ impl StdWriter {
 
         self.handle.block_on(w.close())
     }
+
+    /// The metadata of the written file, available once the writer is closed.
+    pub fn metadata(&self) -> Option<&Metadata> {
+        self.w.as_ref()?.metadata()
+    }
+
+    /// Abort the internal writer and clean up all written data.
+    pub fn abort(&mut self) -> Result<()> {
+        let Some(w) = self.w.as_mut() else {
+            return Err(Error::new(ErrorKind::Unexpected, "writer has been dropped"));
+        };
+
+        self.handle.block_on(w.abort())
+    }
 }
 
 impl Write for StdWriter {
@@ -87,6 +87,20 @@ impl Writer {
         self.handle.block_on(inner.close())
     }
 
+    /// Abort the writer and clean up all written data.
+    ///
+    /// ## Notes
+    ///
+    /// Abort should only be called when the writer is not closed or
+    /// aborted, otherwise an unexpected error could be returned.
+    pub fn abort(&mut self) -> Result<()> {
+        let Some(inner) = self.inner.as_mut() else {
+            return Err(Error::new(ErrorKind::Unexpected, "writer has been dropped"));
+        };
+
+        self.handle.block_on(inner.abort())
+    }
+
     /// Convert writer into [`StdWriter`] which implements [`std::io::Write`],
     pub fn into_std_write(mut self) -> StdWriter {
         let inner = self
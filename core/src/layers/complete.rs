@@ -218,7 +218,8 @@ impl<A: Access> LayeredAccess for CompleteAccessor<A> {
 
     async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
         let (rp, w) = self.inner.write(path, args.clone()).await?;
-        let w = CompleteWriter::new(w, args.append());
+        // Like appends, writes at an offset report the size of the whole file.
+        let w = CompleteWriter::new(w, args.append() || args.offset().is_some());
         Ok((rp, w))
     }
 
@@ -264,8 +265,9 @@ impl<R> CompleteReader<R> {
 
         match self.read.cmp(&size) {
             Ordering::Equal => Ok(()),
+            // The range ran past the end of the content.
             Ordering::Less => Err(
-                Error::new(ErrorKind::Unexpected, "reader got too little data")
+                Error::new(ErrorKind::RangeNotSatisfied, "reader got too little data")
                     .with_context("expect", size)
                     .with_context("actual", self.read),
             ),
@@ -140,6 +140,13 @@ impl<A: Access> LayeredAccess for CorrectnessAccessor<A> {
                 "append",
             ));
         }
+        if args.offset().is_some() && !capability.write_with_offset {
+            return Err(new_unsupported_error(
+                &self.info,
+                Operation::Write,
+                "offset",
+            ));
+        }
         if args.if_not_exists() && !capability.write_with_if_not_exists {
             return Err(new_unsupported_error(
                 &self.info,
@@ -185,6 +185,22 @@ impl<A: Access> LayeredAccess for ErrorContextAccessor<A> {
                 .with_context("path", path)
         })
     }
+
+    async fn put_tags(&self, path: &str, args: OpPutTags) -> Result<RpPutTags> {
+        self.inner.put_tags(path, args).await.map_err(|err| {
+            err.with_operation(Operation::PutTags)
+                .with_context("service", self.info.scheme())
+                .with_context("path", path)
+        })
+    }
+
+    async fn get_tags(&self, path: &str, args: OpGetTags) -> Result<RpGetTags> {
+        self.inner.get_tags(path, args).await.map_err(|err| {
+            err.with_operation(Operation::GetTags)
+                .with_context("service", self.info.scheme())
+                .with_context("path", path)
+        })
+    }
 }
 
 pub struct ErrorContextWrapper<T> {
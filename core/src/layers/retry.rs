@@ -253,6 +253,24 @@ pub trait RetryInterceptor: Send + Sync + 'static {
     /// The intercept must be quick and non-blocking. No heavy IO is
     /// allowed. Otherwise, the retry will be blocked.
     fn intercept(&self, err: &Error, dur: Duration);
+
+    /// Same as [`RetryInterceptor::intercept`], but also given the number of
+    /// the attempt that failed, starting from 1 for the first call.
+    ///
+    /// Calls `intercept` by default.
+    fn intercept_attempt(&self, err: &Error, dur: Duration, attempt: usize) {
+        let _ = attempt;
+        self.intercept(err, dur)
+    }
+}
+
+/// Build a backon notify callback that counts the attempts of one retry loop.
+fn notify_attempts<I: RetryInterceptor>(notify: &I) -> impl FnMut(&Error, Duration) + '_ {
+    let mut attempt = 0;
+    move |err, dur| {
+        attempt += 1;
+        notify.intercept_attempt(err, dur, attempt)
+    }
 }
 
 impl<F> RetryInterceptor for F
@@ -305,7 +323,7 @@ impl<A: Access, I: RetryInterceptor> LayeredAccess for RetryAccessor<A, I> {
         { || self.inner.create_dir(path, args.clone()) }
             .retry(self.builder)
             .when(|e| e.is_temporary())
-            .notify(|err, dur: Duration| self.notify.intercept(err, dur))
+            .notify(notify_attempts(&*self.notify))
             .await
             .map_err(|e| e.set_persistent())
     }
@@ -314,7 +332,7 @@ impl<A: Access, I: RetryInterceptor> LayeredAccess for RetryAccessor<A, I> {
         let (rp, reader) = { || self.inner.read(path, args.clone()) }
             .retry(self.builder)
             .when(|e| e.is_temporary())
-            .notify(|err, dur| self.notify.intercept(err, dur))
+            .notify(notify_attempts(&*self.notify))
             .await
             .map_err(|e| e.set_persistent())?;
 
@@ -328,7 +346,7 @@ impl<A: Access, I: RetryInterceptor> LayeredAccess for RetryAccessor<A, I> {
         { || self.inner.write(path, args.clone()) }
             .retry(self.builder)
             .when(|e| e.is_temporary())
-            .notify(|err, dur| self.notify.intercept(err, dur))
+            .notify(notify_attempts(&*self.notify))
             .await
             .map(|(rp, r)| (rp, RetryWrapper::new(r, self.notify.clone(), self.builder)))
             .map_err(|e| e.set_persistent())
@@ -338,7 +356,7 @@ impl<A: Access, I: RetryInterceptor> LayeredAccess for RetryAccessor<A, I> {
         { || self.inner.stat(path, args.clone()) }
             .retry(self.builder)
             .when(|e| e.is_temporary())
-            .notify(|err, dur| self.notify.intercept(err, dur))
+            .notify(notify_attempts(&*self.notify))
             .await
             .map_err(|e| e.set_persistent())
     }
@@ -347,7 +365,7 @@ impl<A: Access, I: RetryInterceptor> LayeredAccess for RetryAccessor<A, I> {
         { || self.inner.delete() }
             .retry(self.builder)
             .when(|e| e.is_temporary())
-            .notify(|err, dur| self.notify.intercept(err, dur))
+            .notify(notify_attempts(&*self.notify))
             .await
             .map(|(rp, r)| (rp, RetryWrapper::new(r, self.notify.clone(), self.builder)))
             .map_err(|e| e.set_persistent())
@@ -357,7 +375,7 @@ impl<A: Access, I: RetryInterceptor> LayeredAccess for RetryAccessor<A, I> {
         { || self.inner.copy(from, to, args.clone()) }
             .retry(self.builder)
             .when(|e| e.is_temporary())
-            .notify(|err, dur| self.notify.intercept(err, dur))
+            .notify(notify_attempts(&*self.notify))
             .await
             .map_err(|e| e.set_persistent())
     }
@@ -366,7 +384,7 @@ impl<A: Access, I: RetryInterceptor> LayeredAccess for RetryAccessor<A, I> {
         { || self.inner.rename(from, to, args.clone()) }
             .retry(self.builder)
             .when(|e| e.is_temporary())
-            .notify(|err, dur| self.notify.intercept(err, dur))
+            .notify(notify_attempts(&*self.notify))
             .await
             .map_err(|e| e.set_persistent())
     }
@@ -375,7 +393,7 @@ impl<A: Access, I: RetryInterceptor> LayeredAccess for RetryAccessor<A, I> {
         { || self.inner.list(path, args.clone()) }
             .retry(self.builder)
             .when(|e| e.is_temporary())
-            .notify(|err, dur| self.notify.intercept(err, dur))
+            .notify(notify_attempts(&*self.notify))
             .await
             .map(|(rp, r)| (rp, RetryWrapper::new(r, self.notify.clone(), self.builder)))
             .map_err(|e| e.set_persistent())
@@ -464,7 +482,7 @@ impl<R: oio::Read, I: RetryInterceptor> oio::Read for RetryWrapper<R, I> {
         .retry(self.builder)
         .when(|e| e.is_temporary())
         .context(inner)
-        .notify(|err, dur| self.notify.intercept(err, dur))
+        .notify(notify_attempts(&*self.notify))
         .await;
 
         self.inner = Some(inner);
@@ -488,7 +506,7 @@ impl<R: oio::Write, I: RetryInterceptor> oio::Write for RetryWrapper<R, I> {
         .retry(self.builder)
         .when(|e| e.is_temporary())
         .context((inner, bs))
-        .notify(|err, dur| self.notify.intercept(err, dur))
+        .notify(notify_attempts(&*self.notify))
         .await;
 
         self.inner = Some(inner);
@@ -510,7 +528,7 @@ impl<R: oio::Write, I: RetryInterceptor> oio::Write for RetryWrapper<R, I> {
         .retry(self.builder)
         .when(|e| e.is_temporary())
         .context(inner)
-        .notify(|err, dur| self.notify.intercept(err, dur))
+        .notify(notify_attempts(&*self.notify))
         .await;
 
         self.inner = Some(inner);
@@ -532,7 +550,7 @@ impl<R: oio::Write, I: RetryInterceptor> oio::Write for RetryWrapper<R, I> {
         .retry(self.builder)
         .when(|e| e.is_temporary())
         .context(inner)
-        .notify(|err, dur| self.notify.intercept(err, dur))
+        .notify(notify_attempts(&*self.notify))
         .await;
 
         self.inner = Some(inner);
@@ -556,7 +574,7 @@ impl<P: oio::List, I: RetryInterceptor> oio::List for RetryWrapper<P, I> {
         .retry(self.builder)
         .when(|e| e.is_temporary())
         .context(inner)
-        .notify(|err, dur| self.notify.intercept(err, dur))
+        .notify(notify_attempts(&*self.notify))
         .await;
 
         self.inner = Some(inner);
@@ -569,9 +587,7 @@ impl<P: oio::Delete, I: RetryInterceptor> oio::Delete for RetryWrapper<P, I> {
         { || self.inner.as_mut().unwrap().delete(path, args.clone()) }
             .retry(self.builder)
             .when(|e| e.is_temporary())
-            .notify(|err, dur| {
-                self.notify.intercept(err, dur);
-            })
+            .notify(notify_attempts(&*self.notify))
             .call()
             .map_err(|e| e.set_persistent())
     }
@@ -591,7 +607,7 @@ impl<P: oio::Delete, I: RetryInterceptor> oio::Delete for RetryWrapper<P, I> {
         .retry(self.builder)
         .when(|e| e.is_temporary())
         .context(inner)
-        .notify(|err, dur| self.notify.intercept(err, dur))
+        .notify(notify_attempts(&*self.notify))
         .await;
 
         self.inner = Some(inner);
@@ -695,6 +711,19 @@ mod tests {
         }
     }
 
+    #[derive(Clone, Default)]
+    struct MockInterceptor {
+        attempts: Arc<Mutex<Vec<usize>>>,
+    }
+
+    impl RetryInterceptor for MockInterceptor {
+        fn intercept(&self, _: &Error, _: Duration) {}
+
+        fn intercept_attempt(&self, _: &Error, _: Duration, attempt: usize) {
+            self.attempts.lock().unwrap().push(attempt);
+        }
+    }
+
     #[derive(Debug, Clone, Default)]
     struct MockReader {
         buf: Buffer,
@@ -838,10 +867,11 @@ mod tests {
             .try_init();
 
         let builder = MockBuilder::default();
+        let interceptor = MockInterceptor::default();
         let op = Operator::new(builder.clone())
             .unwrap()
             .layer(LoggingLayer::default())
-            .layer(RetryLayer::new())
+            .layer(RetryLayer::new().with_notify(interceptor.clone()))
             .finish();
 
         let r = op.reader("retryable_error").await.unwrap();
@@ -854,6 +884,8 @@ mod tests {
         assert_eq!(content, "Hello, World!".as_bytes());
         // The error is retryable, we should request it 3 times.
         assert_eq!(*builder.attempt.lock().unwrap(), 5);
+        // The first read retries twice, the trailing read starts a new loop.
+        assert_eq!(*interceptor.attempts.lock().unwrap(), vec![1, 2, 1]);
     }
 
     /// This test is used to reproduce the panic issue while composing retry layer with timeout layer.
@@ -894,9 +926,10 @@ mod tests {
         let _ = tracing_subscriber::fmt().with_test_writer().try_init();
 
         let builder = MockBuilder::default();
+        let interceptor = MockInterceptor::default();
         let op = Operator::new(builder.clone())
             .unwrap()
-            .layer(RetryLayer::new())
+            .layer(RetryLayer::new().with_notify(interceptor.clone()))
             .finish();
 
         let expected = vec!["hello", "world", "2023/", "0208/"];
@@ -911,6 +944,8 @@ mod tests {
         }
 
         assert_eq!(actual, expected);
+        // Every failed `next` call is retried in its own loop.
+        assert_eq!(*interceptor.attempts.lock().unwrap(), vec![1, 1]);
     }
 
     #[tokio::test]
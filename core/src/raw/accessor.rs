@@ -257,6 +257,48 @@ pub trait Access: Send + Sync + Debug + Unpin + 'static {
             "operation is not supported",
         )))
     }
+
+    /// Invoke the `put_tags` operation on the specified path.
+    ///
+    /// Require [`Capability::tagging`]
+    ///
+    /// # Behavior
+    ///
+    /// - `path` MUST be a file path, DON'T NEED to check mode.
+    /// - The given tags SHOULD replace all existing tags of the file.
+    fn put_tags(
+        &self,
+        path: &str,
+        args: OpPutTags,
+    ) -> impl Future<Output = Result<RpPutTags>> + MaybeSend {
+        let (_, _) = (path, args);
+
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        )))
+    }
+
+    /// Invoke the `get_tags` operation on the specified path.
+    ///
+    /// Require [`Capability::tagging`]
+    ///
+    /// # Behavior
+    ///
+    /// - `path` MUST be a file path, DON'T NEED to check mode.
+    /// - A file without tags SHOULD return empty tags.
+    fn get_tags(
+        &self,
+        path: &str,
+        args: OpGetTags,
+    ) -> impl Future<Output = Result<RpGetTags>> + MaybeSend {
+        let (_, _) = (path, args);
+
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        )))
+    }
 }
 
 /// `AccessDyn` is the dyn version of [`Access`] make it possible to use as
@@ -312,6 +354,18 @@ pub trait AccessDyn: Send + Sync + Debug + Unpin {
         path: &'a str,
         args: OpPresign,
     ) -> BoxedFuture<'a, Result<RpPresign>>;
+    /// Dyn version of [`Accessor::put_tags`]
+    fn put_tags_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpPutTags,
+    ) -> BoxedFuture<'a, Result<RpPutTags>>;
+    /// Dyn version of [`Accessor::get_tags`]
+    fn get_tags_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpGetTags,
+    ) -> BoxedFuture<'a, Result<RpGetTags>>;
 }
 
 impl<A: ?Sized> AccessDyn for A
@@ -392,6 +446,22 @@ where
     ) -> BoxedFuture<'a, Result<RpPresign>> {
         Box::pin(self.presign(path, args))
     }
+
+    fn put_tags_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpPutTags,
+    ) -> BoxedFuture<'a, Result<RpPutTags>> {
+        Box::pin(self.put_tags(path, args))
+    }
+
+    fn get_tags_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpGetTags,
+    ) -> BoxedFuture<'a, Result<RpGetTags>> {
+        Box::pin(self.get_tags(path, args))
+    }
 }
 
 impl Access for dyn AccessDyn {
@@ -439,6 +509,14 @@ impl Access for dyn AccessDyn {
     async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
         self.presign_dyn(path, args).await
     }
+
+    async fn put_tags(&self, path: &str, args: OpPutTags) -> Result<RpPutTags> {
+        self.put_tags_dyn(path, args).await
+    }
+
+    async fn get_tags(&self, path: &str, args: OpGetTags) -> Result<RpGetTags> {
+        self.get_tags_dyn(path, args).await
+    }
 }
 
 /// Dummy implementation of accessor.
@@ -538,6 +616,22 @@ impl<T: Access + ?Sized> Access for Arc<T> {
     ) -> impl Future<Output = Result<RpPresign>> + MaybeSend {
         async move { self.as_ref().presign(path, args).await }
     }
+
+    fn put_tags(
+        &self,
+        path: &str,
+        args: OpPutTags,
+    ) -> impl Future<Output = Result<RpPutTags>> + MaybeSend {
+        async move { self.as_ref().put_tags(path, args).await }
+    }
+
+    fn get_tags(
+        &self,
+        path: &str,
+        args: OpGetTags,
+    ) -> impl Future<Output = Result<RpGetTags>> + MaybeSend {
+        async move { self.as_ref().get_tags(path, args).await }
+    }
 }
 
 /// Accessor is the type erased accessor with `Arc<dyn Accessor>`.
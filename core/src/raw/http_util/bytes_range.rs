@@ -19,6 +19,7 @@ use std::fmt::Debug;
 use std::fmt::Display;
 use std::fmt::Formatter;
 use std::ops::Bound;
+use std::ops::Range;
 use std::ops::RangeBounds;
 use std::str::FromStr;
 
@@ -105,6 +106,7 @@ impl BytesRange {
     }
 
     /// Convert bytes range into rust range with usize.
+    #[cfg(test)]
     pub(crate) fn to_range_as_usize(self) -> impl RangeBounds<usize> {
         (
             Bound::Included(self.0 as usize),
@@ -114,6 +116,24 @@ impl BytesRange {
             },
         )
     }
+
+    /// Convert bytes range into the rust range it covers of content that is
+    /// `len` bytes long, stopping at the end like an HTTP range does.
+    ///
+    /// Returns `RangeNotSatisfied` if the range starts past the end.
+    pub(crate) fn to_range_within(self, len: usize) -> Result<Range<usize>> {
+        let offset = self.0 as usize;
+        if offset > 0 && offset >= len {
+            return Err(Error::new(
+                ErrorKind::RangeNotSatisfied,
+                "range start offset exceeds content length",
+            )
+            .with_context("offset", offset)
+            .with_context("length", len));
+        }
+        let end = self.1.map_or(len, |size| (offset + size as usize).min(len));
+        Ok(offset..end)
+    }
 }
 
 impl Display for BytesRange {
@@ -226,6 +246,31 @@ mod tests {
         assert_eq!(h.to_header(), "bytes=1024-2047");
     }
 
+    #[test]
+    fn test_bytes_range_to_range_within() {
+        let cases = vec![
+            ("full", BytesRange::default(), 10, Some(0..10)),
+            ("full of empty", BytesRange::default(), 0, Some(0..0)),
+            ("inside", BytesRange::new(2, Some(3)), 10, Some(2..5)),
+            ("past the end", BytesRange::new(8, Some(5)), 10, Some(8..10)),
+            ("open", BytesRange::new(4, None), 10, Some(4..10)),
+            ("at the end", BytesRange::new(10, None), 10, None),
+            ("after the end", BytesRange::new(12, Some(1)), 10, None),
+        ];
+
+        for (name, range, len, expected) in cases {
+            let actual = range.to_range_within(len);
+            match expected {
+                Some(expected) => assert_eq!(actual.unwrap(), expected, "{name}"),
+                None => assert_eq!(
+                    actual.unwrap_err().kind(),
+                    ErrorKind::RangeNotSatisfied,
+                    "{name}"
+                ),
+            }
+        }
+    }
+
     #[test]
     fn test_bytes_range_from_range_bounds() {
         assert_eq!(BytesRange::new(0, None), BytesRange::from(..));
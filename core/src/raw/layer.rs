@@ -177,6 +177,22 @@ pub trait LayeredAccess: Send + Sync + Debug + Unpin + 'static {
     ) -> impl Future<Output = Result<RpPresign>> + MaybeSend {
         self.inner().presign(path, args)
     }
+
+    fn put_tags(
+        &self,
+        path: &str,
+        args: OpPutTags,
+    ) -> impl Future<Output = Result<RpPutTags>> + MaybeSend {
+        self.inner().put_tags(path, args)
+    }
+
+    fn get_tags(
+        &self,
+        path: &str,
+        args: OpGetTags,
+    ) -> impl Future<Output = Result<RpGetTags>> + MaybeSend {
+        self.inner().get_tags(path, args)
+    }
 }
 
 impl<L: LayeredAccess> Access for L {
@@ -224,6 +240,14 @@ impl<L: LayeredAccess> Access for L {
     async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
         LayeredAccess::presign(self, path, args).await
     }
+
+    async fn put_tags(&self, path: &str, args: OpPutTags) -> Result<RpPutTags> {
+        LayeredAccess::put_tags(self, path, args).await
+    }
+
+    async fn get_tags(&self, path: &str, args: OpGetTags) -> Result<RpGetTags> {
+        LayeredAccess::get_tags(self, path, args).await
+    }
 }
 
 #[cfg(test)]
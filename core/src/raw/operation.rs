@@ -49,6 +49,10 @@ pub enum Operation {
     List,
     /// Operation to generate a presigned URL.
     Presign,
+    /// Operation to set the tags of a file.
+    PutTags,
+    /// Operation to get the tags of a file.
+    GetTags,
 }
 
 impl Operation {
@@ -77,6 +81,8 @@ impl From<Operation> for &'static str {
             Operation::Delete => "delete",
             Operation::List => "list",
             Operation::Presign => "presign",
+            Operation::PutTags => "put_tags",
+            Operation::GetTags => "get_tags",
         }
     }
 }
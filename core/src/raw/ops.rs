@@ -669,6 +669,7 @@ impl From<options::StatOptions> for OpStat {
 #[derive(Debug, Clone, Default)]
 pub struct OpWrite {
     append: bool,
+    offset: Option<u64>,
     concurrent: usize,
     content_type: Option<String>,
     content_disposition: Option<String>,
@@ -707,6 +708,26 @@ impl OpWrite {
         self
     }
 
+    /// Get the offset from op.
+    ///
+    /// The offset is the position in the existing file where this write starts.
+    pub fn offset(&self) -> Option<u64> {
+        self.offset
+    }
+
+    /// Set the offset of op.
+    ///
+    /// If the offset is set, the data will overwrite the existing file in place starting
+    /// at the offset, leaving the rest of the file untouched.
+    ///
+    /// # Notes
+    ///
+    /// Service could return `Unsupported` if the underlying storage does not support offset.
+    pub fn with_offset(mut self, offset: u64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
     /// Get the content type from option
     pub fn content_type(&self) -> Option<&str> {
         self.content_type.as_deref()
@@ -846,6 +867,7 @@ impl From<options::WriteOptions> for (OpWrite, OpWriter) {
         (
             OpWrite {
                 append: value.append,
+                offset: value.offset,
                 // Ensure concurrent is at least 1
                 concurrent: value.concurrent.max(1),
                 content_type: value.content_type,
@@ -873,6 +895,35 @@ impl OpCopy {
     }
 }
 
+/// Args for `put_tags` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpPutTags {
+    tags: HashMap<String, String>,
+}
+
+impl OpPutTags {
+    /// Create a new `OpPutTags` that replaces the file's tags with `tags`.
+    pub fn new(tags: HashMap<String, String>) -> Self {
+        Self { tags }
+    }
+
+    /// Get the tags from op.
+    pub fn tags(&self) -> &HashMap<String, String> {
+        &self.tags
+    }
+}
+
+/// Args for `get_tags` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpGetTags {}
+
+impl OpGetTags {
+    /// Create a new `OpGetTags`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
 /// Args for `rename` operation.
 #[derive(Debug, Clone, Default)]
 pub struct OpRename {}
@@ -15,6 +15,8 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::collections::HashMap;
+
 use http::Request;
 
 use crate::raw::*;
@@ -199,6 +201,35 @@ impl RpCopy {
     }
 }
 
+/// Reply for `put_tags` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpPutTags {}
+
+impl RpPutTags {
+    /// Create a new reply for `put_tags`.
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+/// Reply for `get_tags` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpGetTags {
+    tags: HashMap<String, String>,
+}
+
+impl RpGetTags {
+    /// Create a new reply for `get_tags`.
+    pub fn new(tags: HashMap<String, String>) -> Self {
+        Self { tags }
+    }
+
+    /// Consume reply to get the tags.
+    pub fn into_tags(self) -> HashMap<String, String> {
+        self.tags
+    }
+}
+
 /// Reply for `rename` operation.
 #[derive(Debug, Clone, Default)]
 pub struct RpRename {}
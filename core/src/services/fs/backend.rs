@@ -155,6 +155,7 @@ impl Builder for FsBuilder {
                             write: true,
                             write_can_empty: true,
                             write_can_append: true,
+                            write_with_offset: true,
                             write_can_multi: true,
                             write_with_if_not_exists: true,
 
@@ -227,12 +228,13 @@ impl Access for FsBackend {
     }
 
     async fn write(&self, path: &str, op: OpWrite) -> Result<(RpWrite, Self::Writer)> {
-        let is_append = op.append();
+        // Appends and patches continue from the position the file is opened at.
+        let is_in_place = op.append() || op.offset().is_some();
         let concurrent = op.concurrent();
 
         let writer = FsWriter::create(self.core.clone(), path, op).await?;
 
-        let writer = if is_append {
+        let writer = if is_in_place {
             FsWriters::One(writer)
         } else {
             FsWriters::Two(oio::PositionWriter::new(
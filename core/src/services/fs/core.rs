@@ -114,6 +114,31 @@ impl FsCore {
 
     pub async fn fs_write(&self, path: &PathBuf, op: &OpWrite) -> Result<tokio::fs::File> {
         let mut open_options = tokio::fs::OpenOptions::new();
+
+        // Patch the existing file in place, leaving the rest of it untouched.
+        if let Some(offset) = op.offset() {
+            use tokio::io::AsyncSeekExt;
+
+            let mut f = open_options
+                .write(true)
+                .open(path)
+                .await
+                .map_err(parse_error)?;
+            let size = f.metadata().await.map_err(new_std_io_error)?.len();
+            if offset > size {
+                return Err(Error::new(
+                    ErrorKind::RangeNotSatisfied,
+                    "write offset is past the end of the file",
+                )
+                .with_context("offset", offset)
+                .with_context("size", size));
+            }
+            f.seek(SeekFrom::Start(offset))
+                .await
+                .map_err(new_std_io_error)?;
+            return Ok(f);
+        }
+
         if op.if_not_exists() {
             open_options.create_new(true);
         } else {
@@ -62,9 +62,10 @@ impl FsWriter {
             ));
         }
 
-        // The only case we allow write in place is the file
-        // exists and users request for append writing.
-        let (f, temp_path) = if !(is_append && is_exist) {
+        // The only cases we allow write in place are the file
+        // exists and users request for append writing, or patching
+        // at an offset which needs the existing file anyway.
+        let (f, temp_path) = if !(is_append && is_exist) && op.offset().is_none() {
             core.fs_tempfile_write(path).await?
         } else {
             let f = core.fs_write(&target_path, &op).await?;
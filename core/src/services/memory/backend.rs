@@ -144,10 +144,8 @@ impl Access for MemoryAccessor {
             }
         };
 
-        Ok((
-            RpRead::new(),
-            value.content.slice(args.range().to_range_as_usize()),
-        ))
+        let range = args.range().to_range_within(value.content.len())?;
+        Ok((RpRead::new(), value.content.slice(range)))
     }
 
     async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
@@ -26,6 +26,7 @@ use std::sync::LazyLock;
 
 use base64::prelude::BASE64_STANDARD;
 use base64::Engine;
+use bytes::Buf;
 use constants::X_AMZ_META_PREFIX;
 use constants::X_AMZ_VERSION_ID;
 use http::Response;
@@ -965,6 +966,8 @@ impl Builder for S3Builder {
 
                             copy: true,
 
+                            tagging: true,
+
                             list: true,
                             list_with_limit: true,
                             list_with_start_after: true,
@@ -1126,6 +1129,39 @@ impl Access for S3Backend {
         }
     }
 
+    async fn put_tags(&self, path: &str, args: OpPutTags) -> Result<RpPutTags> {
+        let resp = self.core.s3_put_object_tagging(path, args.tags()).await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK => Ok(RpPutTags::new()),
+            _ => Err(parse_error(resp)),
+        }
+    }
+
+    async fn get_tags(&self, path: &str, _args: OpGetTags) -> Result<RpGetTags> {
+        let resp = self.core.s3_get_object_tagging(path).await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK => {
+                let tagging: Tagging = quick_xml::de::from_reader(resp.into_body().reader())
+                    .map_err(new_xml_deserialize_error)?;
+                Ok(RpGetTags::new(
+                    tagging
+                        .tag_set
+                        .tag
+                        .into_iter()
+                        .map(|tag| (tag.key, tag.value))
+                        .collect(),
+                ))
+            }
+            _ => Err(parse_error(resp)),
+        }
+    }
+
     async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
         let (expire, op) = args.into_parts();
         // We will not send this request out, just for signing.
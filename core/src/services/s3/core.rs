@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::collections::HashMap;
 use std::fmt;
 use std::fmt::Debug;
 use std::fmt::Display;
@@ -1017,6 +1018,71 @@ impl S3Core {
         self.send(req).await
     }
 
+    pub async fn s3_put_object_tagging(
+        &self,
+        path: &str,
+        tags: &HashMap<String, String>,
+    ) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+
+        let url = format!("{}/{}?tagging", self.endpoint, percent_encode_path(&p));
+
+        let mut req = Request::put(&url);
+
+        let mut tag: Vec<TaggingTag> = tags
+            .iter()
+            .map(|(key, value)| TaggingTag {
+                key: key.clone(),
+                value: value.clone(),
+            })
+            .collect();
+        tag.sort_by(|a, b| a.key.cmp(&b.key));
+        let content = quick_xml::se::to_string(&Tagging {
+            tag_set: TaggingTagSet { tag },
+        })
+        .map_err(new_xml_serialize_error)?;
+
+        req = req.header(CONTENT_LENGTH, content.len());
+        req = req.header(CONTENT_TYPE, "application/xml");
+        // Set content-md5 as required by API.
+        req = req.header("CONTENT-MD5", format_content_md5(content.as_bytes()));
+
+        // Set request payer header if enabled.
+        req = self.insert_request_payer_header(req);
+
+        // Inject operation to the request.
+        req = req.extension(Operation::PutTags);
+
+        let mut req = req
+            .body(Buffer::from(Bytes::from(content)))
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(req).await
+    }
+
+    pub async fn s3_get_object_tagging(&self, path: &str) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+
+        let url = format!("{}/{}?tagging", self.endpoint, percent_encode_path(&p));
+
+        let mut req = Request::get(&url);
+
+        // Set request payer header if enabled.
+        req = self.insert_request_payer_header(req);
+
+        let mut req = req
+            // Inject operation to the request.
+            .extension(Operation::GetTags)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(req).await
+    }
+
     pub async fn s3_list_object_versions(
         &self,
         prefix: &str,
@@ -1151,6 +1217,26 @@ pub struct DeleteObjectsRequestObject {
     pub version_id: Option<String>,
 }
 
+/// Request of PutObjectTagging and result of GetObjectTagging.
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(default, rename = "Tagging", rename_all = "PascalCase")]
+pub struct Tagging {
+    pub tag_set: TaggingTagSet,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct TaggingTagSet {
+    pub tag: Vec<TaggingTag>,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct TaggingTag {
+    pub key: String,
+    pub value: String,
+}
+
 /// Result of DeleteObjects.
 #[derive(Default, Debug, Deserialize)]
 #[serde(default, rename = "DeleteResult", rename_all = "PascalCase")]
@@ -1492,6 +1578,87 @@ mod tests {
         assert_eq!(out.error.len(), 0);
     }
 
+    /// This example is from https://docs.aws.amazon.com/AmazonS3/latest/API/API_PutObjectTagging.html#API_PutObjectTagging_Examples
+    #[test]
+    fn test_serialize_put_object_tagging_request() {
+        let req = Tagging {
+            tag_set: TaggingTagSet {
+                tag: vec![
+                    TaggingTag {
+                        key: "tag1".to_string(),
+                        value: "val1".to_string(),
+                    },
+                    TaggingTag {
+                        key: "tag2".to_string(),
+                        value: "val2".to_string(),
+                    },
+                ],
+            },
+        };
+
+        let actual = quick_xml::se::to_string(&req).expect("must succeed");
+
+        pretty_assertions::assert_eq!(
+            actual,
+            r#"<Tagging>
+             <TagSet>
+               <Tag>
+                 <Key>tag1</Key>
+                 <Value>val1</Value>
+               </Tag>
+               <Tag>
+                 <Key>tag2</Key>
+                 <Value>val2</Value>
+               </Tag>
+             </TagSet>
+             </Tagging>"#
+                // Cleanup space and new line
+                .replace([' ', '\n'], "")
+        )
+    }
+
+    /// This example is from https://docs.aws.amazon.com/AmazonS3/latest/API/API_GetObjectTagging.html#API_GetObjectTagging_Examples
+    #[test]
+    fn test_deserialize_get_object_tagging_result() {
+        let bs = Bytes::from(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+            <Tagging xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+              <TagSet>
+                 <Tag>
+                   <Key>tag1</Key>
+                   <Value>val1</Value>
+                 </Tag>
+                 <Tag>
+                   <Key>tag2</Key>
+                   <Value>val2</Value>
+                 </Tag>
+              </TagSet>
+            </Tagging>"#,
+        );
+
+        let out: Tagging = quick_xml::de::from_reader(bs.reader()).expect("must success");
+
+        assert_eq!(out.tag_set.tag.len(), 2);
+        assert_eq!(out.tag_set.tag[0].key, "tag1");
+        assert_eq!(out.tag_set.tag[0].value, "val1");
+        assert_eq!(out.tag_set.tag[1].key, "tag2");
+        assert_eq!(out.tag_set.tag[1].value, "val2");
+    }
+
+    #[test]
+    fn test_deserialize_get_object_tagging_result_without_tags() {
+        let bs = Bytes::from(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+            <Tagging xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+              <TagSet/>
+            </Tagging>"#,
+        );
+
+        let out: Tagging = quick_xml::de::from_reader(bs.reader()).expect("must success");
+
+        assert!(out.tag_set.tag.is_empty());
+    }
+
     /// This example is from https://docs.aws.amazon.com/AmazonS3/latest/API/API_ListObjects.html#API_ListObjects_Examples
     #[test]
     fn test_parse_list_output_v1() {
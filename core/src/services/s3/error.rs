@@ -40,9 +40,10 @@ pub(super) fn parse_error(resp: Response<Buffer>) -> Error {
     let bs = body.to_bytes();
 
     let (mut kind, mut retryable) = match parts.status.as_u16() {
-        403 => (ErrorKind::PermissionDenied, false),
+        401 | 403 => (ErrorKind::PermissionDenied, false),
         404 => (ErrorKind::NotFound, false),
         304 | 412 => (ErrorKind::ConditionNotMatch, false),
+        416 => (ErrorKind::RangeNotSatisfied, false),
         // Service like R2 could return 499 error with a message like:
         // Client Disconnect, we should retry it.
         499 => (ErrorKind::Unexpected, true),
@@ -120,6 +121,8 @@ pub fn parse_s3_error_code(code: &str) -> Option<(ErrorKind, bool)> {
 
 #[cfg(test)]
 mod tests {
+    use http::StatusCode;
+
     use super::*;
 
     /// Error response example is from https://docs.aws.amazon.com/AmazonS3/latest/API/ErrorResponses.html
@@ -163,4 +166,37 @@ mod tests {
         let out: S3Error = de::from_reader(bs.reader()).expect("must success");
         assert_eq!(out, S3Error::default());
     }
+
+    #[test]
+    fn test_parse_error_with_status() {
+        let err_res = vec![
+            (
+                StatusCode::UNAUTHORIZED,
+                r#"<Error><Code>InvalidAccessKeyId</Code><Message>The AWS access key Id you provided does not exist in our records.</Message></Error>"#,
+                ErrorKind::PermissionDenied,
+            ),
+            (StatusCode::UNAUTHORIZED, "", ErrorKind::PermissionDenied),
+            (
+                StatusCode::FORBIDDEN,
+                r#"<Error><Code>AccessDenied</Code><Message>Access Denied</Message></Error>"#,
+                ErrorKind::PermissionDenied,
+            ),
+            (StatusCode::NOT_FOUND, "", ErrorKind::NotFound),
+            (
+                StatusCode::RANGE_NOT_SATISFIABLE,
+                r#"<Error><Code>InvalidRange</Code><Message>The requested range is not satisfiable</Message></Error>"#,
+                ErrorKind::RangeNotSatisfied,
+            ),
+        ];
+
+        for res in err_res {
+            let body = Buffer::from(bytes::Bytes::from(res.1));
+            let resp = Response::builder().status(res.0).body(body).unwrap();
+
+            let err = parse_error(resp);
+
+            assert_eq!(err.kind(), res.2, "status {}", res.0);
+            assert!(!err.is_temporary(), "status {}", res.0);
+        }
+    }
 }
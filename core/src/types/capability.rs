@@ -110,6 +110,8 @@ pub struct Capability {
     pub write_can_empty: bool,
     /// Indicates if append operations are supported.
     pub write_can_append: bool,
+    /// Indicates if writes can start at an offset of an existing file, patching it in place.
+    pub write_with_offset: bool,
     /// Indicates if Content-Type can be specified during write operations.
     pub write_with_content_type: bool,
     /// Indicates if Content-Disposition can be specified during write operations.
@@ -152,6 +154,9 @@ pub struct Capability {
     /// Indicates if rename operations are supported.
     pub rename: bool,
 
+    /// Indicates if object tags can be set and read.
+    pub tagging: bool,
+
     /// Indicates if list operations are supported.
     pub list: bool,
     /// Indicates if list operations support result limiting.
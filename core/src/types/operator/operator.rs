@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::collections::HashMap;
 use std::future::Future;
 use std::time::Duration;
 
@@ -1130,6 +1131,74 @@ impl Operator {
         Ok(())
     }
 
+    /// Replace the tags of the file at `path` with `tags`.
+    ///
+    /// # Notes
+    ///
+    /// - `path` must be a file.
+    /// - Existing tags not in `tags` are removed, an empty map clears them all.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::collections::HashMap;
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let tags = HashMap::from([("team".to_string(), "data".to_string())]);
+    /// op.put_tags("path/to/file", tags).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn put_tags(&self, path: &str, tags: HashMap<String, String>) -> Result<()> {
+        let path = normalize_path(path);
+
+        if !validate_path(&path, EntryMode::FILE) {
+            return Err(Error::new(ErrorKind::IsADirectory, "path is a directory")
+                .with_operation("Operator::put_tags")
+                .with_context("service", self.info().scheme())
+                .with_context("path", path));
+        }
+
+        self.inner().put_tags(&path, OpPutTags::new(tags)).await?;
+
+        Ok(())
+    }
+
+    /// Get the tags of the file at `path`.
+    ///
+    /// # Notes
+    ///
+    /// - `path` must be a file.
+    /// - A file without tags returns an empty map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let tags = op.get_tags("path/to/file").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_tags(&self, path: &str) -> Result<HashMap<String, String>> {
+        let path = normalize_path(path);
+
+        if !validate_path(&path, EntryMode::FILE) {
+            return Err(Error::new(ErrorKind::IsADirectory, "path is a directory")
+                .with_operation("Operator::get_tags")
+                .with_context("service", self.info().scheme())
+                .with_context("path", path));
+        }
+
+        let rp = self.inner().get_tags(&path, OpGetTags::new()).await?;
+
+        Ok(rp.into_tags())
+    }
+
     /// Delete the given path.
     ///
     /// # Notes
@@ -653,6 +653,29 @@ impl<F: Future<Output = Result<Metadata>>> FutureWrite<F> {
         self
     }
 
+    /// Sets the offset in the existing file where this write starts.
+    ///
+    /// Refer to [`options::WriteOptions::offset`] for more details.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let _ = op
+    ///     .write_with("path/to/file", vec![0; 4096])
+    ///     .offset(1024)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn offset(mut self, v: u64) -> Self {
+        self.args.0.offset = Some(v);
+        self
+    }
+
     /// Sets chunk size for buffered writes.
     ///
     /// Refer to [`options::WriteOptions::chunk`] for more details.
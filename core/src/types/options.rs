@@ -306,6 +306,24 @@ pub struct WriteOptions {
     /// This operation allows adding data to existing files instead of overwriting them.
     pub append: bool,
 
+    /// Sets the offset in the existing file where this write starts.
+    ///
+    /// ### Capability
+    ///
+    /// Check [`Capability::write_with_offset`] before using this option.
+    ///
+    /// ### Behavior
+    ///
+    /// - By default, write operations overwrite existing files
+    /// - When offset is set:
+    ///   - The file must exist, and the offset must not be past its end
+    ///   - Data overwrites the file in place starting at the offset
+    ///   - Content before and after the written range is kept
+    /// - If not supported, will return an error
+    ///
+    /// This operation allows patching a region of a file instead of rewriting it.
+    pub offset: Option<u64>,
+
     /// Sets Cache-Control header for this write operation.
     ///
     /// ### Capability
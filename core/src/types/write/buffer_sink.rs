@@ -31,6 +31,8 @@ use crate::*;
 pub struct BufferSink {
     state: State,
     buf: Buffer,
+    /// Boxed to keep the sink small while writing.
+    metadata: Option<Box<Metadata>>,
 }
 
 enum State {
@@ -51,8 +53,32 @@ impl BufferSink {
         BufferSink {
             state: State::Idle(Some(w)),
             buf: Buffer::new(),
+            metadata: None,
         }
     }
+
+    /// The metadata of the written file, available once the sink is closed.
+    pub fn metadata(&self) -> Option<&Metadata> {
+        self.metadata.as_deref()
+    }
+
+    /// Abort the sink and clean up all written data, see [`Writer::abort`].
+    pub async fn abort(&mut self) -> Result<()> {
+        let State::Idle(w) = &mut self.state else {
+            return Err(Error::new(
+                ErrorKind::Unexpected,
+                "state invalid: sink is writing or closing",
+            ));
+        };
+        let Some(mut w) = w.take() else {
+            return Err(Error::new(
+                ErrorKind::Unexpected,
+                "state invalid: sink has been closed",
+            ));
+        };
+        self.buf = Buffer::new();
+        w.abort().await
+    }
 }
 
 impl futures::Sink<Buffer> for BufferSink {
@@ -149,8 +175,9 @@ impl futures::Sink<Buffer> for BufferSink {
                     let (w, res) = ready!(fut.as_mut().poll(cx));
                     this.state = State::Idle(Some(w));
                     match res {
-                        Ok(_) => {
+                        Ok(meta) => {
                             this.state = State::Idle(None);
+                            this.metadata = Some(Box::new(meta));
                             return Poll::Ready(Ok(()));
                         }
                         Err(err) => return Poll::Ready(Err(err)),
@@ -46,6 +46,19 @@ impl FuturesAsyncWriter {
             buf: oio::FlexBuf::new(256 * 1024),
         }
     }
+
+    /// The metadata of the written file, available once the writer is closed.
+    pub fn metadata(&self) -> Option<&Metadata> {
+        self.sink.metadata()
+    }
+
+    /// Abort the writer and clean up all written data, see [`Writer::abort`].
+    pub async fn abort(&mut self) -> Result<()> {
+        // Drop the buffered bytes, frozen or not.
+        self.buf.freeze();
+        self.buf.clean();
+        self.sink.abort().await
+    }
 }
 
 impl AsyncWrite for FuturesAsyncWriter {
@@ -0,0 +1,96 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+
+use anyhow::Result;
+
+use crate::*;
+
+pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
+    let cap = op.info().full_capability();
+
+    if cap.write && cap.tagging {
+        tests.extend(async_trials!(
+            op,
+            test_put_tags,
+            test_put_tags_replace,
+            test_get_tags_without_tags,
+            test_get_tags_not_exist
+        ))
+    }
+}
+
+/// Put tags on a file and get them back.
+pub async fn test_put_tags(op: Operator) -> Result<()> {
+    let (path, content, _) = TEST_FIXTURE.new_file(op.clone());
+    op.write(&path, content).await?;
+
+    let tags = HashMap::from([
+        ("team".to_string(), "data".to_string()),
+        ("stage".to_string(), "prod".to_string()),
+    ]);
+    op.put_tags(&path, tags.clone())
+        .await
+        .expect("put tags must succeed");
+
+    let actual = op.get_tags(&path).await.expect("get tags must succeed");
+    assert_eq!(actual, tags);
+
+    Ok(())
+}
+
+/// Put tags must replace the existing tags instead of merging them.
+pub async fn test_put_tags_replace(op: Operator) -> Result<()> {
+    let (path, content, _) = TEST_FIXTURE.new_file(op.clone());
+    op.write(&path, content).await?;
+
+    op.put_tags(
+        &path,
+        HashMap::from([("team".to_string(), "data".to_string())]),
+    )
+    .await?;
+    let tags = HashMap::from([("stage".to_string(), "prod".to_string())]);
+    op.put_tags(&path, tags.clone()).await?;
+
+    let actual = op.get_tags(&path).await?;
+    assert_eq!(actual, tags);
+
+    Ok(())
+}
+
+/// Get tags of a file without tags must return an empty map.
+pub async fn test_get_tags_without_tags(op: Operator) -> Result<()> {
+    let (path, content, _) = TEST_FIXTURE.new_file(op.clone());
+    op.write(&path, content).await?;
+
+    let actual = op.get_tags(&path).await?;
+    assert!(actual.is_empty());
+
+    Ok(())
+}
+
+/// Get tags of a not existing file must return NotFound.
+pub async fn test_get_tags_not_exist(op: Operator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+
+    let res = op.get_tags(&path).await;
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind(), ErrorKind::NotFound);
+
+    Ok(())
+}
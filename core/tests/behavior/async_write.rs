@@ -71,6 +71,15 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
             test_writer_with_append
         ))
     }
+
+    if cap.read && cap.write && cap.write_with_offset && cap.stat {
+        tests.extend(async_trials!(
+            op,
+            test_write_with_offset,
+            test_write_with_offset_at_end,
+            test_write_with_offset_past_end
+        ))
+    }
 }
 
 /// Write a single file and test with stat.
@@ -615,6 +624,55 @@ pub async fn test_write_with_append(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// Test write with offset patches the existing file in place.
+pub async fn test_write_with_offset(op: Operator) -> Result<()> {
+    let path = TEST_FIXTURE.new_file_path();
+
+    op.write(&path, "Hello, World!").await?;
+    op.write_with(&path, "OpenDAL")
+        .offset(4)
+        .await
+        .expect("write with offset must success");
+
+    let bs = op.read(&path).await?.to_bytes();
+    assert_eq!(bs, "HellOpenDALd!".as_bytes());
+
+    Ok(())
+}
+
+/// Test write with offset at the end of the file extends it.
+pub async fn test_write_with_offset_at_end(op: Operator) -> Result<()> {
+    let path = TEST_FIXTURE.new_file_path();
+
+    op.write(&path, "Hello").await?;
+    op.write_with(&path, ", World!")
+        .offset(5)
+        .await
+        .expect("write with offset at the end must success");
+
+    let meta = op.stat(&path).await?;
+    assert_eq!(meta.content_length(), 13);
+    let bs = op.read(&path).await?.to_bytes();
+    assert_eq!(bs, "Hello, World!".as_bytes());
+
+    Ok(())
+}
+
+/// Test write with offset past the end of the file must fail.
+pub async fn test_write_with_offset_past_end(op: Operator) -> Result<()> {
+    let path = TEST_FIXTURE.new_file_path();
+
+    op.write(&path, "Hello").await?;
+    let res = op.write_with(&path, "World").offset(6).await;
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind(), ErrorKind::RangeNotSatisfied);
+
+    let bs = op.read(&path).await?.to_bytes();
+    assert_eq!(bs, "Hello".as_bytes());
+
+    Ok(())
+}
+
 pub async fn test_write_with_append_returns_metadata(op: Operator) -> Result<()> {
     let cap = op.info().full_capability();
 